@@ -83,6 +83,7 @@ pub struct AnimationManager {
 }
 
 impl AnimationManager {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             position_animations: Vec::new(),
@@ -4,11 +4,22 @@ pub struct GLContext;
 
 impl GLContext {
     pub fn new(window: &super::Window) -> Self {
-        gl::load_with(|s| window.get_proc_address(s));
+        Self::load_with(|s| window.get_proc_address(s))
+    }
+
+    /// 창 없는 컨텍스트(`HeadlessContext`)의 OpenGL 함수를 불러옵니다.
+    #[cfg(feature = "headless")]
+    pub fn new_headless(context: &super::HeadlessContext) -> Self {
+        Self::load_with(|s| context.get_proc_address(s))
+    }
+
+    fn load_with(loader: impl FnMut(&'static str) -> *const std::ffi::c_void) -> Self {
+        gl::load_with(loader);
 
         unsafe {
             gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+            // 알파 채널은 (1 - 원본 알파)만큼 덮어서, 투명한 오프스크린 레이어에 그려도 알파가 올바르게 쌓이도록 합니다.
+            gl::BlendFuncSeparate(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA, gl::ONE, gl::ONE_MINUS_SRC_ALPHA);
         }
 
         Self
@@ -0,0 +1,229 @@
+use glutin_egl_sys::egl;
+use glutin_egl_sys::egl::types::{ EGLConfig, EGLContext, EGLDisplay, EGLSurface, EGLenum, EGLint };
+use libloading::Library;
+use log::info;
+use std::ffi::{ c_void, CStr, CString };
+
+// EGL_MESA_platform_surfaceless: X11/Wayland 없이 GPU 또는 llvmpipe로 그리는 Mesa 플랫폼
+const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31dd;
+
+#[cfg(target_os = "windows")]
+const EGL_LIBRARIES: [&str; 1] = ["libEGL.dll"];
+#[cfg(not(target_os = "windows"))]
+const EGL_LIBRARIES: [&str; 2] = ["libEGL.so.1", "libEGL.so"];
+
+/// 창 없이 OpenGL 3.3 코어 컨텍스트를 만드는 오프스크린 렌더링 대상 (CI, 테스트, 스크린샷용).
+///
+/// EGL pbuffer를 기본 프레임버퍼로 쓰므로 `Window`와 같은 방식으로 `Renderer`와 위젯을 그리고
+/// `Renderer::capture_frame`으로 결과를 읽을 수 있습니다. Mesa가 있으면 디스플레이 없이 surfaceless
+/// 플랫폼을 사용하며, GPU가 없는 환경에서는 llvmpipe 소프트웨어 렌더러로 동작합니다
+/// (`LIBGL_ALWAYS_SOFTWARE=1`로 강제할 수 있음).
+pub struct HeadlessContext {
+    egl: egl::Egl,
+    display: EGLDisplay,
+    config: EGLConfig,
+    context: EGLContext,
+    surface: EGLSurface,
+    width: u32,
+    height: u32,
+    _library: Library, // egl 함수 포인터가 가리키는 라이브러리 (egl보다 나중에 해제)
+}
+
+impl HeadlessContext {
+    /// width x height 크기의 pbuffer와 컨텍스트를 만들고 현재 스레드에 바인딩합니다.
+    pub fn new(width: u32, height: u32) -> Result<Self, String> {
+        let library = EGL_LIBRARIES.iter()
+            .find_map(|name| unsafe { Library::new(name).ok() })
+            .ok_or_else(|| format!("Failed to load EGL library ({})", EGL_LIBRARIES.join(", ")))?;
+        let egl = load_egl(&library)?;
+
+        unsafe {
+            let display = get_display(&egl)?;
+            let (mut major, mut minor) = (0, 0);
+            if egl.Initialize(display, &mut major, &mut minor) == egl::FALSE {
+                return Err(format!("eglInitialize failed (0x{:x})", egl.GetError()));
+            }
+            info!("Headless EGL {}.{} initialized", major, minor);
+
+            // 같은 플랫폼의 EGL 디스플레이는 프로세스 안에서 하나이므로, 다른 컨텍스트가 쓰고 있을 수 있어
+            // 실패하거나 해제할 때 eglTerminate로 닫지 않습니다.
+            if egl.BindAPI(egl::OPENGL_API) == egl::FALSE {
+                return Err("EGL does not support desktop OpenGL".to_string());
+            }
+
+            let config_attributes = [
+                egl::SURFACE_TYPE as EGLint,
+                egl::PBUFFER_BIT as EGLint,
+                egl::RENDERABLE_TYPE as EGLint,
+                egl::OPENGL_BIT as EGLint,
+                egl::RED_SIZE as EGLint,
+                8,
+                egl::GREEN_SIZE as EGLint,
+                8,
+                egl::BLUE_SIZE as EGLint,
+                8,
+                egl::ALPHA_SIZE as EGLint,
+                8,
+                egl::STENCIL_SIZE as EGLint,
+                8, // 둥근 클립 (Renderer::push_rounded_clip)
+                egl::NONE as EGLint,
+            ];
+            let mut config = std::ptr::null();
+            let mut config_count = 0;
+            if
+                egl.ChooseConfig(display, config_attributes.as_ptr(), &mut config, 1, &mut config_count) ==
+                    egl::FALSE ||
+                config_count == 0
+            {
+                return Err("No EGL config supports RGBA8 pbuffers with a stencil buffer".to_string());
+            }
+
+            let context_attributes = [
+                egl::CONTEXT_MAJOR_VERSION as EGLint,
+                3,
+                egl::CONTEXT_MINOR_VERSION as EGLint,
+                3,
+                egl::CONTEXT_OPENGL_PROFILE_MASK as EGLint,
+                egl::CONTEXT_OPENGL_CORE_PROFILE_BIT as EGLint,
+                egl::NONE as EGLint,
+            ];
+            let context = egl.CreateContext(
+                display,
+                config,
+                egl::NO_CONTEXT,
+                context_attributes.as_ptr()
+            );
+            if context == egl::NO_CONTEXT {
+                return Err(format!("Failed to create an OpenGL 3.3 core context (0x{:x})", egl.GetError()));
+            }
+
+            let mut headless = Self {
+                egl,
+                display,
+                config,
+                context,
+                surface: egl::NO_SURFACE,
+                width: 0,
+                height: 0,
+                _library: library,
+            };
+            headless.create_surface(width, height)?;
+            Ok(headless)
+        }
+    }
+
+    pub fn size(&self) -> (f32, f32) {
+        (self.width as f32, self.height as f32)
+    }
+
+    pub fn get_proc_address(&self, s: &str) -> *const c_void {
+        let name = CString::new(s).unwrap();
+        unsafe { self.egl.GetProcAddress(name.as_ptr()) as *const _ }
+    }
+
+    /// pbuffer를 새 크기로 다시 만듭니다 (이전 내용은 사라짐). `Window::resize`처럼 크기 비율을 반환합니다.
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<(f32, f32), String> {
+        let scale_x = (width as f32) / (self.width as f32);
+        let scale_y = (height as f32) / (self.height as f32);
+        self.create_surface(width, height)?;
+
+        unsafe {
+            gl::Viewport(0, 0, self.width as i32, self.height as i32);
+        }
+
+        Ok((scale_x, scale_y))
+    }
+
+    // 기존 pbuffer를 버리고 새 pbuffer를 만들어 컨텍스트와 함께 바인딩합니다.
+    fn create_surface(&mut self, width: u32, height: u32) -> Result<(), String> {
+        let (width, height) = (width.max(1), height.max(1));
+        unsafe {
+            if self.surface != egl::NO_SURFACE {
+                self.egl.MakeCurrent(self.display, egl::NO_SURFACE, egl::NO_SURFACE, egl::NO_CONTEXT);
+                self.egl.DestroySurface(self.display, self.surface);
+                self.surface = egl::NO_SURFACE;
+            }
+
+            let surface_attributes = [
+                egl::WIDTH as EGLint,
+                width as EGLint,
+                egl::HEIGHT as EGLint,
+                height as EGLint,
+                egl::NONE as EGLint,
+            ];
+            let surface = self.egl.CreatePbufferSurface(
+                self.display,
+                self.config,
+                surface_attributes.as_ptr()
+            );
+            if surface == egl::NO_SURFACE {
+                return Err(format!("Failed to create a {}x{} pbuffer (0x{:x})", width, height, self.egl.GetError()));
+            }
+            self.surface = surface;
+
+            if self.egl.MakeCurrent(self.display, surface, surface, self.context) == egl::FALSE {
+                return Err(format!("eglMakeCurrent failed (0x{:x})", self.egl.GetError()));
+            }
+        }
+
+        self.width = width;
+        self.height = height;
+        Ok(())
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+        unsafe {
+            self.egl.MakeCurrent(self.display, egl::NO_SURFACE, egl::NO_SURFACE, egl::NO_CONTEXT);
+            if self.surface != egl::NO_SURFACE {
+                self.egl.DestroySurface(self.display, self.surface);
+            }
+            self.egl.DestroyContext(self.display, self.context);
+        }
+    }
+}
+
+// 라이브러리에서 찾을 수 없는 함수(확장)는 eglGetProcAddress로 찾습니다.
+fn load_egl(library: &Library) -> Result<egl::Egl, String> {
+    type GetProcAddress = unsafe extern "C" fn(*const std::os::raw::c_char) -> *const c_void;
+    let get_proc_address = unsafe {
+        *library.get::<GetProcAddress>(b"eglGetProcAddress\0").map_err(|e| e.to_string())?
+    };
+
+    Ok(
+        egl::Egl::load_with(|name| {
+            let name = CString::new(name).unwrap();
+            unsafe {
+                match library.get::<*const c_void>(name.as_bytes_with_nul()) {
+                    Ok(symbol) => *symbol,
+                    Err(_) => get_proc_address(name.as_ptr()),
+                }
+            }
+        })
+    )
+}
+
+// Mesa의 surfaceless 플랫폼을 우선 사용하고, 없으면 기본 디스플레이를 엽니다.
+unsafe fn get_display(egl: &egl::Egl) -> Result<EGLDisplay, String> {
+    let extensions = egl.QueryString(egl::NO_DISPLAY, egl::EXTENSIONS as EGLint);
+    let surfaceless =
+        !extensions.is_null() &&
+        CStr::from_ptr(extensions)
+            .to_string_lossy()
+            .split_whitespace()
+            .any(|extension| extension == "EGL_MESA_platform_surfaceless");
+
+    let display = if surfaceless && egl.GetPlatformDisplay.is_loaded() {
+        egl.GetPlatformDisplay(EGL_PLATFORM_SURFACELESS_MESA, egl::DEFAULT_DISPLAY as *mut _, std::ptr::null())
+    } else if surfaceless && egl.GetPlatformDisplayEXT.is_loaded() {
+        egl.GetPlatformDisplayEXT(EGL_PLATFORM_SURFACELESS_MESA, egl::DEFAULT_DISPLAY as *mut _, std::ptr::null())
+    } else {
+        egl.GetDisplay(egl::DEFAULT_DISPLAY)
+    };
+
+    if display == egl::NO_DISPLAY {
+        return Err("No EGL display is available".to_string());
+    }
+    Ok(display)
+}
@@ -24,8 +24,9 @@ impl log::Log for Logger {
     fn flush(&self) {}
 }
 
+/// 로거를 설치합니다. 이미 설치된 로거가 있으면 (두 번째 초기화 또는 애플리케이션이 설치한 로거) 그대로 둡니다.
 pub fn initialize() {
-    log::set_logger(&Logger)
-        .map(|()| log::set_max_level(LevelFilter::Debug))
-        .expect("Failed to initialize logger");
+    if log::set_logger(&Logger).is_ok() {
+        log::set_max_level(LevelFilter::Debug);
+    }
 }
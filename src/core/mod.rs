@@ -2,8 +2,12 @@ pub mod window;
 pub mod gl_context;
 pub mod logger;
 pub mod error_handler;
+#[cfg(feature = "headless")]
+pub mod headless;
 
 pub use window::Window;
 pub use gl_context::GLContext;
 pub use logger::initialize as initialize_logger;
 pub use error_handler::initialize_error_handler;
+#[cfg(feature = "headless")]
+pub use headless::HeadlessContext;
@@ -21,6 +21,7 @@ impl Window {
 
         let windowed_context = ContextBuilder::new()
             .with_vsync(true)
+            .with_stencil_buffer(8) // 둥근 클립 (Renderer::push_rounded_clip)
             .build_windowed(wb, &event_loop)
             .unwrap();
 
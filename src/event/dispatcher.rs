@@ -0,0 +1,496 @@
+use super::{ EventPhase, Key, Modifiers, MouseButton, UiEvent };
+use crate::widget::{ focusable_paths, hit_test_path, widget_at_path, Widget };
+use glutin::event::{ ElementState, ModifiersState, MouseScrollDelta, VirtualKeyCode, WindowEvent };
+use log::debug;
+
+// 줄 단위 휠 입력을 픽셀로 바꿀 때 사용하는 한 줄의 높이
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+/// glutin 윈도우 이벤트를 UI 이벤트로 바꾸어 위젯 트리에 전달하는 디스패처.
+///
+/// 포인터 이벤트는 z-order상 가장 위에 있는 위젯을 히트 테스트로 찾아 캡처/타깃/버블 단계로
+/// 전달하며, 핸들러가 이벤트를 처리(`true`)하면 전파를 멈춥니다. 버튼을 누른 이벤트를 처리한
+/// 위젯은 같은 버튼을 뗄 때까지 포인터를 캡처하여, 드래그 중 포인터가 위젯 밖으로 나가도 이동/뗌
+/// 이벤트를 계속 받습니다.
+///
+/// 키보드 이벤트는 포커스된 위젯의 경로를 따라 같은 방식으로 전달되며, 처리되지 않은 Tab/Shift+Tab은
+/// 포커스를 다음/이전 위젯으로 옮깁니다. 포인터로 누른 위젯(또는 가장 가까운 조상) 중 포커스를
+/// 받을 수 있는 위젯이 포커스를 받습니다.
+///
+/// 위젯 경로는 `widgets` 슬라이스부터의 자식 인덱스 목록이므로, 디스패처는 항상 같은 최상위
+/// 위젯 컬렉션과 함께 사용해야 합니다.
+pub struct EventDispatcher {
+    cursor_position: Option<(f32, f32)>,
+    modifiers: Modifiers,
+    hovered_path: Vec<usize>,
+    captured_path: Option<Vec<usize>>,
+    // 캡처를 시작한 버튼. 이 버튼을 뗄 때만 캡처가 풀립니다.
+    captured_button: Option<MouseButton>,
+    focused_path: Option<Vec<usize>>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self {
+            cursor_position: None,
+            modifiers: Modifiers::default(),
+            hovered_path: Vec::new(),
+            captured_path: None,
+            captured_button: None,
+            focused_path: None,
+        }
+    }
+
+    /// 마지막으로 알려진 커서 위치 (윈도우 좌표)
+    pub fn cursor_position(&self) -> Option<(f32, f32)> {
+        self.cursor_position
+    }
+
+    /// 현재 포인터 아래에 있는 위젯 경로 (최상위부터 가장 안쪽 위젯까지)
+    pub fn hovered_path(&self) -> &[usize] {
+        &self.hovered_path
+    }
+
+    /// 포인터를 캡처한 위젯 경로
+    pub fn captured_path(&self) -> Option<&[usize]> {
+        self.captured_path.as_deref()
+    }
+
+    /// 이후의 포인터 이동/뗌 이벤트를 히트 테스트와 관계없이 `path`의 위젯으로 보냅니다.
+    /// 캡처는 `button`을 뗄 때 풀립니다.
+    pub fn set_pointer_capture(&mut self, path: Vec<usize>, button: MouseButton) {
+        debug!("Pointer captured by {:?} ({:?})", path, button);
+        self.captured_path = Some(path);
+        self.captured_button = Some(button);
+    }
+
+    pub fn release_pointer_capture(&mut self) {
+        self.captured_path = None;
+        self.captured_button = None;
+    }
+
+    /// 현재 눌려 있는 보조 키
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// 키보드 포커스를 가진 위젯 경로
+    pub fn focused_path(&self) -> Option<&[usize]> {
+        self.focused_path.as_deref()
+    }
+
+    /// `path`의 위젯에 포커스를 줍니다. 포커스를 받을 수 없는 위젯이면 포커스를 해제합니다.
+    pub fn set_focus(&mut self, widgets: &mut [Box<dyn Widget>], path: &[usize]) {
+        if self.focused_path.as_deref() == Some(path) {
+            return;
+        }
+        self.clear_focus(widgets);
+
+        if let Some(widget) = widget_at_path(widgets, path) {
+            if widget.is_focusable() {
+                debug!("Focus moved to {:?}", path);
+                widget.set_focused(true);
+                self.focused_path = Some(path.to_vec());
+            }
+        }
+    }
+
+    pub fn clear_focus(&mut self, widgets: &mut [Box<dyn Widget>]) {
+        if let Some(path) = self.focused_path.take() {
+            if let Some(widget) = widget_at_path(widgets, &path) {
+                widget.set_focused(false);
+            }
+        }
+    }
+
+    /// Tab 순서상 다음 위젯으로 포커스를 옮깁니다 (마지막 다음은 처음).
+    pub fn focus_next(&mut self, widgets: &mut [Box<dyn Widget>]) {
+        self.move_focus(widgets, true);
+    }
+
+    /// Tab 순서상 이전 위젯으로 포커스를 옮깁니다 (처음 이전은 마지막).
+    pub fn focus_previous(&mut self, widgets: &mut [Box<dyn Widget>]) {
+        self.move_focus(widgets, false);
+    }
+
+    fn move_focus(&mut self, widgets: &mut [Box<dyn Widget>], forward: bool) {
+        let order = focusable_paths(widgets);
+        if order.is_empty() {
+            return;
+        }
+
+        let current = self.focused_path
+            .as_ref()
+            .and_then(|focused| order.iter().position(|path| path == focused));
+        let next = match (current, forward) {
+            (Some(index), true) => (index + 1) % order.len(),
+            (Some(index), false) => (index + order.len() - 1) % order.len(),
+            (None, true) => 0,
+            (None, false) => order.len() - 1,
+        };
+        self.set_focus(widgets, &order[next]);
+    }
+
+    /// glutin 윈도우 이벤트를 UI 이벤트로 변환합니다. 커서 위치가 필요한 이벤트는
+    /// 마지막 `CursorMoved` 위치를 사용하며, UI와 관계없는 이벤트는 `None`을 반환합니다.
+    pub fn translate_window_event(&mut self, event: &WindowEvent) -> Option<UiEvent> {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                let (x, y) = (position.x as f32, position.y as f32);
+                self.cursor_position = Some((x, y));
+                Some(UiEvent::PointerMove { x, y })
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let (x, y) = self.cursor_position?;
+                let button = convert_mouse_button(*button);
+                Some(match state {
+                    ElementState::Pressed => UiEvent::PointerDown { x, y, button },
+                    ElementState::Released => UiEvent::PointerUp { x, y, button },
+                })
+            }
+            WindowEvent::ModifiersChanged(state) => {
+                self.modifiers = convert_modifiers(*state);
+                None
+            }
+            WindowEvent::KeyboardInput { input, .. } => {
+                let key = match input.virtual_keycode {
+                    Some(keycode) => convert_key(keycode, input.scancode)?,
+                    None => Key::Other { virtual_code: None, scancode: input.scancode },
+                };
+                let modifiers = self.modifiers;
+                Some(match input.state {
+                    ElementState::Pressed => UiEvent::KeyDown { key, modifiers },
+                    ElementState::Released => UiEvent::KeyUp { key, modifiers },
+                })
+            }
+            WindowEvent::ReceivedCharacter(character) if !character.is_control() => {
+                Some(UiEvent::Char { character: *character })
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                let (x, y) = self.cursor_position?;
+                let (delta_x, delta_y) = match delta {
+                    MouseScrollDelta::LineDelta(dx, dy) =>
+                        (-dx * SCROLL_LINE_HEIGHT, -dy * SCROLL_LINE_HEIGHT),
+                    MouseScrollDelta::PixelDelta(position) =>
+                        (-position.x as f32, -position.y as f32),
+                };
+                Some(UiEvent::Wheel { x, y, delta_x, delta_y })
+            }
+            _ => None,
+        }
+    }
+
+    /// glutin 윈도우 이벤트를 변환해서 위젯 트리에 전달합니다.
+    /// 어떤 위젯이 이벤트를 처리했으면 `true`를 반환합니다.
+    pub fn handle_window_event(
+        &mut self,
+        widgets: &mut [Box<dyn Widget>],
+        event: &WindowEvent
+    ) -> bool {
+        if let WindowEvent::CursorLeft { .. } = event {
+            self.cursor_position = None;
+            self.update_hover(widgets, None);
+            return false;
+        }
+
+        match self.translate_window_event(event) {
+            Some(ui_event) => self.dispatch(widgets, ui_event),
+            None => false,
+        }
+    }
+
+    /// UI 이벤트를 위젯 트리에 전달합니다. 어떤 위젯이 이벤트를 처리했으면 `true`를 반환합니다.
+    pub fn dispatch(&mut self, widgets: &mut [Box<dyn Widget>], event: UiEvent) -> bool {
+        match event {
+            UiEvent::PointerMove { x, y } => {
+                self.update_hover(widgets, Some((x, y)));
+                let path = match &self.captured_path {
+                    Some(path) => path.clone(),
+                    None => self.hovered_path.clone(),
+                };
+                propagate(widgets, &path, &event).is_some()
+            }
+            UiEvent::PointerDown { x, y, button } => {
+                let path = hit_test_path(widgets, x, y).unwrap_or_default();
+                // 포커스는 왼쪽 버튼으로만 옮깁니다 (오른쪽 버튼은 컨텍스트 메뉴 등에 사용).
+                if button == MouseButton::Left {
+                    self.focus_at(widgets, &path);
+                }
+
+                // 눌리지 않은 최상위 위젯에 알림 (팝업 닫기 등)
+                let outside = UiEvent::PointerDownOutside { x, y, button };
+                for (index, widget) in widgets.iter_mut().enumerate() {
+                    if path.first() != Some(&index) {
+                        widget.handle_event(&outside, EventPhase::Target);
+                    }
+                }
+
+                match propagate(widgets, &path, &event) {
+                    Some(depth) => {
+                        // 이벤트를 처리한 위젯이 버튼을 뗄 때까지 포인터를 캡처
+                        // (드래그 중 다른 버튼을 눌러도 기존 캡처를 유지)
+                        if self.captured_path.is_none() {
+                            self.set_pointer_capture(path[..depth].to_vec(), button);
+                        }
+                        true
+                    }
+                    None => false,
+                }
+            }
+            UiEvent::PointerUp { x, y, button } => {
+                let path = match self.captured_path.clone() {
+                    Some(path) => {
+                        // 캡처를 시작한 버튼을 뗄 때만 캡처를 풂
+                        if self.captured_button == Some(button) {
+                            self.release_pointer_capture();
+                        }
+                        path
+                    }
+                    None => hit_test_path(widgets, x, y).unwrap_or_default(),
+                };
+                propagate(widgets, &path, &event).is_some()
+            }
+            UiEvent::Wheel { x, y, .. } => {
+                let path = hit_test_path(widgets, x, y).unwrap_or_default();
+                propagate(widgets, &path, &event).is_some()
+            }
+            UiEvent::KeyDown { key: Key::Tab, modifiers } => {
+                let path = self.focused_path.clone().unwrap_or_default();
+                if propagate(widgets, &path, &event).is_some() {
+                    return true;
+                }
+                // 위젯이 Tab을 처리하지 않으면 포커스 이동
+                if modifiers.shift {
+                    self.focus_previous(widgets);
+                } else {
+                    self.focus_next(widgets);
+                }
+                true
+            }
+            UiEvent::KeyDown { .. } | UiEvent::KeyUp { .. } | UiEvent::Char { .. } => {
+                let path = self.focused_path.clone().unwrap_or_default();
+                propagate(widgets, &path, &event).is_some()
+            }
+            | UiEvent::PointerEnter { .. }
+            | UiEvent::PointerLeave { .. }
+            | UiEvent::PointerDownOutside { .. } => {
+                let path = self.hovered_path.clone();
+                deliver(widgets, &path, &event, EventPhase::Target)
+            }
+        }
+    }
+
+    // 누른 위젯부터 조상 방향으로 포커스를 받을 수 있는 위젯을 찾아 포커스를 줍니다.
+    // 그런 위젯이 없으면 포커스를 해제합니다.
+    fn focus_at(&mut self, widgets: &mut [Box<dyn Widget>], path: &[usize]) {
+        for depth in (1..=path.len()).rev() {
+            let focusable = widget_at_path(widgets, &path[..depth]).is_some_and(|widget|
+                widget.is_focusable()
+            );
+            if focusable {
+                self.set_focus(widgets, &path[..depth]);
+                return;
+            }
+        }
+        self.clear_focus(widgets);
+    }
+
+    // 포인터 아래의 위젯 경로를 갱신하고, 벗어난 위젯에는 PointerLeave를(안쪽부터),
+    // 새로 들어간 위젯에는 PointerEnter를(바깥쪽부터) 보냅니다.
+    fn update_hover(&mut self, widgets: &mut [Box<dyn Widget>], position: Option<(f32, f32)>) {
+        let new_path = position
+            .and_then(|(x, y)| hit_test_path(widgets, x, y))
+            .unwrap_or_default();
+        if new_path == self.hovered_path {
+            return;
+        }
+
+        let (x, y) = position.unwrap_or((f32::MIN, f32::MIN));
+        let common = self.hovered_path
+            .iter()
+            .zip(&new_path)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let leave = UiEvent::PointerLeave { x, y };
+        for depth in (common..self.hovered_path.len()).rev() {
+            deliver(widgets, &self.hovered_path[..=depth], &leave, EventPhase::Target);
+        }
+
+        let enter = UiEvent::PointerEnter { x, y };
+        for depth in common..new_path.len() {
+            deliver(widgets, &new_path[..=depth], &enter, EventPhase::Target);
+        }
+
+        self.hovered_path = new_path;
+    }
+}
+
+impl Default for EventDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// `path`를 따라 캡처 → 타깃 → 버블 순서로 이벤트를 전달합니다.
+// 이벤트를 처리한 위젯의 경로 길이(깊이 + 1)를 반환합니다.
+fn propagate(widgets: &mut [Box<dyn Widget>], path: &[usize], event: &UiEvent) -> Option<usize> {
+    let (&index, rest) = path.split_first()?;
+    let widget = widgets.get_mut(index)?;
+
+    if rest.is_empty() {
+        return widget.handle_event(event, EventPhase::Target).then_some(1);
+    }
+
+    if widget.handle_event(event, EventPhase::Capture) {
+        return Some(1);
+    }
+
+    let local_event = child_event(widget.as_ref(), event);
+    if let Some(depth) = propagate(widget.children_mut(), rest, &local_event) {
+        return Some(depth + 1);
+    }
+
+    widget.handle_event(event, EventPhase::Bubble).then_some(1)
+}
+
+// `path`의 마지막 위젯에만 이벤트를 전달합니다 (좌표는 경로를 따라 변환).
+fn deliver(
+    widgets: &mut [Box<dyn Widget>],
+    path: &[usize],
+    event: &UiEvent,
+    phase: EventPhase
+) -> bool {
+    let Some((&index, rest)) = path.split_first() else {
+        return false;
+    };
+    let Some(widget) = widgets.get_mut(index) else {
+        return false;
+    };
+
+    if rest.is_empty() {
+        return widget.handle_event(event, phase);
+    }
+
+    let local_event = child_event(widget.as_ref(), event);
+    deliver(widget.children_mut(), rest, &local_event, phase)
+}
+
+// 부모 좌표계의 이벤트를 `widget`의 자식 좌표계로 바꿉니다 (위젯 변환을 되돌린 뒤 자식 기준점만큼 이동).
+fn child_event(widget: &dyn Widget, event: &UiEvent) -> UiEvent {
+    let (origin_x, origin_y) = widget.children_origin();
+    event.mapped(|x, y| {
+        let (x, y) = widget.to_local(x, y);
+        (x - origin_x, y - origin_y)
+    })
+}
+
+fn convert_mouse_button(button: glutin::event::MouseButton) -> MouseButton {
+    match button {
+        glutin::event::MouseButton::Left => MouseButton::Left,
+        glutin::event::MouseButton::Right => MouseButton::Right,
+        glutin::event::MouseButton::Middle => MouseButton::Middle,
+        glutin::event::MouseButton::Other(id) => MouseButton::Other(id),
+    }
+}
+
+fn convert_modifiers(state: ModifiersState) -> Modifiers {
+    Modifiers {
+        shift: state.shift(),
+        ctrl: state.ctrl(),
+        alt: state.alt(),
+        logo: state.logo(),
+    }
+}
+
+fn convert_key(keycode: VirtualKeyCode, scancode: u32) -> Option<Key> {
+    use VirtualKeyCode as Code;
+
+    let key = match keycode {
+        Code::Tab => Key::Tab,
+        Code::Return | Code::NumpadEnter => Key::Enter,
+        Code::Space => Key::Space,
+        Code::Escape => Key::Escape,
+        Code::Back => Key::Backspace,
+        Code::Delete => Key::Delete,
+        Code::Insert => Key::Insert,
+        Code::Left => Key::Left,
+        Code::Right => Key::Right,
+        Code::Up => Key::Up,
+        Code::Down => Key::Down,
+        Code::Home => Key::Home,
+        Code::End => Key::End,
+        Code::PageUp => Key::PageUp,
+        Code::PageDown => Key::PageDown,
+        // 보조 키 자체는 ModifiersChanged로 처리
+        | Code::LShift
+        | Code::RShift
+        | Code::LControl
+        | Code::RControl
+        | Code::LAlt
+        | Code::RAlt
+        | Code::LWin
+        | Code::RWin => {
+            return None;
+        }
+        _ => {
+            let key = character_key(keycode).map(Key::Character);
+            return Some(key.unwrap_or(Key::Other { virtual_code: Some(keycode as u32), scancode }));
+        }
+    };
+    Some(key)
+}
+
+// 영문자/숫자 키를 문자로 변환
+fn character_key(keycode: VirtualKeyCode) -> Option<char> {
+    use VirtualKeyCode as Code;
+
+    let letters = [
+        Code::A,
+        Code::B,
+        Code::C,
+        Code::D,
+        Code::E,
+        Code::F,
+        Code::G,
+        Code::H,
+        Code::I,
+        Code::J,
+        Code::K,
+        Code::L,
+        Code::M,
+        Code::N,
+        Code::O,
+        Code::P,
+        Code::Q,
+        Code::R,
+        Code::S,
+        Code::T,
+        Code::U,
+        Code::V,
+        Code::W,
+        Code::X,
+        Code::Y,
+        Code::Z,
+    ];
+    let digits = [
+        Code::Key0,
+        Code::Key1,
+        Code::Key2,
+        Code::Key3,
+        Code::Key4,
+        Code::Key5,
+        Code::Key6,
+        Code::Key7,
+        Code::Key8,
+        Code::Key9,
+    ];
+
+    if let Some(index) = letters.iter().position(|&code| code == keycode) {
+        return Some((b'a' + (index as u8)) as char);
+    }
+    digits
+        .iter()
+        .position(|&code| code == keycode)
+        .map(|index| (b'0' + (index as u8)) as char)
+}
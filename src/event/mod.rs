@@ -0,0 +1,171 @@
+pub mod dispatcher;
+
+pub use dispatcher::EventDispatcher;
+
+/// 키보드 이벤트에서 사용하는 키
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    Tab,
+    Enter,
+    Space,
+    Escape,
+    Backspace,
+    Delete,
+    Insert,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    /// 문자 키 (영문자는 소문자, 숫자는 '0'..'9'). 단축키 처리용이며 입력된 문자는 `UiEvent::Char`로 전달됩니다.
+    Character(char),
+    /// 그 외의 키. `virtual_code`는 glutin `VirtualKeyCode`의 값(`as u32`)이며 가상 키가 없으면 `None`,
+    /// `scancode`는 키보드 배열과 상관없는 물리 키 코드입니다.
+    Other {
+        virtual_code: Option<u32>,
+        scancode: u32,
+    },
+}
+
+/// 키 이벤트와 함께 전달되는 보조 키 상태
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    /// Windows 키 / Command 키
+    pub logo: bool,
+}
+
+impl Modifiers {
+    /// 단축키 보조 키 (macOS에서는 Command, 그 외에는 Ctrl)
+    pub fn command(&self) -> bool {
+        if cfg!(target_os = "macos") { self.logo } else { self.ctrl }
+    }
+}
+
+/// UI 이벤트에서 사용하는 마우스 버튼
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Other(u16),
+}
+
+/// 위젯에 전달되는 UI 이벤트.
+///
+/// 좌표는 이벤트를 받는 위젯의 부모 좌표계 기준입니다(`Widget::position`과 같은 좌표계).
+/// `EventDispatcher`가 트리를 따라 내려가며 컨테이너의 원점만큼 좌표를 변환해서 전달합니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UiEvent {
+    PointerMove {
+        x: f32,
+        y: f32,
+    },
+    PointerDown {
+        x: f32,
+        y: f32,
+        button: MouseButton,
+    },
+    PointerUp {
+        x: f32,
+        y: f32,
+        button: MouseButton,
+    },
+    /// 포인터가 위젯(또는 그 자식) 위로 들어옴. 대상 위젯에만 전달됩니다.
+    PointerEnter {
+        x: f32,
+        y: f32,
+    },
+    /// 포인터가 위젯을 벗어남. 대상 위젯에만 전달됩니다.
+    PointerLeave {
+        x: f32,
+        y: f32,
+    },
+    /// 다른 곳을 눌렀을 때 눌리지 않은 최상위 위젯에 전달됩니다 (팝업 닫기 등).
+    PointerDownOutside {
+        x: f32,
+        y: f32,
+        button: MouseButton,
+    },
+    /// 마우스 휠 (픽셀 단위, 아래/오른쪽이 양수)
+    Wheel {
+        x: f32,
+        y: f32,
+        delta_x: f32,
+        delta_y: f32,
+    },
+    /// 키를 누름 (포커스된 위젯으로 전달)
+    KeyDown {
+        key: Key,
+        modifiers: Modifiers,
+    },
+    /// 키를 뗌 (포커스된 위젯으로 전달)
+    KeyUp {
+        key: Key,
+        modifiers: Modifiers,
+    },
+    /// 입력된 문자 (포커스된 위젯으로 전달, 제어 문자는 제외)
+    Char {
+        character: char,
+    },
+}
+
+impl UiEvent {
+    /// 포인터 이벤트의 좌표 (키보드 이벤트는 `None`)
+    pub fn position(&self) -> Option<(f32, f32)> {
+        match *self {
+            | UiEvent::PointerMove { x, y }
+            | UiEvent::PointerDown { x, y, .. }
+            | UiEvent::PointerUp { x, y, .. }
+            | UiEvent::PointerEnter { x, y }
+            | UiEvent::PointerLeave { x, y }
+            | UiEvent::PointerDownOutside { x, y, .. }
+            | UiEvent::Wheel { x, y, .. } => Some((x, y)),
+            UiEvent::KeyDown { .. } | UiEvent::KeyUp { .. } | UiEvent::Char { .. } => None,
+        }
+    }
+
+    pub fn is_keyboard(&self) -> bool {
+        self.position().is_none()
+    }
+
+    /// 좌표를 (dx, dy)만큼 옮긴 이벤트 (자식 좌표계로 변환할 때 사용)
+    pub fn translated(&self, dx: f32, dy: f32) -> Self {
+        self.mapped(|x, y| (x + dx, y + dy))
+    }
+
+    /// 포인터 좌표를 `map`으로 바꾼 이벤트 (변환된 위젯의 자식 좌표계로 바꿀 때 사용)
+    pub fn mapped(&self, map: impl Fn(f32, f32) -> (f32, f32)) -> Self {
+        let mut event = *self;
+        match &mut event {
+            | UiEvent::PointerMove { x, y }
+            | UiEvent::PointerDown { x, y, .. }
+            | UiEvent::PointerUp { x, y, .. }
+            | UiEvent::PointerEnter { x, y }
+            | UiEvent::PointerLeave { x, y }
+            | UiEvent::PointerDownOutside { x, y, .. }
+            | UiEvent::Wheel { x, y, .. } => {
+                (*x, *y) = map(*x, *y);
+            }
+            UiEvent::KeyDown { .. } | UiEvent::KeyUp { .. } | UiEvent::Char { .. } => (),
+        }
+        event
+    }
+}
+
+/// 이벤트 전파 단계.
+///
+/// 대상 위젯까지의 경로에서 조상 위젯은 먼저 `Capture` 단계로, 대상 위젯은 `Target` 단계로,
+/// 다시 조상 위젯은 안쪽부터 `Bubble` 단계로 이벤트를 받습니다. 어느 단계에서든 핸들러가
+/// `true`를 반환하면 전파가 멈춥니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventPhase {
+    Capture,
+    Target,
+    Bubble,
+}
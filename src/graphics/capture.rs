@@ -0,0 +1,56 @@
+use gl::types::*;
+use image::{ imageops, ImageFormat, RgbaImage };
+use std::path::Path;
+
+/// 프레임버퍼의 (x, y, width, height) 영역을 읽어 위쪽 행부터 저장된 이미지로 돌려줍니다.
+/// 좌표는 OpenGL 기준(왼쪽 아래가 원점)이며, `premultiplied`이면 알파로 나눠 일반 RGBA로 바꿉니다.
+pub(crate) fn read_pixels(
+    framebuffer: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    premultiplied: bool
+) -> RgbaImage {
+    let mut pixels = vec![0u8; (width as usize) * (height as usize) * 4];
+    if !pixels.is_empty() {
+        unsafe {
+            // 현재 바인딩된 읽기 프레임버퍼를 바꾸지 않도록 되돌립니다.
+            let mut previous = 0;
+            gl::GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut previous);
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer);
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(
+                x,
+                y,
+                width as GLsizei,
+                height as GLsizei,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                pixels.as_mut_ptr() as *mut _
+            );
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, previous as GLuint);
+        }
+    }
+
+    let mut image = RgbaImage::from_raw(width, height, pixels).expect("pixel buffer size matches");
+    // OpenGL은 아래쪽 행부터 돌려주므로 위아래를 뒤집습니다.
+    imageops::flip_vertical_in_place(&mut image);
+
+    if premultiplied {
+        for pixel in image.pixels_mut() {
+            let alpha = pixel[3] as u32;
+            if alpha > 0 && alpha < 255 {
+                for channel in 0..3 {
+                    pixel[channel] = ((pixel[channel] as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+                }
+            }
+        }
+    }
+    image
+}
+
+/// 캡처한 이미지를 PNG 파일로 저장합니다.
+pub fn save_png(image: &RgbaImage, path: impl AsRef<Path>) -> Result<(), String> {
+    image.save_with_format(path, ImageFormat::Png).map_err(|e| e.to_string())
+}
@@ -0,0 +1,409 @@
+use super::transform::Transform;
+
+/// 드로우 리스트가 사용하는 공용 정점 형식 (위치, 텍스처 좌표, 색상, 가장자리 좌표, 둥근 사각형 모양)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Vertex {
+    pub position: [f32; 2],
+    pub tex_coord: [f32; 2],
+    pub color: [f32; 4],
+    /// 경로 안티에일리어싱용 좌표. 선의 중심(또는 채우기 안쪽)에서의 벡터로, 길이가 0이면 안쪽,
+    /// 1이면 그려진 기하의 바깥 가장자리입니다. 경로 셰이더만 사용합니다.
+    pub edge: [f32; 2],
+    /// 둥근 사각형의 (반너비, 반높이, 테두리 두께, 그림자 흐림 폭). 둥근 사각형 셰이더만 사용하며,
+    /// 모양을 정점마다 넘기므로 크기와 테두리가 다른 사각형도 하나의 배치로 그려집니다.
+    pub rect: [f32; 4],
+    /// 둥근 사각형의 모서리 반지름 (왼쪽 위, 오른쪽 위, 오른쪽 아래, 왼쪽 아래)
+    pub radii: [f32; 4],
+    /// 둥근 사각형의 테두리 색
+    pub border_color: [f32; 4],
+}
+
+impl Vertex {
+    pub fn new(x: f32, y: f32, u: f32, v: f32, color: [f32; 4]) -> Self {
+        Self {
+            position: [x, y],
+            tex_coord: [u, v],
+            color,
+            edge: [0.0; 2],
+            rect: [0.0; 4],
+            radii: [0.0; 4],
+            border_color: [0.0; 4],
+        }
+    }
+
+    pub fn with_edge(mut self, edge: [f32; 2]) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    pub fn with_rounded_rect(mut self, rect: [f32; 4], radii: [f32; 4], border_color: [f32; 4]) -> Self {
+        self.rect = rect;
+        self.radii = radii;
+        self.border_color = border_color;
+        self
+    }
+}
+
+/// 배치를 그릴 때 사용할 셰이더 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchKind {
+    /// 정점 색상만으로 채우는 도형 (사각형, 삼각형, 원)
+    Solid,
+    /// RGBA 텍스처에 정점 색상을 곱해서 그리는 사각형 (이미지)
+    Texture,
+    /// 단일 채널 글리프 아틀라스를 커버리지로 사용하는 텍스트
+    Text,
+    /// 거리 필드 아틀라스를 사용하는 텍스트. `style`은 렌더러가 프레임마다 모아 두는
+    /// 효과(외곽선, 글로우, 그림자) 목록의 인덱스이며, 효과가 다르면 배치가 나뉩니다.
+    SdfText {
+        style: u32,
+    },
+    /// 테셀레이션한 벡터 경로 (채우기, 선). 정점의 `edge`로 가장자리를 부드럽게 그리며,
+    /// 그라디언트로 칠할 때는 `paint`에 `Paint` 목록의 인덱스가 들어갑니다.
+    Path {
+        paint: Option<u32>,
+    },
+    /// 그라디언트로 채우는 도형. `paint`는 렌더러가 프레임마다 모아 두는 `Paint` 목록의 인덱스이며,
+    /// 텍스처 좌표에는 도형 경계 사각형 기준 비율 좌표가 들어갑니다.
+    Gradient {
+        paint: u32,
+    },
+    /// 오프스크린 레이어 텍스처(알파를 곱한 색)를 합성하는 사각형. `effect`는 렌더러가 프레임마다 모아 두는
+    /// 흐림/채도 효과 목록의 인덱스이며, 불투명도는 정점 색의 알파로 전달됩니다.
+    Layer {
+        effect: u32,
+    },
+    /// 거리 함수로 그리는 둥근 사각형, 테두리, 그림자. 크기/반지름/테두리는 정점의 `rect`, `radii`,
+    /// `border_color`로 전달하고, 텍스처 좌표에는 사각형 중심 기준 좌표가 들어갑니다.
+    /// 그라디언트로 채울 때는 `paint`에 `Paint` 목록의 인덱스가 들어갑니다.
+    RoundedRect {
+        paint: Option<u32>,
+    },
+}
+
+/// 같은 배치로 합칠 수 있는지 판단하는 렌더 상태
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchState {
+    pub kind: BatchKind,
+    pub texture_id: u32,
+}
+
+/// 한 번의 드로우 콜로 그려지는 인덱스 묶음
+pub struct DrawBatch {
+    pub state: BatchState,
+    /// 렌더러가 프레임마다 모아 두는 클립 목록의 인덱스 (`None`이면 잘라내지 않음)
+    pub clip: Option<u32>,
+    indices: Vec<u32>,
+    bounds: [f32; 4], // 배치에 포함된 정점들의 화면 좌표 경계 (x0, y0, x1, y1)
+    index_offset: usize,
+}
+
+impl DrawBatch {
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// 플러시 시 합쳐진 인덱스 버퍼 안에서 이 배치가 시작하는 위치
+    pub fn index_offset(&self) -> usize {
+        self.index_offset
+    }
+}
+
+// 같은 상태의 배치를 찾을 때 되돌아볼 최대 배치 수
+const MAX_BATCH_LOOKBACK: usize = 16;
+
+/// 한 프레임 동안의 도형, 이미지, 텍스트 쿼드를 모아 두는 즉시 모드 드로우 리스트.
+///
+/// 모든 정점은 하나의 공용 VBO/EBO에 쌓이고, 셰이더와 텍스처가 같은 프리미티브는 하나의
+/// 배치로 합쳐집니다. 새 프리미티브는 같은 상태의 이전 배치로 옮겨질 수 있는데, 그 사이에 있는
+/// 배치들과 화면 영역이 겹치지 않을 때만 옮기므로 그리는 순서(겹침 결과)는 유지됩니다.
+/// 클립이 다른 프리미티브는 같은 배치로 합쳐지지 않습니다.
+pub struct DrawList {
+    vertices: Vec<Vertex>,
+    batches: Vec<DrawBatch>,
+    masks: Vec<DrawBatch>, // 스텐실에만 그리는 클립 모양 (배치 인덱스 뒤에 업로드)
+    transform: Transform, // 추가되는 모든 정점에 적용하는 변환
+    clip: Option<u32>, // 추가되는 배치에 붙는 클립
+    vao: u32,
+    vbo: u32,
+    ebo: u32,
+}
+
+impl DrawList {
+    pub fn new() -> Self {
+        let (mut vao, mut vbo, mut ebo) = (0, 0, 0);
+        let stride = std::mem::size_of::<Vertex>() as i32;
+
+        unsafe {
+            gl::GenVertexArrays(1, &mut vao);
+            gl::GenBuffers(1, &mut vbo);
+            gl::GenBuffers(1, &mut ebo);
+
+            gl::BindVertexArray(vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
+
+            // location 0 = position (vec2), 1 = tex coord (vec2), 2 = color (vec4), 3 = edge (vec2),
+            // 4 = rect (vec4), 5 = radii (vec4), 6 = border color (vec4)
+            gl::VertexAttribPointer(0, 2, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(
+                1,
+                2,
+                gl::FLOAT,
+                gl::FALSE,
+                stride,
+                (2 * std::mem::size_of::<f32>()) as *const _
+            );
+            gl::EnableVertexAttribArray(1);
+            gl::VertexAttribPointer(
+                2,
+                4,
+                gl::FLOAT,
+                gl::FALSE,
+                stride,
+                (4 * std::mem::size_of::<f32>()) as *const _
+            );
+            gl::EnableVertexAttribArray(2);
+            gl::VertexAttribPointer(
+                3,
+                2,
+                gl::FLOAT,
+                gl::FALSE,
+                stride,
+                (8 * std::mem::size_of::<f32>()) as *const _
+            );
+            gl::EnableVertexAttribArray(3);
+            for (location, offset) in [(4, 10), (5, 14), (6, 18)] {
+                gl::VertexAttribPointer(
+                    location,
+                    4,
+                    gl::FLOAT,
+                    gl::FALSE,
+                    stride,
+                    (offset * std::mem::size_of::<f32>()) as *const _
+                );
+                gl::EnableVertexAttribArray(location);
+            }
+
+            gl::BindVertexArray(0);
+        }
+
+        Self {
+            vertices: Vec::new(),
+            batches: Vec::new(),
+            masks: Vec::new(),
+            transform: Transform::IDENTITY,
+            clip: None,
+            vao,
+            vbo,
+            ebo,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+
+    pub fn batches(&self) -> &[DrawBatch] {
+        &self.batches
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// 이후 추가되는 정점에 적용할 변환을 설정합니다.
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// 이후 추가되는 배치를 잘라낼 클립 (렌더러의 클립 목록 인덱스)을 설정합니다.
+    pub fn set_clip(&mut self, clip: Option<u32>) {
+        self.clip = clip;
+    }
+
+    pub fn clip(&self) -> Option<u32> {
+        self.clip
+    }
+
+    /// 정점과 (정점 배열 기준) 인덱스를 드로우 리스트에 추가합니다.
+    pub fn push(&mut self, state: BatchState, vertices: &[Vertex], indices: &[u32]) {
+        if vertices.is_empty() || indices.is_empty() {
+            return;
+        }
+
+        let transform = self.transform;
+        let vertices: Vec<Vertex> = vertices
+            .iter()
+            .map(|v| {
+                let (x, y) = transform.apply(v.position[0], v.position[1]);
+                Vertex { position: [x, y], ..*v }
+            })
+            .collect();
+
+        let bounds = vertices.iter().fold(
+            [f32::MAX, f32::MAX, f32::MIN, f32::MIN],
+            |b, v| [
+                b[0].min(v.position[0]),
+                b[1].min(v.position[1]),
+                b[2].max(v.position[0]),
+                b[3].max(v.position[1]),
+            ]
+        );
+
+        let base = self.vertices.len() as u32;
+        self.vertices.extend_from_slice(&vertices);
+
+        let batch_index = match self.find_batch(state, &bounds) {
+            Some(index) => index,
+            None => {
+                self.batches.push(DrawBatch {
+                    state,
+                    clip: self.clip,
+                    indices: Vec::new(),
+                    bounds,
+                    index_offset: 0,
+                });
+                self.batches.len() - 1
+            }
+        };
+
+        let batch = &mut self.batches[batch_index];
+        batch.indices.extend(indices.iter().map(|i| base + i));
+        batch.bounds = [
+            batch.bounds[0].min(bounds[0]),
+            batch.bounds[1].min(bounds[1]),
+            batch.bounds[2].max(bounds[2]),
+            batch.bounds[3].max(bounds[3]),
+        ];
+    }
+
+    /// 화면 좌표 사각형 (x0, y0, x1, y1)과 UV 사각형 (u0, v0, u1, v1)으로 쿼드를 추가합니다.
+    pub fn push_quad(&mut self, state: BatchState, rect: [f32; 4], uv: [f32; 4], color: [f32; 4]) {
+        let [x0, y0, x1, y1] = rect;
+        let [u0, v0, u1, v1] = uv;
+        let vertices = [
+            Vertex::new(x0, y0, u0, v0, color), // 좌측 상단
+            Vertex::new(x1, y0, u1, v0, color), // 우측 상단
+            Vertex::new(x1, y1, u1, v1, color), // 우측 하단
+            Vertex::new(x0, y1, u0, v1, color), // 좌측 하단
+        ];
+        self.push(state, &vertices, &[0, 1, 2, 2, 3, 0]);
+    }
+
+    /// 스텐실에 그릴 클립 모양을 추가하고 마스크 인덱스를 반환합니다.
+    /// 정점은 이미 화면 좌표이므로 변환을 적용하지 않습니다.
+    pub fn push_mask(&mut self, vertices: &[Vertex], indices: &[u32]) -> u32 {
+        let base = self.vertices.len() as u32;
+        self.vertices.extend_from_slice(vertices);
+        self.masks.push(DrawBatch {
+            state: BatchState { kind: BatchKind::Solid, texture_id: 0 },
+            clip: None,
+            indices: indices
+                .iter()
+                .map(|i| base + i)
+                .collect(),
+            bounds: [0.0; 4],
+            index_offset: 0,
+        });
+        (self.masks.len() - 1) as u32
+    }
+
+    pub fn mask(&self, index: u32) -> &DrawBatch {
+        &self.masks[index as usize]
+    }
+
+    fn find_batch(&self, state: BatchState, bounds: &[f32; 4]) -> Option<usize> {
+        for (index, batch) in self.batches.iter().enumerate().rev().take(MAX_BATCH_LOOKBACK) {
+            if batch.state == state && batch.clip == self.clip {
+                return Some(index);
+            }
+            // 뒤에 그려질 배치와 겹치면 앞으로 옮길 수 없음
+            if overlaps(&batch.bounds, bounds) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// 쌓인 정점과 인덱스를 GPU 버퍼에 올리고, 각 배치의 인덱스 오프셋을 확정합니다.
+    /// 업로드 후 VAO가 바인딩된 상태로 반환됩니다.
+    pub fn upload(&mut self) {
+        let mut indices: Vec<u32> = Vec::with_capacity(
+            self.batches
+                .iter()
+                .chain(&self.masks)
+                .map(|b| b.indices.len())
+                .sum()
+        );
+        for batch in self.batches.iter_mut().chain(self.masks.iter_mut()) {
+            batch.index_offset = indices.len();
+            indices.extend_from_slice(&batch.indices);
+        }
+
+        unsafe {
+            gl::BindVertexArray(self.vao);
+
+            // 같은 버퍼 객체를 재사용하면서 저장 공간만 새로 지정 (orphaning)
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (self.vertices.len() * std::mem::size_of::<Vertex>()) as isize,
+                self.vertices.as_ptr() as *const _,
+                gl::STREAM_DRAW
+            );
+
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.ebo);
+            gl::BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                (indices.len() * std::mem::size_of::<u32>()) as isize,
+                indices.as_ptr() as *const _,
+                gl::STREAM_DRAW
+            );
+        }
+    }
+
+    /// 업로드된 배치 하나를 그립니다. `upload` 이후, 셰이더와 텍스처를 바인딩한 뒤 호출합니다.
+    pub fn draw_batch(&self, batch: &DrawBatch) {
+        unsafe {
+            gl::DrawElements(
+                gl::TRIANGLES,
+                batch.index_count() as i32,
+                gl::UNSIGNED_INT,
+                (batch.index_offset() * std::mem::size_of::<u32>()) as *const _
+            );
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.batches.clear();
+        self.masks.clear();
+    }
+}
+
+impl Default for DrawList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for DrawList {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteBuffers(1, &self.ebo);
+            gl::DeleteVertexArrays(1, &self.vao);
+        }
+    }
+}
+
+fn overlaps(a: &[f32; 4], b: &[f32; 4]) -> bool {
+    a[0] < b[2] && b[0] < a[2] && a[1] < b[3] && b[1] < a[3]
+}
@@ -0,0 +1,168 @@
+use gl::types::*;
+use log::error;
+
+/// 오프스크린 프레임버퍼(FBO)와 그 결과를 담는 텍스처.
+///
+/// `Renderer::render_to_layer`로 한 번 그려 두고 `Renderer::draw_layer`로 여러 프레임에 걸쳐 다시 그리면
+/// 잘 바뀌지 않는 패널을 매번 다시 그리지 않아도 됩니다. 텍스처에는 알파를 곱한(premultiplied) 색이
+/// 저장되며, 클립을 위한 스텐실 버퍼가 함께 붙어 있습니다.
+pub struct Layer {
+    framebuffer: GLuint,
+    texture: GLuint,
+    depth_stencil: GLuint,
+    width: u32,
+    height: u32,
+}
+
+impl Layer {
+    pub fn new(width: u32, height: u32) -> Self {
+        let (mut framebuffer, mut texture, mut depth_stencil) = (0, 0, 0);
+        unsafe {
+            gl::GenFramebuffers(1, &mut framebuffer);
+            gl::GenTextures(1, &mut texture);
+            gl::GenRenderbuffers(1, &mut depth_stencil);
+
+            gl::BindTexture(gl::TEXTURE_2D, texture);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as i32);
+            // 흐림 효과가 가장자리 밖을 읽으면 투명하게 보이도록 테두리 색(기본값 투명)을 사용
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_BORDER as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_BORDER as i32);
+        }
+
+        let mut layer = Self { framebuffer, texture, depth_stencil, width: 0, height: 0 };
+        layer.resize(width, height);
+        layer
+    }
+
+    /// 크기가 바뀌면 저장 공간을 다시 할당합니다 (기존 내용은 사라짐).
+    pub fn resize(&mut self, width: u32, height: u32) {
+        let (width, height) = (width.max(1), height.max(1));
+        if (width, height) == (self.width, self.height) {
+            return;
+        }
+        self.width = width;
+        self.height = height;
+
+        unsafe {
+            gl::BindTexture(gl::TEXTURE_2D, self.texture);
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                gl::RGBA8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                std::ptr::null()
+            );
+
+            gl::BindRenderbuffer(gl::RENDERBUFFER, self.depth_stencil);
+            gl::RenderbufferStorage(
+                gl::RENDERBUFFER,
+                gl::DEPTH24_STENCIL8,
+                width as i32,
+                height as i32
+            );
+
+            // 현재 바인딩된 프레임버퍼를 바꾸지 않도록 되돌립니다.
+            let mut previous = 0;
+            gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut previous);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.framebuffer);
+            gl::FramebufferTexture2D(
+                gl::FRAMEBUFFER,
+                gl::COLOR_ATTACHMENT0,
+                gl::TEXTURE_2D,
+                self.texture,
+                0
+            );
+            gl::FramebufferRenderbuffer(
+                gl::FRAMEBUFFER,
+                gl::DEPTH_STENCIL_ATTACHMENT,
+                gl::RENDERBUFFER,
+                self.depth_stencil
+            );
+            let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+            if status != gl::FRAMEBUFFER_COMPLETE {
+                error!("Layer framebuffer is incomplete (status: 0x{:x})", status);
+            }
+            gl::BindFramebuffer(gl::FRAMEBUFFER, previous as GLuint);
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// 결과 텍스처 ID (`Renderer::draw_textured_rect`에는 알파를 곱한 색이므로 `draw_layer`를 권장)
+    pub fn texture_id(&self) -> u32 {
+        self.texture
+    }
+
+    pub(crate) fn framebuffer_id(&self) -> u32 {
+        self.framebuffer
+    }
+}
+
+impl Drop for Layer {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteFramebuffers(1, &self.framebuffer);
+            gl::DeleteRenderbuffers(1, &self.depth_stencil);
+            gl::DeleteTextures(1, &self.texture);
+        }
+    }
+}
+
+/// 레이어를 화면에 합성할 때의 효과
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayerEffect {
+    /// 레이어 전체의 불투명도. 겹친 내용이 서로 비치지 않고 한 장의 그림처럼 옅어집니다.
+    pub opacity: f32,
+    /// 가우시안 흐림의 표준편차 (픽셀, CSS `filter: blur()`와 같은 의미). 0이면 흐리지 않습니다.
+    pub blur: f32,
+    /// 채도 배율. 0이면 흑백, 1이면 원래 색입니다.
+    pub saturation: f32,
+}
+
+impl Default for LayerEffect {
+    fn default() -> Self {
+        Self { opacity: 1.0, blur: 0.0, saturation: 1.0 }
+    }
+}
+
+impl LayerEffect {
+    /// 불투명도만 적용하는 효과 (그룹 불투명도)
+    pub fn opacity(opacity: f32) -> Self {
+        Self { opacity: opacity.clamp(0.0, 1.0), ..Self::default() }
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_blur(mut self, blur: f32) -> Self {
+        self.blur = blur.max(0.0);
+        self
+    }
+
+    pub fn with_saturation(mut self, saturation: f32) -> Self {
+        self.saturation = saturation.max(0.0);
+        self
+    }
+
+    // 흐림 효과가 번지는 거리 (픽셀). 레이어를 이만큼 넓게 잡아야 가장자리가 잘리지 않습니다.
+    pub(crate) fn spread(&self) -> f32 {
+        (self.blur * 3.0).ceil()
+    }
+}
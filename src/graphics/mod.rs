@@ -1,7 +1,21 @@
+pub mod capture;
+pub mod draw_list;
+pub mod layer;
+pub mod paint;
+pub mod path;
 pub mod renderer;
+pub mod rounded_rect;
 pub mod shader;
 pub mod texture;
+pub mod transform;
 
+pub use capture::save_png;
+pub use draw_list::DrawList;
+pub use layer::{ Layer, LayerEffect };
+pub use paint::{ GradientStop, Paint, MAX_GRADIENT_STOPS };
+pub use path::{ FillRule, LineCap, LineJoin, Path, PathCommand, StrokeStyle };
 pub use renderer::Renderer;
+pub use rounded_rect::{ BoxShadow, CornerRadius };
 pub use shader::Shader;
 pub use texture::Texture;
+pub use transform::Transform;
@@ -0,0 +1,143 @@
+use crate::style::color::Color;
+use log::warn;
+
+/// 그라디언트 하나가 가질 수 있는 최대 색 정지점 수 (셰이더 uniform 배열 크기)
+pub const MAX_GRADIENT_STOPS: usize = 8;
+
+/// 그라디언트의 색 정지점. `offset`은 0.0(시작) ~ 1.0(끝) 위치입니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color: [f32; 4],
+}
+
+impl GradientStop {
+    pub fn new(offset: f32, color: [f32; 4]) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// 도형을 채우는 방법: 단색 또는 선형/원형/원뿔형 그라디언트.
+///
+/// 그라디언트의 좌표는 그리는 도형의 경계 사각형 기준 비율입니다. (0, 0)이 왼쪽 위, (1, 1)이
+/// 오른쪽 아래이므로 같은 `Paint`를 크기가 다른 도형에 그대로 쓸 수 있습니다.
+/// 정지점 사이의 색은 알파를 곱한 상태로 보간하므로 투명한 정지점 주변이 회색으로 물들지 않습니다.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Paint {
+    Solid([f32; 4]),
+    /// `start`에서 `end`로 이어지는 직선 방향의 그라디언트
+    Linear {
+        start: [f32; 2],
+        end: [f32; 2],
+        stops: Vec<GradientStop>,
+    },
+    /// `center`에서 바깥쪽으로 퍼지는 그라디언트. `radius`는 가로/세로 반지름입니다 (타원).
+    Radial {
+        center: [f32; 2],
+        radius: [f32; 2],
+        stops: Vec<GradientStop>,
+    },
+    /// `center`를 중심으로 시계 방향으로 도는 그라디언트. `start_angle`은 위쪽(12시)에서부터의 라디안입니다.
+    Conic {
+        center: [f32; 2],
+        start_angle: f32,
+        stops: Vec<GradientStop>,
+    },
+}
+
+impl Paint {
+    pub fn solid(color: [f32; 4]) -> Self {
+        Paint::Solid(color)
+    }
+
+    pub fn linear_gradient(start: [f32; 2], end: [f32; 2], stops: Vec<GradientStop>) -> Self {
+        Paint::Linear { start, end, stops: normalize_stops(stops) }
+    }
+
+    pub fn radial_gradient(center: [f32; 2], radius: [f32; 2], stops: Vec<GradientStop>) -> Self {
+        Paint::Radial { center, radius, stops: normalize_stops(stops) }
+    }
+
+    pub fn conic_gradient(center: [f32; 2], start_angle: f32, stops: Vec<GradientStop>) -> Self {
+        Paint::Conic { center, start_angle, stops: normalize_stops(stops) }
+    }
+
+    /// 위에서 아래로 `top` → `bottom` 두 색의 선형 그라디언트
+    pub fn vertical_gradient(top: [f32; 4], bottom: [f32; 4]) -> Self {
+        Self::linear_gradient(
+            [0.0, 0.0],
+            [0.0, 1.0],
+            vec![GradientStop::new(0.0, top), GradientStop::new(1.0, bottom)]
+        )
+    }
+
+    /// 왼쪽에서 오른쪽으로 `left` → `right` 두 색의 선형 그라디언트
+    pub fn horizontal_gradient(left: [f32; 4], right: [f32; 4]) -> Self {
+        Self::linear_gradient(
+            [0.0, 0.0],
+            [1.0, 0.0],
+            vec![GradientStop::new(0.0, left), GradientStop::new(1.0, right)]
+        )
+    }
+
+    pub fn is_solid(&self) -> bool {
+        matches!(self, Paint::Solid(_))
+    }
+
+    pub fn stops(&self) -> &[GradientStop] {
+        match self {
+            Paint::Solid(_) => &[],
+            Paint::Linear { stops, .. } | Paint::Radial { stops, .. } | Paint::Conic { stops, .. } => {
+                stops
+            }
+        }
+    }
+
+    /// 모든 색의 알파에 `opacity`를 곱한 복사본 (위젯 투명도 적용)
+    pub fn with_opacity(&self, opacity: f32) -> Self {
+        let mut paint = self.clone();
+        match &mut paint {
+            Paint::Solid(color) => {
+                color[3] *= opacity;
+            }
+            Paint::Linear { stops, .. } | Paint::Radial { stops, .. } | Paint::Conic { stops, .. } => {
+                for stop in stops.iter_mut() {
+                    stop.color[3] *= opacity;
+                }
+            }
+        }
+        paint
+    }
+}
+
+impl From<[f32; 4]> for Paint {
+    fn from(color: [f32; 4]) -> Self {
+        Paint::Solid(color)
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Self {
+        Paint::Solid(color.to_array())
+    }
+}
+
+impl From<&Paint> for Paint {
+    fn from(paint: &Paint) -> Self {
+        paint.clone()
+    }
+}
+
+// 정지점을 위치 순서로 정렬하고 셰이더가 받을 수 있는 개수로 자릅니다.
+fn normalize_stops(mut stops: Vec<GradientStop>) -> Vec<GradientStop> {
+    stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    if stops.len() > MAX_GRADIENT_STOPS {
+        warn!(
+            "Gradient has {} stops; only the first {} are used",
+            stops.len(),
+            MAX_GRADIENT_STOPS
+        );
+        stops.truncate(MAX_GRADIENT_STOPS);
+    }
+    stops
+}
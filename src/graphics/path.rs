@@ -0,0 +1,723 @@
+use super::draw_list::Vertex;
+use log::warn;
+use lyon_tessellation::math::point;
+use lyon_tessellation::path::Path as LyonPath;
+use lyon_tessellation::{
+    BuffersBuilder,
+    FillOptions,
+    FillTessellator,
+    FillVertex,
+    Side,
+    StrokeOptions,
+    StrokeTessellator,
+    StrokeVertex,
+    VertexBuffers,
+};
+use std::f32::consts::{ FRAC_PI_2, PI, TAU };
+
+// 곡선을 선분으로 나눌 때 허용하는 최대 오차 (픽셀)
+const FLATTEN_TOLERANCE: f32 = 0.25;
+
+/// 경로를 이루는 명령. 좌표는 `Renderer`의 그리기 좌표(픽셀)입니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathCommand {
+    MoveTo([f32; 2]),
+    LineTo([f32; 2]),
+    /// 제어점, 끝점
+    QuadTo([f32; 2], [f32; 2]),
+    /// 제어점 1, 제어점 2, 끝점
+    CubicTo([f32; 2], [f32; 2], [f32; 2]),
+    Close,
+}
+
+/// 직선, 베지어 곡선, 원호로 이루어진 벡터 경로.
+///
+/// HTML Canvas의 경로와 같은 방식으로 만듭니다. `move_to`로 새 하위 경로를 시작하고, 현재 점이 없을 때의
+/// `line_to`는 `move_to`처럼 동작합니다. `Renderer::fill_path`와 `Renderer::stroke_path`로 그립니다.
+///
+/// ```ignore
+/// let mut path = Path::new();
+/// path.move_to(10.0, 10.0).line_to(100.0, 10.0).quad_to(120.0, 50.0, 100.0, 90.0).close();
+/// renderer.fill_path(&path, FillRule::NonZero, [0.2, 0.6, 1.0, 1.0]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    commands: Vec<PathCommand>,
+    start: [f32; 2], // 현재 하위 경로의 시작점 (close가 돌아갈 위치)
+    current: Option<[f32; 2]>,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.commands.push(PathCommand::MoveTo([x, y]));
+        self.start = [x, y];
+        self.current = Some([x, y]);
+        self
+    }
+
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        if self.current.is_none() {
+            return self.move_to(x, y);
+        }
+        self.commands.push(PathCommand::LineTo([x, y]));
+        self.current = Some([x, y]);
+        self
+    }
+
+    /// 2차 베지어 곡선 (제어점 (cx, cy))
+    pub fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> &mut Self {
+        if self.current.is_none() {
+            self.move_to(cx, cy);
+        }
+        self.commands.push(PathCommand::QuadTo([cx, cy], [x, y]));
+        self.current = Some([x, y]);
+        self
+    }
+
+    /// 3차 베지어 곡선 (제어점 (c1x, c1y), (c2x, c2y))
+    pub fn cubic_to(&mut self, c1x: f32, c1y: f32, c2x: f32, c2y: f32, x: f32, y: f32) -> &mut Self {
+        if self.current.is_none() {
+            self.move_to(c1x, c1y);
+        }
+        self.commands.push(PathCommand::CubicTo([c1x, c1y], [c2x, c2y], [x, y]));
+        self.current = Some([x, y]);
+        self
+    }
+
+    /// (cx, cy)를 중심으로 `start_angle`에서 `end_angle`까지의 원호 (라디안, 0은 3시 방향).
+    ///
+    /// 화면 좌표는 y가 아래쪽이므로 각도가 커지는 방향이 시계 방향이며, `end_angle`이 더 작으면
+    /// 반시계 방향으로 그립니다. 현재 점이 있으면 원호의 시작점까지 직선으로 잇습니다.
+    pub fn arc(
+        &mut self,
+        cx: f32,
+        cy: f32,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32
+    ) -> &mut Self {
+        let at = |angle: f32| [cx + radius * angle.cos(), cy + radius * angle.sin()];
+        let [sx, sy] = at(start_angle);
+        if self.current.is_some() {
+            self.line_to(sx, sy);
+        } else {
+            self.move_to(sx, sy);
+        }
+
+        // 90도 이하의 조각으로 나눠 각각을 3차 베지어로 근사합니다.
+        let sweep = (end_angle - start_angle).clamp(-TAU, TAU);
+        let segments = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / (segments as f32);
+        let k = (4.0 / 3.0) * (step / 4.0).tan() * radius;
+        for i in 0..segments {
+            let a0 = start_angle + step * (i as f32);
+            let a1 = a0 + step;
+            let (p0, p3) = (at(a0), at(a1));
+            self.cubic_to(
+                p0[0] - k * a0.sin(),
+                p0[1] + k * a0.cos(),
+                p3[0] + k * a1.sin(),
+                p3[1] - k * a1.cos(),
+                p3[0],
+                p3[1]
+            );
+        }
+        self
+    }
+
+    /// 현재 하위 경로를 시작점까지 닫습니다.
+    pub fn close(&mut self) -> &mut Self {
+        if self.current.is_some() {
+            self.commands.push(PathCommand::Close);
+            self.current = Some(self.start);
+        }
+        self
+    }
+
+    /// 사각형 하위 경로 (시계 방향)
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32) -> &mut Self {
+        self.move_to(x, y)
+            .line_to(x + width, y)
+            .line_to(x + width, y + height)
+            .line_to(x, y + height)
+            .close()
+    }
+
+    /// 모서리가 둥근 사각형 하위 경로 (시계 방향). `radii`는 왼쪽 위, 오른쪽 위, 오른쪽 아래, 왼쪽 아래 순서이며
+    /// 짧은 변 절반을 넘지 않도록 제한됩니다.
+    pub fn rounded_rect(&mut self, x: f32, y: f32, width: f32, height: f32, radii: [f32; 4]) -> &mut Self {
+        let max_radius = (width.min(height) / 2.0).max(0.0);
+        let [tl, tr, br, bl] = radii.map(|radius| radius.clamp(0.0, max_radius));
+        self.move_to(x + tl, y);
+        self.arc(x + width - tr, y + tr, tr, -FRAC_PI_2, 0.0);
+        self.arc(x + width - br, y + height - br, br, 0.0, FRAC_PI_2);
+        self.arc(x + bl, y + height - bl, bl, FRAC_PI_2, PI);
+        self.arc(x + tl, y + tl, tl, PI, PI + FRAC_PI_2);
+        self.close()
+    }
+
+    /// 원 하위 경로 (시계 방향)
+    pub fn circle(&mut self, cx: f32, cy: f32, radius: f32) -> &mut Self {
+        self.move_to(cx + radius, cy);
+        self.arc(cx, cy, radius, 0.0, TAU).close()
+    }
+
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// 곡선을 선분으로 나눈 뒤의 경계 사각형 (x0, y0, x1, y1)
+    pub fn bounds(&self) -> Option<[f32; 4]> {
+        bounds_of(&self.flatten())
+    }
+
+    // 하위 경로마다 곡선을 선분으로 나눈 점 목록
+    pub(crate) fn flatten(&self) -> Vec<Contour> {
+        let mut contours: Vec<Contour> = Vec::new();
+        let mut current = Contour::default();
+        let mut last = [0.0, 0.0];
+
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(p) => {
+                    current.finish_into(&mut contours);
+                    current.push(p);
+                    last = p;
+                }
+                PathCommand::LineTo(p) => {
+                    current.push(p);
+                    last = p;
+                }
+                PathCommand::QuadTo(c, p) => {
+                    // 균등 분할 오차 |p0 - 2c + p| / (8n²)가 허용 오차 이하가 되는 분할 수
+                    let dd = length(sub(add(last, p), scale(c, 2.0)));
+                    let n = segment_count(dd / 8.0);
+                    for i in 1..=n {
+                        let t = (i as f32) / (n as f32);
+                        let mt = 1.0 - t;
+                        current.push(
+                            add(add(scale(last, mt * mt), scale(c, 2.0 * mt * t)), scale(p, t * t))
+                        );
+                    }
+                    last = p;
+                }
+                PathCommand::CubicTo(c1, c2, p) => {
+                    let dd = length(sub(add(last, c2), scale(c1, 2.0))).max(
+                        length(sub(add(c1, p), scale(c2, 2.0)))
+                    );
+                    let n = segment_count(dd * 0.75);
+                    for i in 1..=n {
+                        let t = (i as f32) / (n as f32);
+                        let mt = 1.0 - t;
+                        current.push(
+                            add(
+                                add(scale(last, mt * mt * mt), scale(c1, 3.0 * mt * mt * t)),
+                                add(scale(c2, 3.0 * mt * t * t), scale(p, t * t * t))
+                            )
+                        );
+                    }
+                    last = p;
+                }
+                PathCommand::Close => {
+                    current.closed = true;
+                    let start = current.points.first().copied();
+                    current.finish_into(&mut contours);
+                    // close 뒤에 바로 이어지는 명령은 시작점에서 새 하위 경로를 시작합니다.
+                    if let Some(start) = start {
+                        current.push(start);
+                        last = start;
+                    }
+                }
+            }
+        }
+        current.finish_into(&mut contours);
+        contours
+    }
+}
+
+/// 채우기에서 경로가 겹치는 영역을 안쪽으로 볼지 결정하는 규칙
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// 감긴 횟수(winding number)가 0이 아니면 안쪽. 반대 방향으로 그린 하위 경로가 구멍이 됩니다.
+    #[default]
+    NonZero,
+    /// 경계를 홀수 번 넘으면 안쪽. 방향과 상관없이 겹친 하위 경로가 구멍이 됩니다.
+    EvenOdd,
+}
+
+/// 선이 꺾이는 곳의 모양
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// 열린 선 끝의 모양
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// 끝점에서 잘림
+    #[default]
+    Butt,
+    /// 끝점에 반원을 붙임
+    Round,
+    /// 끝점에서 선 두께의 절반만큼 사각형으로 연장
+    Square,
+}
+
+/// 선 그리기 설정 (두께, 꺾임, 끝 모양, 점선)
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    /// 꺾인 곳의 뾰족한 길이가 두께의 이 배수를 넘으면 `Bevel`로 그립니다.
+    pub miter_limit: f32,
+    /// 그리는 길이와 건너뛰는 길이를 번갈아 적은 점선 패턴 (픽셀). 비어 있으면 실선입니다.
+    pub dash: Vec<f32>,
+    /// 점선 패턴을 시작할 위치 (픽셀)
+    pub dash_offset: f32,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.0,
+            dash: Vec::new(),
+            dash_offset: 0.0,
+        }
+    }
+}
+
+impl StrokeStyle {
+    pub fn new(width: f32) -> Self {
+        Self { width: width.max(0.0), ..Self::default() }
+    }
+
+    pub fn with_join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    pub fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    pub fn with_miter_limit(mut self, miter_limit: f32) -> Self {
+        self.miter_limit = miter_limit.max(1.0);
+        self
+    }
+
+    /// 점선 패턴. 개수가 홀수이면 SVG처럼 패턴을 두 번 이어 붙인 것으로 봅니다.
+    pub fn with_dash(mut self, pattern: Vec<f32>, offset: f32) -> Self {
+        self.dash = pattern;
+        self.dash_offset = offset;
+        self
+    }
+}
+
+// 선분으로 나눈 하위 경로 하나
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Contour {
+    pub points: Vec<[f32; 2]>,
+    pub closed: bool,
+}
+
+impl Contour {
+    // 직전 점과 같은 점은 건너뜁니다 (길이 0인 선분은 테셀레이션에서 방향을 정할 수 없음).
+    fn push(&mut self, p: [f32; 2]) {
+        if self.points.last() != Some(&p) {
+            self.points.push(p);
+        }
+    }
+
+    fn finish_into(&mut self, contours: &mut Vec<Contour>) {
+        let contour = std::mem::take(self);
+        if contour.points.len() >= 2 {
+            contours.push(contour);
+        }
+    }
+}
+
+/// 테셀레이션 결과 (드로우 리스트에 그대로 추가할 정점과 인덱스)
+pub(crate) struct PathMesh {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+}
+
+/// 경로 안쪽을 삼각형으로 나누고, 가장자리를 따라 1픽셀 폭의 부드러운 테두리(fringe)를 덧붙입니다.
+/// 텍스처 좌표에는 경로 경계 사각형 기준 비율 좌표(그라디언트용)가 들어갑니다.
+pub(crate) fn tessellate_fill(path: &Path, rule: FillRule, color: [f32; 4]) -> Option<PathMesh> {
+    let contours = path.flatten();
+    let bounds = bounds_of(&contours)?;
+    let uv = uv_mapper(bounds);
+
+    let lyon_path = to_lyon_path(&contours, true);
+    let mut buffers = fill_buffers(&lyon_path, rule, &uv, color)?;
+
+    // 가장자리에서 바깥쪽으로 0.5픽셀 동안 옅어지는 테두리. 채우기와 겹치면 반투명한 색이 두 번 섞여
+    // 가장자리가 진해지므로, 채우기 안쪽을 향하는 정점은 경로 위로 모아 바깥쪽 절반만 그립니다.
+    let options = StrokeOptions::tolerance(FLATTEN_TOLERANCE).with_line_width(1.0);
+    for contour in &contours {
+        let Some(inner) = inner_side(contour, &contours, rule) else {
+            continue;
+        };
+        let contour_path = to_lyon_path(std::slice::from_ref(contour), true);
+        let fringe = fringe_buffers(&contour_path, &options, inner, &uv, color)?;
+        append(&mut buffers, fringe);
+    }
+
+    Some(PathMesh { vertices: buffers.vertices, indices: buffers.indices })
+}
+
+// 윤곽선의 어느 쪽이 채워지는지 (lyon의 `Side::Positive`는 진행 방향 (dx, dy)의 (-dy, dx) 쪽).
+// 첫 선분의 중점에서 조금 떨어진 점이 채우기 규칙으로 안쪽인지 확인하며, 길이가 있는 선분이 없으면 `None`입니다.
+fn inner_side(contour: &Contour, contours: &[Contour], rule: FillRule) -> Option<Side> {
+    let count = contour.points.len();
+    (0..count).find_map(|i| {
+        let a = contour.points[i];
+        let b = contour.points[(i + 1) % count];
+        let direction = sub(b, a);
+        let len = length(direction);
+        if len < 1e-4 {
+            return None;
+        }
+        let normal = [-direction[1] / len, direction[0] / len];
+        let probe = add(scale(add(a, b), 0.5), scale(normal, 1e-2));
+        let winding = winding_number(contours, probe);
+        let inside = match rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        };
+        Some(if inside { Side::Positive } else { Side::Negative })
+    })
+}
+
+// 닫힌 윤곽선들이 점을 감싸는 횟수 (방향을 고려한 합)
+fn winding_number(contours: &[Contour], p: [f32; 2]) -> i32 {
+    let mut winding = 0;
+    for contour in contours {
+        let count = contour.points.len();
+        for i in 0..count {
+            let a = contour.points[i];
+            let b = contour.points[(i + 1) % count];
+            let cross = (b[0] - a[0]) * (p[1] - a[1]) - (p[0] - a[0]) * (b[1] - a[1]);
+            if a[1] <= p[1] && b[1] > p[1] && cross > 0.0 {
+                winding += 1;
+            } else if a[1] > p[1] && b[1] <= p[1] && cross < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+    winding
+}
+
+/// 부드러운 테두리 없이 경로 안쪽만 삼각형으로 나눕니다 (스텐실 클립 마스크).
+/// 삼각형끼리 겹치지 않으므로 스텐실 값을 늘리며 그려도 한 번씩만 늘어납니다.
+pub(crate) fn tessellate_mask(path: &Path) -> Option<PathMesh> {
+    let contours = path.flatten();
+    let uv = uv_mapper(bounds_of(&contours)?);
+    let lyon_path = to_lyon_path(&contours, true);
+    let buffers = fill_buffers(&lyon_path, FillRule::NonZero, &uv, [1.0; 4])?;
+    Some(PathMesh { vertices: buffers.vertices, indices: buffers.indices })
+}
+
+fn fill_buffers(
+    path: &LyonPath,
+    rule: FillRule,
+    uv: &impl Fn([f32; 2]) -> [f32; 2],
+    color: [f32; 4]
+) -> Option<VertexBuffers<Vertex, u32>> {
+    let fill_rule = match rule {
+        FillRule::NonZero => lyon_tessellation::FillRule::NonZero,
+        FillRule::EvenOdd => lyon_tessellation::FillRule::EvenOdd,
+    };
+    let options = FillOptions::tolerance(FLATTEN_TOLERANCE).with_fill_rule(fill_rule);
+
+    let mut buffers: VertexBuffers<Vertex, u32> = VertexBuffers::new();
+    let result = FillTessellator::new().tessellate_path(
+        path,
+        &options,
+        &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+            let [x, y] = vertex.position().to_array();
+            let [u, v] = uv([x, y]);
+            Vertex::new(x, y, u, v, color)
+        })
+    );
+    if let Err(error) = result {
+        warn!("Failed to tessellate path fill: {:?}", error);
+        return None;
+    }
+    Some(buffers)
+}
+
+/// 경로의 선을 삼각형으로 나눕니다. 안티에일리어싱을 위해 양쪽으로 0.5픽셀 넓게 만들고,
+/// 정점의 `edge`에 선 중심에서의 거리를 (넓힌) 두께의 절반 단위로 넣어 셰이더가 가장자리를 옅게 그리도록 합니다.
+pub(crate) fn tessellate_stroke(
+    path: &Path,
+    style: &StrokeStyle,
+    color: [f32; 4]
+) -> Option<PathMesh> {
+    if style.width <= 0.0 {
+        return None;
+    }
+    let mut contours = path.flatten();
+    let bounds = bounds_of(&contours)?;
+    let uv = uv_mapper(bounds);
+    if let Some(pattern) = dash_pattern(&style.dash) {
+        contours = dash_contours(&contours, &pattern, style.dash_offset);
+    }
+
+    let lyon_path = to_lyon_path(&contours, false);
+    let options = StrokeOptions::tolerance(FLATTEN_TOLERANCE)
+        .with_line_width(style.width + 1.0)
+        .with_line_join(match style.join {
+            LineJoin::Miter => lyon_tessellation::LineJoin::Miter,
+            LineJoin::Round => lyon_tessellation::LineJoin::Round,
+            LineJoin::Bevel => lyon_tessellation::LineJoin::Bevel,
+        })
+        .with_line_cap(match style.cap {
+            LineCap::Butt => lyon_tessellation::LineCap::Butt,
+            LineCap::Round => lyon_tessellation::LineCap::Round,
+            LineCap::Square => lyon_tessellation::LineCap::Square,
+        })
+        .with_miter_limit(style.miter_limit.max(1.0));
+
+    let buffers = stroke_buffers(&lyon_path, &options, style.join == LineJoin::Round, &uv, color)?;
+    Some(PathMesh { vertices: buffers.vertices, indices: buffers.indices })
+}
+
+// 선 테셀레이션 중의 정점. 삼각형마다 가장자리 좌표를 정하기 위해 선 위의 점과 법선을 함께 보관합니다.
+struct StrokePoint {
+    vertex: Vertex,
+    on_path: [f32; 2],
+    normal: [f32; 2],
+}
+
+fn stroke_buffers(
+    path: &LyonPath,
+    options: &StrokeOptions,
+    round_join: bool,
+    uv: &impl Fn([f32; 2]) -> [f32; 2],
+    color: [f32; 4]
+) -> Option<VertexBuffers<Vertex, u32>> {
+    let mut points: VertexBuffers<StrokePoint, u32> = VertexBuffers::new();
+    let result = StrokeTessellator::new().tessellate_path(
+        path,
+        options,
+        &mut BuffersBuilder::new(&mut points, |vertex: StrokeVertex| {
+            let [x, y] = vertex.position().to_array();
+            let [u, v] = uv([x, y]);
+            // 곧은 부분의 정점은 모두 선 중심에서 수직으로 선 두께의 절반만큼 떨어진 선 위에 있습니다
+            // (마이터 꼭짓점 포함).
+            let side = match vertex.side() {
+                Side::Positive => 1.0,
+                Side::Negative => -1.0,
+            };
+            StrokePoint {
+                vertex: Vertex::new(x, y, u, v, color).with_edge([side, 0.0]),
+                on_path: vertex.position_on_path().to_array(),
+                normal: vertex.normal().to_array(),
+            }
+        })
+    );
+    if let Err(error) = result {
+        warn!("Failed to tessellate path stroke: {:?}", error);
+        return None;
+    }
+
+    // 둥근 끝과 꺾임은 모든 정점이 원 위에 있어 수직 거리가 삼각형 안에서 일정하므로,
+    // 선 위의 점에서 정점까지의 벡터(법선)를 넘겨 셰이더가 원의 중심에서의 거리를 구하게 합니다.
+    // 꺾임 안쪽 꼭짓점은 원 바깥에 있지만 선의 안쪽 가장자리 위에 있으므로 길이를 1로 줄입니다.
+    // 같은 정점을 곧은 부분과 나눠 쓰므로 이런 삼각형은 정점을 복사합니다.
+    let mut buffers: VertexBuffers<Vertex, u32> = VertexBuffers::new();
+    buffers.vertices = points.vertices.iter().map(|point| point.vertex).collect();
+    for triangle in points.indices.chunks_exact(3) {
+        let corners = [0, 1, 2].map(|i| &points.vertices[triangle[i] as usize]);
+        let same_point = corners.iter().all(|corner| corner.on_path == corners[0].on_path);
+        let on_circle = corners.iter().all(|corner| (length(corner.normal) - 1.0).abs() < 1e-3);
+        if same_point && (round_join || on_circle) {
+            for corner in corners {
+                buffers.indices.push(buffers.vertices.len() as u32);
+                let edge = scale(corner.normal, 1.0 / length(corner.normal).max(1.0));
+                buffers.vertices.push(corner.vertex.with_edge(edge));
+            }
+        } else {
+            buffers.indices.extend_from_slice(triangle);
+        }
+    }
+    Some(buffers)
+}
+
+// 채우기 테두리를 삼각형으로 나눕니다. `inner` 쪽 정점은 경로 위에 두고 `edge`를 0으로,
+// 바깥쪽 정점은 `edge`를 1로 두어 셰이더가 가장자리에서 바깥으로 옅게 그리도록 합니다.
+fn fringe_buffers(
+    path: &LyonPath,
+    options: &StrokeOptions,
+    inner: Side,
+    uv: &impl Fn([f32; 2]) -> [f32; 2],
+    color: [f32; 4]
+) -> Option<VertexBuffers<Vertex, u32>> {
+    let mut buffers: VertexBuffers<Vertex, u32> = VertexBuffers::new();
+    let result = StrokeTessellator::new().tessellate_path(
+        path,
+        options,
+        &mut BuffersBuilder::new(&mut buffers, |vertex: StrokeVertex| {
+            let (position, edge) = if vertex.side() == inner {
+                (vertex.position_on_path(), 0.0)
+            } else {
+                (vertex.position(), 1.0)
+            };
+            let [x, y] = position.to_array();
+            let [u, v] = uv([x, y]);
+            Vertex::new(x, y, u, v, color).with_edge([edge, 0.0])
+        })
+    );
+    if let Err(error) = result {
+        warn!("Failed to tessellate path fill fringe: {:?}", error);
+        return None;
+    }
+    Some(buffers)
+}
+
+fn append(target: &mut VertexBuffers<Vertex, u32>, source: VertexBuffers<Vertex, u32>) {
+    let base = target.vertices.len() as u32;
+    target.vertices.extend(source.vertices);
+    target.indices.extend(source.indices.iter().map(|index| index + base));
+}
+
+fn to_lyon_path(contours: &[Contour], force_closed: bool) -> LyonPath {
+    let mut builder = LyonPath::builder();
+    for contour in contours {
+        let [x, y] = contour.points[0];
+        builder.begin(point(x, y));
+        for &[x, y] in &contour.points[1..] {
+            builder.line_to(point(x, y));
+        }
+        builder.end(force_closed || contour.closed);
+    }
+    builder.build()
+}
+
+// 경계 사각형 기준 비율 좌표로 바꾸는 함수 (그라디언트 좌표)
+fn uv_mapper(bounds: [f32; 4]) -> impl Fn([f32; 2]) -> [f32; 2] {
+    let [x0, y0, x1, y1] = bounds;
+    let (width, height) = ((x1 - x0).max(1e-4), (y1 - y0).max(1e-4));
+    move |[x, y]| [(x - x0) / width, (y - y0) / height]
+}
+
+fn bounds_of(contours: &[Contour]) -> Option<[f32; 4]> {
+    let mut points = contours.iter().flat_map(|contour| contour.points.iter());
+    let &[x, y] = points.next()?;
+    Some(
+        points.fold([x, y, x, y], |b, &[x, y]| [b[0].min(x), b[1].min(y), b[2].max(x), b[3].max(y)])
+    )
+}
+
+// 그릴 수 있는 점선 패턴 (음수나 합이 0인 패턴은 실선으로 봄)
+fn dash_pattern(dash: &[f32]) -> Option<Vec<f32>> {
+    if dash.is_empty() || dash.iter().any(|&length| length < 0.0 || !length.is_finite()) {
+        return None;
+    }
+    if dash.iter().sum::<f32>() <= 0.0 {
+        return None;
+    }
+    let mut pattern = dash.to_vec();
+    if pattern.len() % 2 == 1 {
+        pattern.extend_from_slice(dash);
+    }
+    Some(pattern)
+}
+
+// 하위 경로마다 패턴을 처음부터(`offset` 위치부터) 적용해서 그리는 구간만 열린 선으로 남깁니다.
+fn dash_contours(contours: &[Contour], pattern: &[f32], offset: f32) -> Vec<Contour> {
+    let total: f32 = pattern.iter().sum();
+    let mut dashes = Vec::new();
+
+    for contour in contours {
+        let mut points = contour.points.clone();
+        if contour.closed {
+            points.push(points[0]);
+        }
+
+        // offset만큼 패턴을 진행한 상태에서 시작
+        let mut index = 0;
+        let mut remaining = pattern[0];
+        let mut skip = offset.rem_euclid(total);
+        while skip > 0.0 {
+            if skip < remaining {
+                remaining -= skip;
+                break;
+            }
+            skip -= remaining;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+
+        let mut dash = Contour::default();
+        if index % 2 == 0 {
+            dash.push(points[0]);
+        }
+        for segment in points.windows(2) {
+            let (mut from, to) = (segment[0], segment[1]);
+            let mut length = self::length(sub(to, from));
+            while length > 0.0 {
+                let step = remaining.min(length);
+                let t = step / length;
+                let p = add(from, scale(sub(to, from), t));
+                if index % 2 == 0 {
+                    dash.push(p);
+                }
+                remaining -= step;
+                length -= step;
+                from = p;
+                if remaining <= 0.0 {
+                    if index % 2 == 0 {
+                        dash.finish_into(&mut dashes);
+                    }
+                    index = (index + 1) % pattern.len();
+                    remaining = pattern[index];
+                    if index % 2 == 0 {
+                        dash.push(p);
+                    }
+                }
+            }
+        }
+        dash.finish_into(&mut dashes);
+    }
+    dashes
+}
+
+// 추정 오차(1분할 기준)가 허용 오차 이하가 되도록 하는 분할 수
+fn segment_count(error: f32) -> usize {
+    ((error / FLATTEN_TOLERANCE).sqrt().ceil() as usize).clamp(1, 256)
+}
+
+fn add(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn sub(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn scale(a: [f32; 2], s: f32) -> [f32; 2] {
+    [a[0] * s, a[1] * s]
+}
+
+fn length(a: [f32; 2]) -> f32 {
+    (a[0] * a[0] + a[1] * a[1]).sqrt()
+}
@@ -1,64 +1,175 @@
+use super::capture;
+use super::draw_list::{ BatchKind, BatchState, DrawList, Vertex };
+use super::layer::{ Layer, LayerEffect };
+use super::paint::Paint;
+use super::path::{ self, FillRule, Path, PathMesh, StrokeStyle };
+use super::rounded_rect::{ BoxShadow, CornerRadius };
 use super::shader::Shader;
-use crate::text::TextRenderer;
+use super::transform::Transform;
+use crate::text::text_renderer::GlyphQuad;
+use crate::widget::Widget;
+use crate::text::{
+    FontQuery,
+    FontRegistry,
+    FontRenderer,
+    FontStyle,
+    FontWeight,
+    TextEffect,
+    TextRenderMode,
+    TextRenderer,
+    DEFAULT_FONT_FAMILY,
+    SDF_SPREAD,
+};
+use image::RgbaImage;
 use nalgebra_glm as glm;
 
 pub struct Renderer {
     shader: Shader,
     texture_shader: Shader,
+    text_shader: Shader,
+    sdf_text_shader: Shader,
+    rounded_rect_shader: Shader,
+    gradient_shader: Shader,
+    path_shader: Shader,
+    layer_shader: Shader,
     text_renderer: TextRenderer,
+    text_render_mode: TextRenderMode,
+    sdf_styles: Vec<SdfStyle>, // 이번 프레임의 SDF 배치가 참조하는 효과 (BatchKind::SdfText의 인덱스)
+    paints: Vec<Paint>, // 이번 프레임의 그라디언트 (BatchKind::Gradient, RoundedRect, Path의 인덱스)
+    layer_effects: Vec<LayerEffect>, // 이번 프레임의 레이어 합성 효과 (BatchKind::Layer의 인덱스)
+    font_registry: FontRegistry,
+    default_font_weight: FontWeight, // 기본 글꼴을 등록한 굵기 (가변 글꼴은 wght 축 기본값)
+    draw_list: DrawList,
     screen_width: f32,
     screen_height: f32,
     background_color: [f32; 4],
-    vao: u32,
-    vbo: u32,
+    focus_ring_color: [f32; 4],
+    transform_stack: Vec<Transform>, // push_translation/push_transform 이전의 변환 (중첩된 위젯의 좌표계)
+    clips: Vec<Clip>, // 이번 프레임에 쌓인 클립 (DrawBatch::clip의 인덱스)
+    clip_stack: Vec<Option<u32>>, // push_clip 이전의 클립
+    target: RenderTarget, // 지금 그리고 있는 프레임버퍼 (화면 또는 레이어)
+    target_stack: Vec<TargetFrame>, // push_layer/render_to_layer 이전의 그리기 상태
+    layer_pool: Vec<Layer>, // push_layer가 프레임마다 다시 쓰는 레이어
+    layers_in_use: usize, // 이번 프레임에 layer_pool에서 꺼낸 레이어 수
     circle_vertices: Vec<f32>, // 미리 계산된 원의 버텍스들
 }
 
 const CIRCLE_SEGMENTS: usize = 32;
 
-impl Renderer {
-    pub fn new(font_data: Vec<u8>) -> Self {
-        let vertex_source = include_str!("shaders/basic.vert");
-        let fragment_source = include_str!("shaders/basic.frag");
-        let shader = Shader::new(vertex_source, fragment_source);
-        let texture_shader = Shader::new(
-            include_str!("shaders/texture.vert"),
-            include_str!("shaders/texture.frag")
-        );
-        let text_renderer = TextRenderer::new(font_data);
+// SDF 텍스트 배치 하나에 적용할 효과와 글자 자체를 그릴지 여부
+#[derive(Clone, Copy, PartialEq)]
+struct SdfStyle {
+    effect: TextEffect,
+    fill: bool,
+}
 
-        let mut vao = 0;
-        let mut vbo = 0;
+// 둥근 사각형 하나의 모양 (정점 속성으로 셰이더에 전달됨)
+#[derive(Clone, Copy)]
+struct RoundedRectStyle {
+    half_size: [f32; 2],
+    radii: [f32; 4],
+    border_width: f32,
+    border_color: [f32; 4],
+    blur: f32,
+}
 
-        unsafe {
-            gl::GenVertexArrays(1, &mut vao);
-            gl::GenBuffers(1, &mut vbo);
+// 클립 하나. 사각형은 부모 클립과 교차한 화면 좌표 (x0, y0, x1, y1)이고, 둥근 클립은 스텐실에 그릴
+// 모양(화면 좌표)을 함께 가집니다. 스텐실 마스크는 부모를 따라 올라가며 모두 교차합니다.
+struct Clip {
+    rect: [f32; 4],
+    mask: Option<PathMesh>,
+    parent: Option<u32>,
+}
+
+// 그리기 대상 프레임버퍼와, 그 프레임버퍼가 덮는 화면 좌표 영역 (x0, y0, x1, y1)
+#[derive(Clone, Copy)]
+struct RenderTarget {
+    framebuffer: u32,
+    rect: [f32; 4],
+}
 
-            gl::BindVertexArray(vao);
-            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+// 레이어로 그리기를 시작하기 전의 상태. 레이어가 끝나면 되돌리고, `composite`가 있으면
+// 레이어 풀의 그 레이어를 효과와 함께 이전 대상에 합성합니다.
+struct TargetFrame {
+    target: RenderTarget,
+    clip: Option<u32>,
+    clip_stack: Vec<Option<u32>>,
+    transform: Transform,
+    transform_stack: Vec<Transform>,
+    composite: Option<(usize, LayerEffect)>,
+}
 
-            gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 0, std::ptr::null());
-            gl::EnableVertexAttribArray(0);
-        }
+// 텍스처를 쓰지 않는 배치의 텍스처 ID
+const NO_TEXTURE: u32 = 0;
+
+impl Renderer {
+    pub fn new(font_data: Vec<u8>) -> Self {
+        let vertex_source = include_str!("shaders/batch.vert");
+        let shader = Shader::new(vertex_source, include_str!("shaders/basic.frag"));
+        let texture_shader = Shader::new(vertex_source, include_str!("shaders/texture.frag"));
+        let text_shader = Shader::new(vertex_source, include_str!("shaders/glyph.frag"));
+        let sdf_text_shader = Shader::new(vertex_source, include_str!("../text/shaders/sdf.frag"));
+        // 채우기(Paint)를 쓰는 셰이더는 paint.glsl의 paintColor 함수를 이어 붙입니다.
+        let rounded_rect_shader = Shader::new(
+            vertex_source,
+            concat!(include_str!("shaders/rounded_rect.frag"), include_str!("shaders/paint.glsl"))
+        );
+        let gradient_shader = Shader::new(
+            vertex_source,
+            concat!(include_str!("shaders/gradient.frag"), include_str!("shaders/paint.glsl"))
+        );
+        let path_shader = Shader::new(
+            vertex_source,
+            concat!(include_str!("shaders/path.frag"), include_str!("shaders/paint.glsl"))
+        );
+        let layer_shader = Shader::new(vertex_source, include_str!("shaders/layer.frag"));
+        let text_renderer = TextRenderer::new(font_data);
+        let mut font_registry = FontRegistry::new();
+        let default_font_weight = text_renderer.font_renderer().default_weight();
+        font_registry.register_font(
+            DEFAULT_FONT_FAMILY,
+            default_font_weight,
+            FontStyle::Normal,
+            text_renderer.font_renderer().clone()
+        );
 
         // 원의 버텍스들을 미리 계산
-        let mut circle_vertices = Vec::with_capacity(CIRCLE_SEGMENTS * 3 * 3);
+        let mut circle_vertices = Vec::with_capacity(CIRCLE_SEGMENTS * 2);
         for i in 0..CIRCLE_SEGMENTS {
             let angle = (2.0 * std::f32::consts::PI * (i as f32)) / (CIRCLE_SEGMENTS as f32);
             let x = angle.cos();
             let y = angle.sin();
-            circle_vertices.extend_from_slice(&[x, y, 0.0]);
+            circle_vertices.extend_from_slice(&[x, y]);
         }
 
         Self {
             shader,
             texture_shader,
+            text_shader,
+            sdf_text_shader,
+            rounded_rect_shader,
+            gradient_shader,
+            path_shader,
+            layer_shader,
             text_renderer,
+            text_render_mode: TextRenderMode::Bitmap,
+            sdf_styles: Vec::new(),
+            paints: Vec::new(),
+            layer_effects: Vec::new(),
+            font_registry,
+            default_font_weight,
+            draw_list: DrawList::new(),
             screen_width: 0.0,
             screen_height: 0.0,
             background_color: [0.1, 0.35, 0.33, 1.0],
-            vao,
-            vbo,
+            focus_ring_color: [0.25, 0.55, 1.0, 1.0],
+            transform_stack: Vec::new(),
+            clips: Vec::new(),
+            clip_stack: Vec::new(),
+            target: RenderTarget { framebuffer: 0, rect: [0.0; 4] },
+            target_stack: Vec::new(),
+            layer_pool: Vec::new(),
+            layers_in_use: 0,
             circle_vertices,
         }
     }
@@ -67,13 +178,38 @@ impl Renderer {
         self.background_color = [r, g, b, a];
     }
 
+    pub fn set_focus_ring_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.focus_ring_color = [r, g, b, a];
+    }
+
     /// 매 프레임마다 호출하여 전체 화면을 다시 그리는 메서드
     /// (배경 지우기, 카메라/투영 매트릭스 설정 등)
+    ///
+    /// 이후의 `draw_*` 호출은 드로우 리스트에 쌓였다가 `end_frame`(또는 `flush`)에서 한 번에 그려집니다.
     pub fn render(&mut self, screen_width: f32, screen_height: f32) {
+        // 이전 프레임에서 남은 드로우 명령이 있다면 먼저 그립니다.
+        self.flush();
+
         // 화면 크기 업데이트
         self.screen_width = screen_width;
         self.screen_height = screen_height;
 
+        // 이전 프레임에서 짝이 맞지 않은 레이어는 버리고 화면으로 돌아옵니다.
+        self.target_stack.clear();
+        self.layers_in_use = 0;
+        self.target = RenderTarget { framebuffer: 0, rect: [0.0, 0.0, screen_width, screen_height] };
+        self.bind_target();
+
+        // 이전 프레임에서 짝이 맞지 않은 변환은 초기화
+        self.transform_stack.clear();
+        self.draw_list.set_transform(Transform::IDENTITY);
+        self.clips.clear();
+        self.clip_stack.clear();
+        self.draw_list.set_clip(None);
+
+        // 글리프 아틀라스의 프레임 기준 갱신 (이번 프레임에 쓰인 글리프는 제거되지 않음)
+        self.text_renderer.begin_frame();
+
         unsafe {
             // 설정된 배경색으로 화면 지우기
             gl::ClearColor(
@@ -82,117 +218,519 @@ impl Renderer {
                 self.background_color[2],
                 self.background_color[3]
             );
-            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT);
+        }
+    }
+
+    /// 프레임을 마무리합니다. 버퍼를 교체(`swap_buffers`)하기 전에 호출해야 합니다.
+    pub fn end_frame(&mut self) {
+        self.flush();
+    }
+
+    /// 지금까지 쌓인 드로우 리스트를 셰이더/텍스처별 배치로 그리고 비웁니다.
+    pub fn flush(&mut self) {
+        if self.draw_list.is_empty() {
+            return;
         }
 
-        // 셰이더 사용
-        self.shader.use_program();
+        // 레이어에 그릴 때는 레이어가 덮는 영역이 프레임버퍼 전체에 대응합니다.
+        let [x0, y0, x1, y1] = self.target.rect;
+        let projection = glm::ortho(x0, x1, y1, y0, -1.0, 1.0);
+
+        // 둥근 클립 모양은 플러시할 때마다 다시 올립니다 (플러시 후에도 클립 스택은 유지되므로).
+        let masks: Vec<Option<u32>> = self.clips
+            .iter()
+            .map(|clip| {
+                clip.mask.as_ref().map(|mesh| self.draw_list.push_mask(&mesh.vertices, &mesh.indices))
+            })
+            .collect();
+        self.draw_list.upload();
 
-        // 예: Orthographic 투영 행렬을 만들고 셰이더 uniform에 설정
-        // (glm 사용 시 예시)
-        let projection = glm::ortho(0.0, screen_width, screen_height, 0.0, -1.0, 1.0);
-        self.shader.set_mat4("projection", &projection);
+        let mut current_kind: Option<BatchKind> = None;
+        let mut current_texture: Option<u32> = None;
+        let mut current_clip: Option<Option<u32>> = None;
+
+        for batch in self.draw_list.batches() {
+            if current_clip != Some(batch.clip) {
+                // 스텐실 마스크를 그렸다면 단색 셰이더로 바뀌었으므로 배치 셰이더를 다시 설정합니다.
+                if self.apply_clip(batch.clip, &masks, &projection) {
+                    current_kind = None;
+                }
+                current_clip = Some(batch.clip);
+            }
+
+            if current_kind != Some(batch.state.kind) {
+                let shader = match batch.state.kind {
+                    BatchKind::Solid => &self.shader,
+                    BatchKind::Texture => &self.texture_shader,
+                    BatchKind::Text => &self.text_shader,
+                    BatchKind::SdfText { .. } => &self.sdf_text_shader,
+                    BatchKind::Gradient { .. } => &self.gradient_shader,
+                    BatchKind::RoundedRect { .. } => &self.rounded_rect_shader,
+                    BatchKind::Path { .. } => &self.path_shader,
+                    BatchKind::Layer { .. } => &self.layer_shader,
+                };
+                shader.use_program();
+                shader.set_mat4("projection", &projection);
+                match batch.state.kind {
+                    BatchKind::SdfText { style } => {
+                        set_sdf_uniforms(shader, &self.sdf_styles[style as usize]);
+                    }
+                    BatchKind::Gradient { paint } => {
+                        set_paint_uniforms(shader, Some(&self.paints[paint as usize]));
+                    }
+                    BatchKind::RoundedRect { paint } | BatchKind::Path { paint } => {
+                        set_paint_uniforms(shader, paint.map(|paint| &self.paints[paint as usize]));
+                    }
+                    BatchKind::Layer { effect } => {
+                        set_layer_uniforms(shader, &self.layer_effects[effect as usize]);
+                    }
+                    _ => {}
+                }
+                current_kind = Some(batch.state.kind);
+            }
+
+            if
+                batch.state.texture_id != NO_TEXTURE &&
+                current_texture != Some(batch.state.texture_id)
+            {
+                unsafe {
+                    gl::ActiveTexture(gl::TEXTURE0);
+                    gl::BindTexture(gl::TEXTURE_2D, batch.state.texture_id);
+                }
+                current_texture = Some(batch.state.texture_id);
+            }
+
+            self.draw_list.draw_batch(batch);
+        }
+
+        unsafe {
+            gl::Disable(gl::SCISSOR_TEST);
+            gl::Disable(gl::STENCIL_TEST);
+            gl::BindVertexArray(0);
+        }
+        self.draw_list.clear();
+        self.sdf_styles.clear();
+        self.paints.clear();
+        self.layer_effects.clear();
     }
 
-    /// 텍스트 렌더링 객체에 접근 (폰트 그리기 등)
-    pub fn text_renderer(&self) -> &TextRenderer {
-        &self.text_renderer
+    /// 이후의 모든 그리기 좌표를 (x, y)만큼 평행 이동합니다. 기존 변환에 누적되며
+    /// 컨테이너가 자식 위젯을 자신의 위치 기준(상대 좌표)으로 그릴 때 사용합니다.
+    /// 반드시 `pop_translation`과 짝을 맞춰 호출해야 합니다.
+    pub fn push_translation(&mut self, x: f32, y: f32) {
+        self.push_transform(&Transform::translation(x, y));
+    }
+
+    /// 마지막 `push_translation` 이전의 변환으로 되돌립니다.
+    pub fn pop_translation(&mut self) {
+        self.pop_transform();
+    }
+
+    /// 이후의 모든 그리기에 `transform`(회전, 크기, 기울이기 등)을 적용합니다. 기존 변환의 안쪽에
+    /// 누적되므로 `transform`의 좌표는 현재 좌표계 기준입니다. 반드시 `pop_transform`과 짝을 맞춰 호출해야 합니다.
+    pub fn push_transform(&mut self, transform: &Transform) {
+        let current = self.draw_list.transform();
+        self.transform_stack.push(current);
+        self.draw_list.set_transform(current.multiply(transform));
     }
 
-    /// Draws a filled rectangle at (x, y) with given width, height and color.
-    /// Assumes that self.screen_width and self.screen_height are updated.
-    pub fn draw_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: [f32; 4]) {
-        // 현재 바인딩된 shader를 저장
-        let current_shader = unsafe {
-            let mut current_program = 0;
-            gl::GetIntegerv(gl::CURRENT_PROGRAM, &mut current_program);
-            current_program
+    /// 마지막 `push_transform`(또는 `push_translation`) 이전의 변환으로 되돌립니다.
+    pub fn pop_transform(&mut self) {
+        let transform = self.transform_stack.pop().unwrap_or(Transform::IDENTITY);
+        self.draw_list.set_transform(transform);
+    }
+
+    /// 현재 누적된 변환 (그리기 좌표 → 화면 좌표)
+    pub fn transform(&self) -> Transform {
+        self.draw_list.transform()
+    }
+
+    /// 현재 누적된 변환의 평행 이동 성분 (화면 좌표)
+    pub fn translation(&self) -> (f32, f32) {
+        self.draw_list.transform().translation_part()
+    }
+
+    /// 이후의 그리기를 (x, y, width, height) 사각형 안으로 잘라냅니다. 좌표는 현재 변환 기준이며,
+    /// 이미 클립이 있으면 두 영역의 교차 영역만 그려집니다. 반드시 `pop_clip`과 짝을 맞춰 호출해야 합니다.
+    pub fn push_clip(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.push_rounded_clip(x, y, width, height, CornerRadius::default());
+    }
+
+    /// 모서리가 둥근 사각형으로 잘라냅니다. 둥근 모서리(와 회전하거나 기울어진 클립)는 스텐실 버퍼로
+    /// 잘라내므로 가장자리가 부드럽게 처리되지 않습니다.
+    pub fn push_rounded_clip(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        radius: impl Into<CornerRadius>
+    ) {
+        let (width, height) = (width.max(0.0), height.max(0.0));
+        let transform = self.draw_list.transform();
+        let parent = self.draw_list.clip();
+        let mut rect = transform.bounds_of(x, y, width, height);
+        if let Some(parent) = parent {
+            let [x0, y0, x1, y1] = self.clips[parent as usize].rect;
+            rect = [rect[0].max(x0), rect[1].max(y0), rect[2].min(x1), rect[3].min(y1)];
+        }
+
+        // 가위 영역은 축에 나란한 사각형이므로, 회전하거나 기울어진 클립은 스텐실로 모양을 잘라냅니다.
+        let radius = radius.into();
+        let mask = if radius.is_zero() && transform.is_axis_aligned() {
+            None
+        } else {
+            let mut path = Path::new();
+            path.rounded_rect(x, y, width, height, radius.clamped(width, height));
+            path::tessellate_mask(&path).map(|mut mesh| {
+                for vertex in mesh.vertices.iter_mut() {
+                    let (x, y) = transform.apply(vertex.position[0], vertex.position[1]);
+                    vertex.position = [x, y];
+                }
+                mesh
+            })
         };
 
-        // 기본 셰이더로 전환하여 사각형 그리기
-        self.shader.use_program();
-        self.shader.set_vec4("uColor", &color);
-
-        // Convert screen coordinates to normalized device coordinates (NDC)
-        let x_ndc = (x / self.screen_width) * 2.0 - 1.0;
-        let y_ndc = 1.0 - (y / self.screen_height) * 2.0;
-        let x2_ndc = ((x + width) / self.screen_width) * 2.0 - 1.0;
-        let y2_ndc = 1.0 - ((y + height) / self.screen_height) * 2.0;
-
-        let vertices: [f32; 12] = [
-            x_ndc,
-            y_ndc,
-            0.0, // top-left
-            x2_ndc,
-            y_ndc,
-            0.0, // top-right
-            x2_ndc,
-            y2_ndc,
-            0.0, // bottom-right
-            x_ndc,
-            y2_ndc,
-            0.0, // bottom-left
-        ];
-        let indices: [u32; 6] = [0, 1, 2, 2, 3, 0];
+        self.clip_stack.push(parent);
+        self.clips.push(Clip { rect, mask, parent });
+        self.draw_list.set_clip(Some((self.clips.len() - 1) as u32));
+    }
+
+    /// 마지막 `push_clip`(또는 `push_rounded_clip`) 이전의 클립으로 되돌립니다.
+    pub fn pop_clip(&mut self) {
+        let clip = self.clip_stack.pop().unwrap_or(None);
+        self.draw_list.set_clip(clip);
+    }
+
+    /// 현재 클립 영역 (x, y, width, height, 화면 좌표). 클립이 없으면 `None`입니다.
+    /// 영역 밖의 내용을 그리지 않고 건너뛸 때 사용합니다.
+    pub fn clip_rect(&self) -> Option<(f32, f32, f32, f32)> {
+        let [x0, y0, x1, y1] = self.clips[self.draw_list.clip()? as usize].rect;
+        Some((x0, y0, (x1 - x0).max(0.0), (y1 - y0).max(0.0)))
+    }
+
+    /// 이후의 그리기를 (x, y, width, height) 영역의 오프스크린 레이어에 모았다가, `pop_layer`에서
+    /// `effect`(불투명도, 흐림, 채도)를 적용해 한 번에 합성합니다. 좌표는 현재 변환 기준이며 반드시
+    /// `pop_layer`와 짝을 맞춰 호출해야 합니다.
+    ///
+    /// 버튼의 배경, 테두리, 글자처럼 겹쳐 그린 내용을 한 장의 그림처럼 옅어지게 할 때(그룹 불투명도) 사용합니다.
+    /// 흐림은 영역 밖으로 번지는 만큼 레이어를 넓게 잡습니다.
+    pub fn push_layer(&mut self, x: f32, y: f32, width: f32, height: f32, effect: LayerEffect) {
+        let spread = effect.spread();
+        let transform = self.draw_list.transform();
+        let [x0, y0, x1, y1] = transform.bounds_of(
+            x - spread,
+            y - spread,
+            width.max(0.0) + spread * 2.0,
+            height.max(0.0) + spread * 2.0
+        );
+        let rect = [x0.floor(), y0.floor(), x1.ceil().max(x0.floor() + 1.0), y1.ceil().max(y0.floor() + 1.0)];
+        let (layer_width, layer_height) = ((rect[2] - rect[0]) as u32, (rect[3] - rect[1]) as u32);
+
+        let index = self.layers_in_use;
+        self.layers_in_use += 1;
+        if index < self.layer_pool.len() {
+            self.layer_pool[index].resize(layer_width, layer_height);
+        } else {
+            self.layer_pool.push(Layer::new(layer_width, layer_height));
+        }
+
+        let framebuffer = self.layer_pool[index].framebuffer_id();
+        self.begin_target(RenderTarget { framebuffer, rect }, transform, Some((index, effect)));
+    }
+
+    /// 마지막 `push_layer` 이후에 그린 내용을 효과와 함께 이전 대상(화면 또는 바깥 레이어)에 합성합니다.
+    pub fn pop_layer(&mut self) {
+        let rect = self.target.rect;
+        let Some(frame) = self.end_target() else {
+            return;
+        };
+        let Some((index, effect)) = frame.composite else {
+            return;
+        };
+
+        // 레이어 영역은 이미 화면 좌표이므로 변환 없이 그리고, 프레임버퍼는 아래쪽이 원점이라 위아래를 뒤집습니다.
+        let transform = self.draw_list.transform();
+        self.draw_list.set_transform(Transform::IDENTITY);
+        let texture_id = self.layer_pool[index].texture_id();
+        self.push_layer_quad(texture_id, rect, &effect);
+        self.draw_list.set_transform(transform);
+    }
+
+    /// `draw`로 그린 내용을 `layer`에 저장합니다. 레이어의 왼쪽 위가 (0, 0)인 좌표계에서 그리며,
+    /// 레이어의 이전 내용은 지워집니다. 자주 바뀌지 않는 패널을 한 번 그려 두고 `draw_layer`로 재사용할 때 씁니다.
+    pub fn render_to_layer(&mut self, layer: &Layer, draw: impl FnOnce(&mut Self)) {
+        let (width, height) = layer.size();
+        let target = RenderTarget {
+            framebuffer: layer.framebuffer_id(),
+            rect: [0.0, 0.0, width as f32, height as f32],
+        };
+        self.begin_target(target, Transform::IDENTITY, None);
+        draw(self);
+        self.end_target();
+    }
+
+    /// `layer`를 (x, y)에 효과와 함께 그립니다. 현재 변환과 클립이 적용됩니다.
+    pub fn draw_layer(&mut self, layer: &Layer, x: f32, y: f32, effect: &LayerEffect) {
+        let (width, height) = layer.size();
+        self.push_layer_quad(layer.texture_id(), [x, y, x + width as f32, y + height as f32], effect);
+    }
+
+    fn push_layer_quad(&mut self, texture_id: u32, rect: [f32; 4], effect: &LayerEffect) {
+        let effect_index = match self.layer_effects.iter().position(|e| e == effect) {
+            Some(index) => index,
+            None => {
+                self.layer_effects.push(*effect);
+                self.layer_effects.len() - 1
+            }
+        };
+        self.draw_list.push_quad(
+            BatchState { kind: BatchKind::Layer { effect: effect_index as u32 }, texture_id },
+            rect,
+            [0.0, 1.0, 1.0, 0.0],
+            [1.0, 1.0, 1.0, effect.opacity]
+        );
+    }
+
+    // 쌓인 내용을 그린 뒤 그리기 대상을 바꾸고 투명하게 지웁니다. 클립과 변환 스택은 대상별로 따로 둡니다.
+    fn begin_target(&mut self, target: RenderTarget, transform: Transform, composite: Option<(usize, LayerEffect)>) {
+        self.flush();
+        self.target_stack.push(TargetFrame {
+            target: self.target,
+            clip: self.draw_list.clip(),
+            clip_stack: std::mem::take(&mut self.clip_stack),
+            transform: self.draw_list.transform(),
+            transform_stack: std::mem::take(&mut self.transform_stack),
+            composite,
+        });
+        self.draw_list.set_clip(None);
+        self.draw_list.set_transform(transform);
+        self.target = target;
+        self.bind_target();
 
         unsafe {
-            let mut vao = 0;
-            let mut vbo = 0;
-            let mut ebo = 0;
-
-            gl::GenVertexArrays(1, &mut vao);
-            gl::GenBuffers(1, &mut vbo);
-            gl::GenBuffers(1, &mut ebo);
-
-            gl::BindVertexArray(vao);
-
-            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                (vertices.len() * std::mem::size_of::<f32>()) as isize,
-                vertices.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
+            gl::ClearColor(0.0, 0.0, 0.0, 0.0);
+            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT);
+        }
+    }
 
-            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
-            gl::BufferData(
-                gl::ELEMENT_ARRAY_BUFFER,
-                (indices.len() * std::mem::size_of::<u32>()) as isize,
-                indices.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
+    // 레이어에 쌓인 내용을 그리고 이전 대상과 클립, 변환으로 되돌립니다.
+    fn end_target(&mut self) -> Option<TargetFrame> {
+        self.flush();
+        let mut frame = self.target_stack.pop()?;
+        self.target = frame.target;
+        self.bind_target();
+        self.draw_list.set_clip(frame.clip);
+        self.draw_list.set_transform(frame.transform);
+        self.clip_stack = std::mem::take(&mut frame.clip_stack);
+        self.transform_stack = std::mem::take(&mut frame.transform_stack);
+        Some(frame)
+    }
 
-            gl::VertexAttribPointer(
-                0,
-                3,
-                gl::FLOAT,
-                gl::FALSE,
-                (3 * std::mem::size_of::<f32>()) as i32,
-                std::ptr::null()
-            );
-            gl::EnableVertexAttribArray(0);
+    fn bind_target(&self) {
+        let [x0, y0, x1, y1] = self.target.rect;
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.target.framebuffer);
+            gl::Viewport(0, 0, (x1 - x0) as i32, (y1 - y0) as i32);
+        }
+    }
 
-            gl::DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, std::ptr::null());
+    /// 지금까지 그린 화면(레이어 안이라면 그 레이어)을 이미지로 읽어 옵니다. 쌓인 드로우 리스트를 먼저
+    /// 그리므로 `end_frame` 뒤, `swap_buffers` 전에 호출하면 그 프레임 전체가 담깁니다.
+    pub fn capture_frame(&mut self) -> RgbaImage {
+        let [x0, y0, x1, y1] = self.target.rect;
+        self.capture_region(x0, y0, x1 - x0, y1 - y0)
+    }
+
+    /// 화면 좌표 (x, y, width, height) 영역을 이미지로 읽어 옵니다. 그리기 대상 밖은 잘립니다.
+    pub fn capture_region(&mut self, x: f32, y: f32, width: f32, height: f32) -> RgbaImage {
+        self.flush();
+
+        let [target_x, target_y, target_right, target_bottom] = self.target.rect;
+        let left = x.floor().max(target_x);
+        let top = y.floor().max(target_y);
+        let right = (x + width).ceil().min(target_right).max(left);
+        let bottom = (y + height).ceil().min(target_bottom).max(top);
+        capture::read_pixels(
+            self.target.framebuffer,
+            (left - target_x) as i32,
+            (target_bottom - bottom) as i32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+            self.target.framebuffer != 0
+        )
+    }
+
+    /// `widget` 하나만 투명한 배경에 그려 이미지로 돌려줍니다. 위젯 영역(변환 포함)에 `margin`만큼 여유를
+    /// 둔 크기이며, 그림자나 포커스 링처럼 위젯 밖으로 나가는 부분을 담으려면 `margin`을 넓힙니다.
+    /// 화면에 그린 내용에는 영향을 주지 않습니다.
+    pub fn capture_widget(&mut self, widget: &dyn Widget, margin: f32) -> RgbaImage {
+        let (x, y) = widget.position();
+        let (width, height) = widget.size();
+        let margin = margin.max(0.0);
+        let [x0, y0, x1, y1] = widget.transform_matrix().bounds_of(x, y, width, height);
+        let (left, top) = ((x0 - margin).floor(), (y0 - margin).floor());
+        let (right, bottom) = ((x1 + margin).ceil(), (y1 + margin).ceil());
+
+        let layer = Layer::new((right - left).max(1.0) as u32, (bottom - top).max(1.0) as u32);
+        let (screen_width, screen_height) = (self.screen_width, self.screen_height);
+        self.render_to_layer(&layer, |renderer| {
+            renderer.push_translation(-left, -top);
+            widget.draw(renderer, screen_width, screen_height);
+            renderer.pop_translation();
+        });
+
+        let (layer_width, layer_height) = layer.size();
+        capture::read_pixels(layer.framebuffer_id(), 0, 0, layer_width, layer_height, true)
+    }
+
+    /// `capture_frame`으로 읽은 화면을 PNG 파일로 저장합니다.
+    pub fn save_screenshot(&mut self, path: impl AsRef<std::path::Path>) -> Result<(), String> {
+        capture::save_png(&self.capture_frame(), path)
+    }
 
-            // Cleanup
-            gl::DeleteVertexArrays(1, &vao);
-            gl::DeleteBuffers(1, &vbo);
-            gl::DeleteBuffers(1, &ebo);
+    // 배치를 그리기 전에 가위 영역과 스텐실을 설정합니다. 스텐실 마스크를 그렸으면 true를 반환합니다.
+    fn apply_clip(&self, clip: Option<u32>, masks: &[Option<u32>], projection: &glm::Mat4) -> bool {
+        let Some(index) = clip else {
+            unsafe {
+                gl::Disable(gl::SCISSOR_TEST);
+                gl::Disable(gl::STENCIL_TEST);
+            }
+            return false;
+        };
 
-            // 이전 shader로 복원
-            if current_shader != 0 {
-                gl::UseProgram(current_shader as u32);
+        // 가위 영역은 그리기 대상 기준의 정수 픽셀이고, 원점이 왼쪽 아래입니다.
+        let [x0, y0, x1, y1] = self.clips[index as usize].rect;
+        let [target_x, _, _, target_bottom] = self.target.rect;
+        let (left, top) = (x0.floor(), y0.floor());
+        let (right, bottom) = (x1.ceil().max(left), y1.ceil().max(top));
+        unsafe {
+            gl::Enable(gl::SCISSOR_TEST);
+            gl::Scissor(
+                (left - target_x) as i32,
+                (target_bottom - bottom) as i32,
+                (right - left) as i32,
+                (bottom - top) as i32
+            );
+        }
+
+        let mut chain = Vec::new();
+        let mut current = Some(index);
+        while let Some(index) = current {
+            if let Some(mask) = masks[index as usize] {
+                chain.push(mask);
+            }
+            current = self.clips[index as usize].parent;
+        }
+        if chain.is_empty() {
+            unsafe {
+                gl::Disable(gl::STENCIL_TEST);
             }
+            return false;
         }
+
+        // 마스크마다 스텐실 값을 1씩 늘리고, 모든 마스크 안쪽(값이 마스크 수와 같은 곳)에만 그립니다.
+        self.shader.use_program();
+        self.shader.set_mat4("projection", projection);
+        unsafe {
+            gl::Enable(gl::STENCIL_TEST);
+            gl::StencilMask(0xff);
+            gl::ClearStencil(0);
+            gl::Clear(gl::STENCIL_BUFFER_BIT);
+            gl::ColorMask(gl::FALSE, gl::FALSE, gl::FALSE, gl::FALSE);
+            gl::StencilFunc(gl::ALWAYS, 0, 0xff);
+            gl::StencilOp(gl::KEEP, gl::KEEP, gl::INCR);
+        }
+        for &mask in &chain {
+            self.draw_list.draw_batch(self.draw_list.mask(mask));
+        }
+        unsafe {
+            gl::ColorMask(gl::TRUE, gl::TRUE, gl::TRUE, gl::TRUE);
+            gl::StencilFunc(gl::EQUAL, chain.len() as i32, 0xff);
+            gl::StencilOp(gl::KEEP, gl::KEEP, gl::KEEP);
+        }
+        true
     }
 
-    /// Add a new method for mutable access to the text renderer.
+    /// 텍스트 렌더링 객체에 접근 (폰트 그리기 등)
+    pub fn text_renderer(&self) -> &TextRenderer {
+        &self.text_renderer
+    }
+
+    /// 텍스트 렌더러에 대한 가변 접근.
+    ///
+    /// `TextRenderer::render_text`는 즉시 그리기 때문에, 그리는 순서가 유지되도록
+    /// 쌓여 있던 드로우 리스트를 먼저 flush합니다.
     pub fn text_renderer_mut(&mut self) -> &mut TextRenderer {
+        self.flush();
         &mut self.text_renderer
     }
 
+    /// 등록된 글꼴 목록
+    pub fn font_registry(&self) -> &FontRegistry {
+        &self.font_registry
+    }
+
+    /// 글꼴 파일을 패밀리 이름, 굵기, 스타일로 등록합니다.
+    /// 위젯은 `set_font`에서 글꼴을 찾으므로 위젯을 만들기 전에 등록해야 합니다.
+    pub fn register_font(
+        &mut self,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+        font_data: Vec<u8>
+    ) -> Result<(), String> {
+        self.font_registry.register(family, weight, style, font_data)?;
+        self.refresh_default_font();
+        Ok(())
+    }
+
+    /// 글리프가 없을 때 차례로 찾아볼 패밀리 목록 (예: `&["Korean", "Emoji"]`)
+    pub fn set_fallback_chain(&mut self, families: &[&str]) {
+        self.font_registry.set_fallback_chain(families);
+        self.refresh_default_font();
+    }
+
+    /// 요청에 맞는 글꼴과 대체 글꼴 체인. 패밀리가 없으면 기본 글꼴을 사용합니다.
+    /// 위젯의 `set_font`도 이 함수로 `register_font`에 등록한 글꼴을 찾습니다.
+    pub fn font(&self, query: &FontQuery) -> FontRenderer {
+        self.font_registry
+            .resolve(query)
+            .unwrap_or_else(|| self.text_renderer.font_renderer().clone())
+    }
+
+    /// 기본 글꼴의 굵기. 가변 글꼴은 축 기본값이므로 `FontWeight::NORMAL`이 아닐 수 있습니다.
+    pub fn default_font_weight(&self) -> FontWeight {
+        self.default_font_weight
+    }
+
+    // 기본 글꼴에도 대체 글꼴 체인을 적용 (draw_text가 사용)
+    fn refresh_default_font(&mut self) {
+        let font = self.font(&FontQuery::default().with_weight(self.default_font_weight));
+        self.text_renderer.set_font_renderer(font);
+    }
+
+    /// 드로우 리스트에 쌓인 배치 수 (프로파일링용)
+    pub fn pending_batch_count(&self) -> usize {
+        self.draw_list.batches().len()
+    }
+
+    /// Draws a filled rectangle at (x, y) with given width, height and paint.
+    ///
+    /// `paint`는 단색(`[f32; 4]`, `Color`) 또는 그라디언트(`Paint`)입니다.
+    pub fn draw_rect(&mut self, x: f32, y: f32, width: f32, height: f32, paint: impl Into<Paint>) {
+        let (kind, color) = self.paint_batch(paint.into());
+        self.draw_list.push_quad(
+            BatchState { kind, texture_id: NO_TEXTURE },
+            [x, y, x + width, y + height],
+            [0.0, 0.0, 1.0, 1.0],
+            color
+        );
+    }
+
     pub fn draw_textured_rect(
-        &self,
+        &mut self,
         x: f32,
         y: f32,
         width: f32,
@@ -200,170 +738,466 @@ impl Renderer {
         texture_id: u32,
         opacity: f32
     ) {
-        self.texture_shader.use_program();
-
-        // Set projection matrix
-        let projection = glm::ortho(0.0, self.screen_width, self.screen_height, 0.0, -1.0, 1.0);
-        self.texture_shader.set_mat4("projection", &projection);
-        self.texture_shader.set_float("uOpacity", opacity);
+        self.draw_list.push_quad(
+            BatchState { kind: BatchKind::Texture, texture_id },
+            [x, y, x + width, y + height],
+            [0.0, 0.0, 1.0, 1.0],
+            [1.0, 1.0, 1.0, opacity]
+        );
+    }
 
-        unsafe {
-            gl::ActiveTexture(gl::TEXTURE0);
-            gl::BindTexture(gl::TEXTURE_2D, texture_id);
-
-            let vertices: [f32; 20] = [
-                x,
-                y,
-                0.0,
-                0.0,
-                1.0,
-                x + width,
-                y,
-                0.0,
-                1.0,
-                1.0,
-                x + width,
-                y + height,
-                0.0,
-                1.0,
-                0.0,
-                x,
-                y + height,
-                0.0,
-                0.0,
-                0.0,
-            ];
+    pub fn draw_triangle(&mut self, vertices: [(f32, f32); 3], paint: impl Into<Paint>) {
+        let (kind, color) = self.paint_batch(paint.into());
 
-            let indices: [u32; 6] = [0, 1, 2, 2, 3, 0];
+        // 그라디언트 좌표는 삼각형의 경계 사각형 기준 비율
+        let min_x = vertices.iter().fold(f32::INFINITY, |min, &(x, _)| min.min(x));
+        let max_x = vertices.iter().fold(f32::NEG_INFINITY, |max, &(x, _)| max.max(x));
+        let min_y = vertices.iter().fold(f32::INFINITY, |min, &(_, y)| min.min(y));
+        let max_y = vertices.iter().fold(f32::NEG_INFINITY, |max, &(_, y)| max.max(y));
+        let (width, height) = ((max_x - min_x).max(1e-4), (max_y - min_y).max(1e-4));
 
-            let mut vao = 0;
-            let mut vbo = 0;
-            let mut ebo = 0;
+        let vertices: Vec<Vertex> = vertices
+            .iter()
+            .map(|&(x, y)| Vertex::new(x, y, (x - min_x) / width, (y - min_y) / height, color))
+            .collect();
 
-            gl::GenVertexArrays(1, &mut vao);
-            gl::GenBuffers(1, &mut vbo);
-            gl::GenBuffers(1, &mut ebo);
+        self.draw_list.push(BatchState { kind, texture_id: NO_TEXTURE }, &vertices, &[0, 1, 2]);
+    }
 
-            gl::BindVertexArray(vao);
+    pub fn draw_circle(
+        &mut self,
+        center_x: f32,
+        center_y: f32,
+        radius: f32,
+        paint: impl Into<Paint>
+    ) {
+        let (kind, color) = self.paint_batch(paint.into());
 
-            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                (vertices.len() * std::mem::size_of::<f32>()) as isize,
-                vertices.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
+        // 스케일과 위치 변환을 적용한 버텍스들 생성
+        let vertices: Vec<Vertex> = self.circle_vertices
+            .chunks(2)
+            .map(|v| {
+                Vertex::new(
+                    center_x + v[0] * radius,
+                    center_y + v[1] * radius,
+                    v[0] * 0.5 + 0.5,
+                    v[1] * 0.5 + 0.5,
+                    color
+                )
+            })
+            .collect();
 
-            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
-            gl::BufferData(
-                gl::ELEMENT_ARRAY_BUFFER,
-                (indices.len() * std::mem::size_of::<u32>()) as isize,
-                indices.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
+        // 첫 번째 버텍스를 기준으로 한 삼각형 팬
+        let indices: Vec<u32> = (1..(CIRCLE_SEGMENTS as u32) - 1)
+            .flat_map(|i| [0, i, i + 1])
+            .collect();
 
-            // Position attribute
-            gl::VertexAttribPointer(
-                0,
-                3,
-                gl::FLOAT,
-                gl::FALSE,
-                (5 * std::mem::size_of::<f32>()) as i32,
-                std::ptr::null()
-            );
-            gl::EnableVertexAttribArray(0);
-
-            // Texture coord attribute
-            gl::VertexAttribPointer(
-                1,
-                2,
-                gl::FLOAT,
-                gl::FALSE,
-                (5 * std::mem::size_of::<f32>()) as i32,
-                (3 * std::mem::size_of::<f32>()) as *const _
-            );
-            gl::EnableVertexAttribArray(1);
+        self.draw_list.push(BatchState { kind, texture_id: NO_TEXTURE }, &vertices, &indices);
+    }
 
-            gl::DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, std::ptr::null());
+    // 단색은 정점 색으로 그리고(배치 합치기 유지), 그라디언트는 프레임 목록에 등록해서 인덱스를 씁니다.
+    // 그라디언트의 정점 색은 흰색이므로 셰이더에서 곱해도 정지점 색이 그대로 나옵니다.
+    fn paint_batch(&mut self, paint: Paint) -> (BatchKind, [f32; 4]) {
+        match self.paint_index(paint) {
+            Ok(paint) => (BatchKind::Gradient { paint }, [1.0; 4]),
+            Err(color) => (BatchKind::Solid, color),
+        }
+    }
 
-            gl::DeleteVertexArrays(1, &vao);
-            gl::DeleteBuffers(1, &vbo);
-            gl::DeleteBuffers(1, &ebo);
+    fn paint_index(&mut self, paint: Paint) -> Result<u32, [f32; 4]> {
+        if let Paint::Solid(color) = paint {
+            return Err(color);
         }
+        let index = match self.paints.iter().position(|p| *p == paint) {
+            Some(index) => index,
+            None => {
+                self.paints.push(paint);
+                self.paints.len() - 1
+            }
+        };
+        Ok(index as u32)
     }
 
-    pub fn draw_triangle(&mut self, vertices: [(f32, f32); 3], color: [f32; 4]) {
-        let current_shader = unsafe {
-            let mut current_program = 0;
-            gl::GetIntegerv(gl::CURRENT_PROGRAM, &mut current_program);
-            current_program
+    /// 모서리가 둥근 사각형을 그립니다. 모서리마다 반지름을 다르게 줄 수 있으며
+    /// (`CornerRadius::new`), 가장자리는 거리 함수로 계산해서 부드럽게 그려집니다.
+    /// 채우기는 단색 또는 그라디언트(`Paint`)입니다.
+    pub fn draw_rounded_rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        radius: impl Into<CornerRadius>,
+        paint: impl Into<Paint>
+    ) {
+        self.draw_rounded_rect_with_border(x, y, width, height, radius, paint, 0.0, [0.0; 4]);
+    }
+
+    /// 테두리가 있는 둥근 사각형. 테두리는 (x, y, width, height) 안쪽으로 `border_width`만큼 그려지며,
+    /// 테두리와 채우기가 겹치지 않으므로 채우기 색이 반투명해도 테두리 색이 비치지 않습니다.
+    /// 너비나 높이가 0 이하이면 아무것도 그리지 않습니다.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rounded_rect_with_border(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        radius: impl Into<CornerRadius>,
+        fill: impl Into<Paint>,
+        border_width: f32,
+        border_color: [f32; 4]
+    ) {
+        // 크기가 0 이하(또는 NaN)이면 그릴 것이 없고, 테두리 두께의 상한도 음수가 되므로 바로 돌아갑니다.
+        if !(width > 0.0 && height > 0.0) {
+            return;
+        }
+
+        let style = RoundedRectStyle {
+            half_size: [width / 2.0, height / 2.0],
+            radii: radius.into().clamped(width, height),
+            border_width: border_width.clamp(0.0, width.min(height) / 2.0),
+            border_color,
+            blur: 0.0,
         };
+        let (paint, color) = match self.paint_index(fill.into()) {
+            Ok(paint) => (Some(paint), [1.0; 4]),
+            Err(color) => (None, color),
+        };
+        // 가장자리 안티에일리어싱이 잘리지 않도록 1픽셀 여유를 둡니다.
+        self.push_rounded_rect(x, y, 1.0, style, paint, color);
+    }
 
-        self.shader.use_program();
-        self.shader.set_vec4("uColor", &color);
+    /// 둥근 사각형의 테두리만 그립니다 (안쪽은 비워 둠).
+    #[allow(clippy::too_many_arguments)]
+    pub fn stroke_rounded_rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        radius: impl Into<CornerRadius>,
+        border_width: f32,
+        color: [f32; 4]
+    ) {
+        self.draw_rounded_rect_with_border(x, y, width, height, radius, [0.0; 4], border_width, color);
+    }
 
-        // Convert to NDC
-        let vertices_ndc: Vec<f32> = vertices
-            .iter()
-            .flat_map(|(x, y)| {
-                let x_ndc = (x / self.screen_width) * 2.0 - 1.0;
-                let y_ndc = 1.0 - (y / self.screen_height) * 2.0;
-                vec![x_ndc, y_ndc, 0.0]
-            })
-            .collect();
+    /// (x, y, width, height) 사각형이 드리우는 그림자를 그립니다. 사각형보다 먼저 그려야 합니다.
+    pub fn draw_box_shadow(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        radius: impl Into<CornerRadius>,
+        shadow: &BoxShadow
+    ) {
+        let x = x + shadow.offset_x - shadow.spread;
+        let y = y + shadow.offset_y - shadow.spread;
+        let width = width + shadow.spread * 2.0;
+        let height = height + shadow.spread * 2.0;
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
 
-        unsafe {
-            gl::BindVertexArray(self.vao);
-            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                (vertices_ndc.len() * std::mem::size_of::<f32>()) as isize,
-                vertices_ndc.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
-            gl::DrawArrays(gl::TRIANGLES, 0, 3);
+        let radius = radius.into().expanded(shadow.spread);
+        if shadow.blur <= 0.0 {
+            self.draw_rounded_rect(x, y, width, height, radius, shadow.color);
+            return;
         }
 
-        // 이전 셰이더로 복원
-        unsafe {
-            gl::UseProgram(current_shader as u32);
+        let style = RoundedRectStyle {
+            half_size: [width / 2.0, height / 2.0],
+            radii: radius.clamped(width, height),
+            border_width: 0.0,
+            border_color: [0.0; 4],
+            blur: shadow.blur,
+        };
+        // 가우시안의 약 3 표준편차(흐림 폭의 1.5배)까지 그립니다.
+        self.push_rounded_rect(x, y, shadow.blur * 1.5, style, None, shadow.color);
+    }
+
+    // (x, y)에서 시작하는 사각형을 `margin`만큼 넓힌 쿼드를 추가하고,
+    // 텍스처 좌표에 사각형 중심 기준 좌표를 넣습니다.
+    fn push_rounded_rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        margin: f32,
+        style: RoundedRectStyle,
+        paint: Option<u32>,
+        color: [f32; 4]
+    ) {
+        let [half_width, half_height] = style.half_size;
+        let rect = [half_width, half_height, style.border_width, style.blur];
+        let (center_x, center_y) = (x + half_width, y + half_height);
+        let (local_x, local_y) = (half_width + margin, half_height + margin);
+        let vertices = [
+            (center_x - local_x, center_y - local_y, -local_x, -local_y),
+            (center_x + local_x, center_y - local_y, local_x, -local_y),
+            (center_x + local_x, center_y + local_y, local_x, local_y),
+            (center_x - local_x, center_y + local_y, -local_x, local_y),
+        ].map(|(x, y, u, v)| {
+            Vertex::new(x, y, u, v, color).with_rounded_rect(rect, style.radii, style.border_color)
+        });
+        self.draw_list.push(
+            BatchState {
+                kind: BatchKind::RoundedRect { paint },
+                texture_id: NO_TEXTURE,
+            },
+            &vertices,
+            &[0, 1, 2, 2, 3, 0]
+        );
+    }
+
+    /// 경로 안쪽을 채웁니다. 겹치는 하위 경로는 `rule`에 따라 구멍이 되며, 가장자리는 안티에일리어싱됩니다.
+    /// 그라디언트 좌표는 경로의 경계 사각형 기준 비율입니다.
+    pub fn fill_path(&mut self, path: &Path, rule: FillRule, paint: impl Into<Paint>) {
+        let (paint, color) = match self.paint_index(paint.into()) {
+            Ok(paint) => (Some(paint), [1.0; 4]),
+            Err(color) => (None, color),
+        };
+        if let Some(mesh) = path::tessellate_fill(path, rule, color) {
+            self.push_path_mesh(mesh, paint);
         }
     }
 
-    pub fn draw_circle(&mut self, center_x: f32, center_y: f32, radius: f32, color: [f32; 4]) {
-        let current_shader = unsafe {
-            let mut current_program = 0;
-            gl::GetIntegerv(gl::CURRENT_PROGRAM, &mut current_program);
-            current_program
+    /// 경로를 따라 선을 그립니다 (두께, 꺾임, 끝 모양, 점선은 `StrokeStyle`).
+    pub fn stroke_path(&mut self, path: &Path, style: &StrokeStyle, paint: impl Into<Paint>) {
+        let (paint, color) = match self.paint_index(paint.into()) {
+            Ok(paint) => (Some(paint), [1.0; 4]),
+            Err(color) => (None, color),
         };
+        if let Some(mesh) = path::tessellate_stroke(path, style, color) {
+            self.push_path_mesh(mesh, paint);
+        }
+    }
 
-        self.shader.use_program();
-        self.shader.set_vec4("uColor", &color);
+    /// 점들을 차례로 잇는 꺾은선을 그립니다.
+    pub fn draw_polyline(&mut self, points: &[[f32; 2]], style: &StrokeStyle, paint: impl Into<Paint>) {
+        let mut path = Path::new();
+        for &[x, y] in points {
+            path.line_to(x, y);
+        }
+        self.stroke_path(&path, style, paint);
+    }
 
-        // 스케일과 위치 변환을 적용한 버텍스들 생성
-        let vertices_transformed: Vec<f32> = self.circle_vertices
-            .chunks(3)
-            .flat_map(|v| {
-                let x = center_x + v[0] * radius;
-                let y = center_y + v[1] * radius;
-                vec![(x / self.screen_width) * 2.0 - 1.0, 1.0 - (y / self.screen_height) * 2.0, 0.0]
-            })
-            .collect();
+    /// (x0, y0)에서 (x1, y1)까지 직선을 그립니다.
+    pub fn draw_line(
+        &mut self,
+        x0: f32,
+        y0: f32,
+        x1: f32,
+        y1: f32,
+        width: f32,
+        paint: impl Into<Paint>
+    ) {
+        self.draw_polyline(&[[x0, y0], [x1, y1]], &StrokeStyle::new(width), paint);
+    }
 
-        unsafe {
-            gl::BindVertexArray(self.vao);
-            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                (vertices_transformed.len() * std::mem::size_of::<f32>()) as isize,
-                vertices_transformed.as_ptr() as *const _,
-                gl::STATIC_DRAW
+    fn push_path_mesh(&mut self, mesh: PathMesh, paint: Option<u32>) {
+        self.draw_list.push(
+            BatchState { kind: BatchKind::Path { paint }, texture_id: NO_TEXTURE },
+            &mesh.vertices,
+            &mesh.indices
+        );
+    }
+
+    /// 키보드 포커스를 가진 위젯 주위에 포커스 링(외곽선)을 그립니다.
+    /// (x, y, width, height)는 위젯 영역이며, 링은 그 바깥쪽에 약간 떨어져 그려집니다.
+    pub fn draw_focus_ring(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        const RING_OFFSET: f32 = 3.0;
+        const RING_WIDTH: f32 = 2.0;
+
+        let color = self.focus_ring_color;
+        let (outer_x, outer_y) = (x - RING_OFFSET - RING_WIDTH, y - RING_OFFSET - RING_WIDTH);
+        let outer_width = width + (RING_OFFSET + RING_WIDTH) * 2.0;
+        let outer_height = height + (RING_OFFSET + RING_WIDTH) * 2.0;
+
+        // 위, 아래, 왼쪽, 오른쪽 변
+        let side_height = outer_height - RING_WIDTH * 2.0;
+        self.draw_rect(outer_x, outer_y, outer_width, RING_WIDTH, color);
+        self.draw_rect(outer_x, outer_y + outer_height - RING_WIDTH, outer_width, RING_WIDTH, color);
+        self.draw_rect(outer_x, outer_y + RING_WIDTH, RING_WIDTH, side_height, color);
+        self.draw_rect(
+            outer_x + outer_width - RING_WIDTH,
+            outer_y + RING_WIDTH,
+            RING_WIDTH,
+            side_height,
+            color
+        );
+    }
+
+    /// `draw_text`와 `draw_text_with_font`가 글리프를 아틀라스에 올리는 방식.
+    ///
+    /// `TextRenderMode::Sdf`는 크기가 계속 바뀌는 텍스트(애니메이션, DPI 변경)를 다시 래스터화하지 않고
+    /// 선명하게 그리며, 작은 고정 크기 텍스트는 `Bitmap`이 더 선명합니다.
+    pub fn set_text_render_mode(&mut self, mode: TextRenderMode) {
+        self.text_render_mode = mode;
+    }
+
+    pub fn text_render_mode(&self) -> TextRenderMode {
+        self.text_render_mode
+    }
+
+    /// 텍스트를 (x, y) 좌상단 기준으로 드로우 리스트에 추가합니다.
+    /// 글리프는 아틀라스에서 가져오므로 같은 아틀라스 페이지의 텍스트는 하나의 배치로 합쳐집니다.
+    pub fn draw_text(&mut self, text: &str, x: f32, y: f32, scale: f32, color: [f32; 4]) {
+        if self.text_render_mode == TextRenderMode::Sdf {
+            let font = self.text_renderer.font_renderer().clone();
+            self.draw_text_with_effect(&font, text, x, y, scale, color, &TextEffect::default());
+            return;
+        }
+        let quads = self.text_renderer.layout_quads(text, x, y, scale);
+        self.push_glyph_quads(quads, color);
+    }
+
+    /// `draw_text`와 같지만 기본 글꼴 대신 `font`(`Renderer::font`로 찾은 글꼴)로 그립니다.
+    pub fn draw_text_with_font(
+        &mut self,
+        font: &FontRenderer,
+        text: &str,
+        x: f32,
+        y: f32,
+        scale: f32,
+        color: [f32; 4]
+    ) {
+        if self.text_render_mode == TextRenderMode::Sdf {
+            self.draw_text_with_effect(font, text, x, y, scale, color, &TextEffect::default());
+            return;
+        }
+        let quads = self.text_renderer.layout_quads_with_font(font, text, x, y, scale);
+        self.push_glyph_quads(quads, color);
+    }
+
+    /// 거리 필드(SDF) 글리프로 텍스트를 그리고 외곽선, 글로우, 그림자 효과를 적용합니다.
+    /// `set_text_render_mode`와 상관없이 항상 SDF로 그립니다.
+    ///
+    /// 효과는 모든 글자 뒤에 먼저 그리므로, 한 글자의 그림자나 외곽선이 앞 글자를 덮지 않습니다.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_with_effect(
+        &mut self,
+        font: &FontRenderer,
+        text: &str,
+        x: f32,
+        y: f32,
+        scale: f32,
+        color: [f32; 4],
+        effect: &TextEffect
+    ) {
+        let quads = self.text_renderer.layout_sdf_quads(font, text, x, y, scale);
+        if !effect.is_empty() {
+            let style = self.sdf_style(SdfStyle { effect: *effect, fill: false });
+            self.push_sdf_quads(&quads, style, color);
+        }
+        let style = self.sdf_style(SdfStyle { effect: TextEffect::default(), fill: true });
+        self.push_sdf_quads(&quads, style, color);
+    }
+
+    // 같은 효과는 한 번만 등록해서 배치가 합쳐지도록 합니다.
+    fn sdf_style(&mut self, style: SdfStyle) -> u32 {
+        let index = match self.sdf_styles.iter().position(|s| *s == style) {
+            Some(index) => index,
+            None => {
+                self.sdf_styles.push(style);
+                self.sdf_styles.len() - 1
+            }
+        };
+        index as u32
+    }
+
+    fn push_sdf_quads(&mut self, quads: &[GlyphQuad], style: u32, color: [f32; 4]) {
+        for quad in quads {
+            self.draw_list.push_quad(
+                BatchState { kind: BatchKind::SdfText { style }, texture_id: quad.texture_id },
+                quad.rect,
+                quad.uv,
+                color
             );
-            gl::DrawArrays(gl::TRIANGLE_FAN, 0, CIRCLE_SEGMENTS as i32);
         }
+    }
 
-        unsafe {
-            gl::UseProgram(current_shader as u32);
+    /// 글리프를 기준선을 중심으로 기울여 그립니다 (이탤릭 글꼴이 없을 때의 합성 이탤릭).
+    /// `skew`는 기준선에서 1픽셀 위로 갈 때마다 오른쪽으로 밀리는 양입니다.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_skewed(
+        &mut self,
+        font: &FontRenderer,
+        text: &str,
+        x: f32,
+        y: f32,
+        scale: f32,
+        skew: f32,
+        color: [f32; 4]
+    ) {
+        let baseline = y + font.measure("", scale).ascent;
+        let quads = self.text_renderer.layout_quads_with_font(font, text, x, y, scale);
+        for quad in quads {
+            let [x0, y0, x1, y1] = quad.rect;
+            let [u0, v0, u1, v1] = quad.uv;
+            let (top, bottom) = ((baseline - y0) * skew, (baseline - y1) * skew);
+            let vertices = [
+                Vertex::new(x0 + top, y0, u0, v0, color),
+                Vertex::new(x1 + top, y0, u1, v0, color),
+                Vertex::new(x1 + bottom, y1, u1, v1, color),
+                Vertex::new(x0 + bottom, y1, u0, v1, color),
+            ];
+            self.draw_list.push(
+                BatchState { kind: BatchKind::Text, texture_id: quad.texture_id },
+                &vertices,
+                &[0, 1, 2, 2, 3, 0]
+            );
         }
     }
+
+    fn push_glyph_quads(&mut self, quads: Vec<GlyphQuad>, color: [f32; 4]) {
+        for quad in quads {
+            self.draw_list.push_quad(
+                BatchState { kind: BatchKind::Text, texture_id: quad.texture_id },
+                quad.rect,
+                quad.uv,
+                color
+            );
+        }
+    }
+}
+
+fn set_sdf_uniforms(shader: &Shader, style: &SdfStyle) {
+    let effect = &style.effect;
+    shader.set_float("sdfSpread", SDF_SPREAD);
+    shader.set_float("drawFill", if style.fill { 1.0 } else { 0.0 });
+    shader.set_float("outlineWidth", effect.outline_width);
+    shader.set_vec4("outlineColor", &effect.outline_color);
+    shader.set_float("glowRadius", effect.glow_radius);
+    shader.set_vec4("glowColor", &effect.glow_color);
+    shader.set_vec2("shadowOffset", &effect.shadow_offset);
+    shader.set_float("shadowSoftness", effect.shadow_softness);
+    shader.set_vec4("shadowColor", &effect.shadow_color);
+}
+
+fn set_layer_uniforms(shader: &Shader, effect: &LayerEffect) {
+    shader.set_float("blur", effect.blur);
+    shader.set_float("saturation", effect.saturation);
+}
+
+fn set_paint_uniforms(shader: &Shader, paint: Option<&Paint>) {
+    let (paint_type, geometry) = match paint {
+        None | Some(Paint::Solid(_)) => (0, [0.0; 4]),
+        Some(Paint::Linear { start, end, .. }) => (1, [start[0], start[1], end[0], end[1]]),
+        Some(Paint::Radial { center, radius, .. }) => (2, [center[0], center[1], radius[0], radius[1]]),
+        Some(Paint::Conic { center, start_angle, .. }) => (3, [center[0], center[1], *start_angle, 0.0]),
+    };
+    shader.set_int("paintType", paint_type);
+    if paint_type == 0 {
+        return;
+    }
+
+    let stops = paint.map(|paint| paint.stops()).unwrap_or(&[]);
+    shader.set_vec4("paintGeometry", &geometry);
+    shader.set_int("stopCount", stops.len() as i32);
+    for (i, stop) in stops.iter().enumerate() {
+        shader.set_float(&format!("stopOffsets[{}]", i), stop.offset);
+        shader.set_vec4(&format!("stopColors[{}]", i), &stop.color);
+    }
 }
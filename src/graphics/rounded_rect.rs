@@ -0,0 +1,81 @@
+/// 사각형 네 모서리의 반지름 (픽셀). 값이 0인 모서리는 각지게 그립니다.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct CornerRadius {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+impl CornerRadius {
+    pub fn new(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Self {
+        Self { top_left, top_right, bottom_right, bottom_left }
+    }
+
+    /// 네 모서리가 같은 반지름
+    pub fn uniform(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.to_array().iter().all(|&radius| radius <= 0.0)
+    }
+
+    /// 둥근 모서리만 `amount`만큼 키운 반지름 (테두리나 그림자의 바깥쪽 윤곽선)
+    pub fn expanded(&self, amount: f32) -> Self {
+        let grow = |radius: f32| if radius > 0.0 { (radius + amount).max(0.0) } else { 0.0 };
+        Self::new(
+            grow(self.top_left),
+            grow(self.top_right),
+            grow(self.bottom_right),
+            grow(self.bottom_left)
+        )
+    }
+
+    /// (왼쪽 위, 오른쪽 위, 오른쪽 아래, 왼쪽 아래) 순서의 배열
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.top_left, self.top_right, self.bottom_right, self.bottom_left]
+    }
+
+    // 반지름이 사각형의 짧은 변 절반을 넘지 않도록 제한
+    pub(crate) fn clamped(&self, width: f32, height: f32) -> [f32; 4] {
+        let max_radius = (width.min(height) / 2.0).max(0.0);
+        self.to_array().map(|radius| radius.clamp(0.0, max_radius))
+    }
+}
+
+impl From<f32> for CornerRadius {
+    fn from(radius: f32) -> Self {
+        Self::uniform(radius)
+    }
+}
+
+/// 사각형 아래에 깔리는 부드러운 그림자 (CSS의 `box-shadow`와 같은 의미).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxShadow {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    /// 그림자 가장자리가 흐려지는 폭 (픽셀, 0이면 선명한 가장자리)
+    pub blur: f32,
+    /// 그림자를 사각형보다 키우는(음수면 줄이는) 양 (픽셀)
+    pub spread: f32,
+    pub color: [f32; 4],
+}
+
+impl BoxShadow {
+    pub fn new(offset_x: f32, offset_y: f32, blur: f32, color: [f32; 4]) -> Self {
+        Self { offset_x, offset_y, blur: blur.max(0.0), spread: 0.0, color }
+    }
+
+    pub fn with_spread(mut self, spread: f32) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// 그림자 색의 알파에 `opacity`를 곱한 복사본 (위젯이 사라지는 애니메이션 중)
+    pub fn with_opacity(&self, opacity: f32) -> Self {
+        let mut shadow = *self;
+        shadow.color[3] *= opacity;
+        shadow
+    }
+}
@@ -70,6 +70,24 @@ impl Shader {
         }
     }
 
+    pub fn set_int(&self, name: &str, value: i32) {
+        unsafe {
+            let c_name = CString::new(name).unwrap();
+            gl::Uniform1i(gl::GetUniformLocation(self.program, c_name.as_ptr()), value);
+        }
+    }
+
+    pub fn set_vec2(&self, name: &str, value: &[f32; 2]) {
+        unsafe {
+            let c_name = CString::new(name).unwrap();
+            gl::Uniform2fv(
+                gl::GetUniformLocation(self.program, c_name.as_ptr()),
+                1,
+                value.as_ptr()
+            );
+        }
+    }
+
     pub fn set_vec4(&self, name: &str, value: &[f32; 4]) {
         unsafe {
             let c_name = CString::new(name).unwrap();
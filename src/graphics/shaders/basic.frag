@@ -1,7 +1,7 @@
 #version 330 core
+in vec4 Color;
 out vec4 fragColor;
-uniform vec4 uColor;
 
 void main() {
-    fragColor = uColor;
-}
\ No newline at end of file
+    fragColor = Color;
+}
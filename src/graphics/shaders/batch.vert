@@ -0,0 +1,28 @@
+#version 330 core
+layout (location = 0) in vec2 aPos;
+layout (location = 1) in vec2 aTexCoord;
+layout (location = 2) in vec4 aColor;
+layout (location = 3) in vec2 aEdge;
+layout (location = 4) in vec4 aRect;
+layout (location = 5) in vec4 aRadii;
+layout (location = 6) in vec4 aBorderColor;
+
+out vec2 TexCoord;
+out vec4 Color;
+out vec2 Edge;
+// 둥근 사각형 셰이더만 사용 (사각형 하나의 정점은 모두 같은 값)
+flat out vec4 Rect;
+flat out vec4 Radii;
+flat out vec4 BorderColor;
+
+uniform mat4 projection;
+
+void main() {
+    gl_Position = projection * vec4(aPos, 0.0, 1.0);
+    TexCoord = aTexCoord;
+    Color = aColor;
+    Edge = aEdge;
+    Rect = aRect;
+    Radii = aRadii;
+    BorderColor = aBorderColor;
+}
@@ -0,0 +1,11 @@
+#version 330 core
+in vec2 TexCoord;
+in vec4 Color;
+out vec4 FragColor;
+
+uniform sampler2D glyphAtlas;
+
+void main() {
+    float alpha = texture(glyphAtlas, TexCoord).r;
+    FragColor = vec4(Color.rgb, Color.a * alpha);
+}
@@ -0,0 +1,10 @@
+#version 330 core
+in vec2 TexCoord; // 도형 경계 사각형 기준 비율 좌표
+in vec4 Color;
+out vec4 FragColor;
+
+vec4 paintColor(vec2 uv, vec4 vertexColor);
+
+void main() {
+    FragColor = paintColor(TexCoord, Color);
+}
@@ -0,0 +1,44 @@
+#version 330 core
+in vec2 TexCoord;
+in vec4 Color; // 알파가 레이어 불투명도
+out vec4 FragColor;
+
+uniform sampler2D texture1; // 알파를 곱한(premultiplied) 레이어 텍스처
+uniform float blur; // 가우시안 표준편차 (픽셀)
+uniform float saturation;
+
+// 한 방향의 샘플 수 (중심 제외). 큰 흐림은 샘플 간격을 넓혀서 근사합니다.
+const int TAPS = 6;
+
+vec4 blurred(vec2 uv) {
+    if (blur <= 0.0) {
+        return texture(texture1, uv);
+    }
+    vec2 texel = 1.0 / vec2(textureSize(texture1, 0));
+    float spacing = max(blur * 3.0 / float(TAPS), 1.0);
+    vec4 sum = vec4(0.0);
+    float total = 0.0;
+    for (int y = -TAPS; y <= TAPS; y++) {
+        for (int x = -TAPS; x <= TAPS; x++) {
+            vec2 offset = vec2(float(x), float(y)) * spacing;
+            float weight = exp(-dot(offset, offset) / (2.0 * blur * blur));
+            sum += texture(texture1, uv + offset * texel) * weight;
+            total += weight;
+        }
+    }
+    return sum / total;
+}
+
+void main() {
+    vec4 color = blurred(TexCoord);
+    if (color.a <= 0.0) {
+        FragColor = vec4(0.0);
+        return;
+    }
+
+    // 알파를 나눈 뒤 채도를 조절하고, 블렌딩이 알파를 곱하는 형식으로 출력합니다.
+    vec3 rgb = color.rgb / color.a;
+    float luminance = dot(rgb, vec3(0.2126, 0.7152, 0.0722));
+    rgb = mix(vec3(luminance), rgb, saturation);
+    FragColor = vec4(rgb, color.a) * Color;
+}
@@ -0,0 +1,57 @@
+
+// 채우기 (Paint). 이 파일은 프래그먼트 셰이더 뒤에 이어 붙여서 사용하며,
+// 셰이더는 `vec4 paintColor(vec2 uv, vec4 vertexColor);` 선언만 두고 호출합니다.
+
+// 0 단색(정점 색), 1 선형, 2 원형, 3 원뿔형
+uniform int paintType;
+// 선형: 시작점(xy), 끝점(zw) / 원형: 중심(xy), 반지름(zw) / 원뿔형: 중심(xy), 시작 각도(z)
+uniform vec4 paintGeometry;
+uniform int stopCount;
+uniform float stopOffsets[8];
+uniform vec4 stopColors[8];
+
+// 알파를 곱한 상태로 보간해서 투명한 정지점 주변이 어두워지지 않도록 합니다.
+vec4 mixStops(vec4 a, vec4 b, float t) {
+    vec4 premultiplied = mix(vec4(a.rgb * a.a, a.a), vec4(b.rgb * b.a, b.a), t);
+    if (premultiplied.a <= 0.0) {
+        return vec4(0.0);
+    }
+    return vec4(premultiplied.rgb / premultiplied.a, premultiplied.a);
+}
+
+vec4 gradientAt(float t) {
+    if (stopCount <= 0) {
+        return vec4(0.0);
+    }
+    if (t <= stopOffsets[0]) {
+        return stopColors[0];
+    }
+    for (int i = 1; i < stopCount; i++) {
+        if (t <= stopOffsets[i]) {
+            float span = max(stopOffsets[i] - stopOffsets[i - 1], 1e-5);
+            return mixStops(stopColors[i - 1], stopColors[i], (t - stopOffsets[i - 1]) / span);
+        }
+    }
+    return stopColors[stopCount - 1];
+}
+
+// uv는 도형 경계 사각형 기준 비율 좌표 (0, 0 = 왼쪽 위)
+vec4 paintColor(vec2 uv, vec4 vertexColor) {
+    if (paintType == 0) {
+        return vertexColor;
+    }
+
+    float t;
+    if (paintType == 1) {
+        vec2 direction = paintGeometry.zw - paintGeometry.xy;
+        t = dot(uv - paintGeometry.xy, direction) / max(dot(direction, direction), 1e-6);
+    } else if (paintType == 2) {
+        t = length((uv - paintGeometry.xy) / max(paintGeometry.zw, vec2(1e-6)));
+    } else {
+        // 12시 방향에서 시계 방향으로 잰 각도 (화면 좌표는 y가 아래쪽)
+        vec2 p = uv - paintGeometry.xy;
+        float angle = atan(p.x, -p.y) - paintGeometry.z;
+        t = fract(angle / 6.28318531);
+    }
+    return gradientAt(t) * vertexColor;
+}
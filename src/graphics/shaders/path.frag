@@ -0,0 +1,18 @@
+#version 330 core
+in vec2 TexCoord; // 경로 경계 사각형 기준 비율 좌표 (그라디언트)
+in vec4 Color;
+in vec2 Edge; // 선의 중심에서의 벡터. 길이 0이 중심, 1이 그려진 기하의 바깥 가장자리
+out vec4 FragColor;
+
+vec4 paintColor(vec2 uv, vec4 vertexColor);
+
+void main() {
+    // 기하는 실제 가장자리보다 0.5픽셀 바깥까지 그려져 있으므로, 바깥 가장자리에서 1픽셀 동안 불투명해집니다.
+    // 둥근 끝과 꺾임은 원의 중심에서의 벡터가 보간되므로 길이가 곧 중심에서의 거리입니다.
+    // 채우기 안쪽은 Edge가 0으로 일정해서 미분값이 0이므로 항상 불투명합니다.
+    // 채우기 테두리는 가장자리(Edge 0)에서 바깥(Edge 1)으로만 그려지므로 0.5에서 0으로 옅어집니다.
+    float distance = length(Edge);
+    float coverage = clamp((1.0 - distance) / max(fwidth(distance), 1e-6), 0.0, 1.0);
+    vec4 color = paintColor(TexCoord, Color);
+    FragColor = vec4(color.rgb, color.a * coverage);
+}
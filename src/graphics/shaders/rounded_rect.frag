@@ -0,0 +1,59 @@
+#version 330 core
+in vec2 TexCoord; // 사각형 중심 기준 좌표 (픽셀)
+in vec4 Color;
+// (반너비, 반높이, 사각형 안쪽으로 그리는 테두리 두께, 그림자 흐림 폭)
+// 흐림 폭이 0보다 크면 그림자: 가장자리를 이 폭으로 흐리게 그립니다.
+flat in vec4 Rect;
+// 왼쪽 위, 오른쪽 위, 오른쪽 아래, 왼쪽 아래 모서리 반지름
+flat in vec4 Radii;
+flat in vec4 BorderColor;
+out vec4 FragColor;
+
+vec4 paintColor(vec2 uv, vec4 vertexColor);
+
+// 둥근 사각형 윤곽선까지의 부호 있는 거리 (바깥쪽이 양수)
+float roundedBoxDistance(vec2 p, vec2 size, vec4 r) {
+    float radius = p.x < 0.0 ? (p.y < 0.0 ? r.x : r.w) : (p.y < 0.0 ? r.y : r.z);
+    vec2 q = abs(p) - size + radius;
+    return min(max(q.x, q.y), 0.0) + length(max(q, 0.0)) - radius;
+}
+
+// 오차 함수 근사 (가우시안 그림자의 누적 분포)
+float erfApprox(float x) {
+    float s = sign(x);
+    float a = abs(x);
+    float t = 1.0 + (0.278393 + (0.230389 + 0.078108 * (a * a)) * a) * a;
+    t *= t;
+    return s - s / (t * t);
+}
+
+void main() {
+    vec2 halfSize = Rect.xy;
+    float borderWidth = Rect.z;
+    float blur = Rect.w;
+    vec4 borderColor = BorderColor;
+    float dist = roundedBoxDistance(TexCoord, halfSize, Radii);
+
+    if (blur > 0.0) {
+        float sigma = blur * 0.5;
+        float shadow = 0.5 - 0.5 * erfApprox(dist / (sigma * 1.41421356));
+        FragColor = vec4(Color.rgb, Color.a * shadow);
+        return;
+    }
+
+    vec4 fill = paintColor((TexCoord + halfSize) / max(halfSize * 2.0, vec2(1e-4)), Color);
+
+    // 변환으로 크기가 바뀌어도 가장자리가 약 1픽셀 폭으로 부드럽도록 미분값으로 나눕니다.
+    float pixel = max(fwidth(dist), 1e-4);
+    float coverage = clamp(0.5 - dist / pixel, 0.0, 1.0);
+    if (borderWidth <= 0.0) {
+        FragColor = vec4(fill.rgb, fill.a * coverage);
+        return;
+    }
+
+    // 테두리 안쪽에서는 채우기 색만 보이도록 섞습니다 (반투명 채우기 뒤에 테두리가 비치지 않음).
+    float inner = clamp(0.5 - (dist + borderWidth) / pixel, 0.0, 1.0);
+    float alpha = mix(borderColor.a, fill.a, inner);
+    vec3 rgb = mix(borderColor.rgb * borderColor.a, fill.rgb * fill.a, inner);
+    FragColor = vec4(rgb / max(alpha, 1e-4), alpha * coverage);
+}
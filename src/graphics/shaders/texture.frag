@@ -1,11 +1,11 @@
 #version 330 core
 in vec2 TexCoord;
+in vec4 Color;
 out vec4 FragColor;
 
 uniform sampler2D texture1;
-uniform float uOpacity;
 
 void main() {
     vec4 texColor = texture(texture1, TexCoord);
-    FragColor = vec4(texColor.rgb, texColor.a * uOpacity);
-} 
\ No newline at end of file
+    FragColor = texColor * Color;
+}
@@ -5,6 +5,7 @@ pub struct Texture {
 }
 
 impl Texture {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         let mut id = 0;
         unsafe {
@@ -13,6 +14,10 @@ impl Texture {
         Self { id }
     }
 
+    pub fn id(&self) -> GLuint {
+        self.id
+    }
+
     pub fn bind(&self) {
         unsafe {
             gl::BindTexture(gl::TEXTURE_2D, self.id);
@@ -46,6 +51,25 @@ impl Texture {
             );
         }
     }
+
+    /// 이미 할당된 텍스처의 일부 영역(x, y, width, height)만 갱신합니다.
+    pub fn upload_sub_data(&self, x: i32, y: i32, width: i32, height: i32, data: &[u8]) {
+        unsafe {
+            gl::BindTexture(gl::TEXTURE_2D, self.id);
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+            gl::TexSubImage2D(
+                gl::TEXTURE_2D,
+                0,
+                x,
+                y,
+                width,
+                height,
+                gl::RED,
+                gl::UNSIGNED_BYTE,
+                data.as_ptr() as *const _
+            );
+        }
+    }
 }
 
 impl Drop for Texture {
@@ -0,0 +1,125 @@
+/// 2D 아핀 변환. 점 (x, y)를 (a·x + c·y + e, b·x + d·y + f)로 옮깁니다.
+///
+/// `translate`, `rotate`, `scale`, `skew`는 HTML Canvas처럼 기존 변환의 안쪽(로컬 좌표)에 덧붙이므로,
+/// 나중에 호출한 변환이 점에 먼저 적용됩니다.
+///
+/// ```ignore
+/// // (100, 100)을 중심으로 45도 회전
+/// let t = Transform::translation(100.0, 100.0).rotate(FRAC_PI_4).translate(-100.0, -100.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self { a: 1.0, b: 0.0, c: 0.0, d: 1.0, e: 0.0, f: 0.0 };
+
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    pub fn translation(x: f32, y: f32) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// 원점을 중심으로 회전 (라디안). 화면 좌표는 y가 아래쪽이므로 양수가 시계 방향입니다.
+    pub fn rotation(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    pub fn scaling(x: f32, y: f32) -> Self {
+        Self::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// x축, y축 방향으로 기울이기 (라디안, CSS의 `skew(x, y)`와 같음)
+    pub fn skewing(x: f32, y: f32) -> Self {
+        Self::new(1.0, y.tan(), x.tan(), 1.0, 0.0, 0.0)
+    }
+
+    /// `other`를 먼저 적용한 뒤 이 변환을 적용하는 변환 (행렬 곱 self × other)
+    pub fn multiply(&self, other: &Transform) -> Self {
+        Self::new(
+            self.a * other.a + self.c * other.b,
+            self.b * other.a + self.d * other.b,
+            self.a * other.c + self.c * other.d,
+            self.b * other.c + self.d * other.d,
+            self.a * other.e + self.c * other.f + self.e,
+            self.b * other.e + self.d * other.f + self.f
+        )
+    }
+
+    pub fn translate(self, x: f32, y: f32) -> Self {
+        self.multiply(&Self::translation(x, y))
+    }
+
+    pub fn rotate(self, angle: f32) -> Self {
+        self.multiply(&Self::rotation(angle))
+    }
+
+    pub fn scale(self, x: f32, y: f32) -> Self {
+        self.multiply(&Self::scaling(x, y))
+    }
+
+    pub fn skew(self, x: f32, y: f32) -> Self {
+        self.multiply(&Self::skewing(x, y))
+    }
+
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.a * x + self.c * y + self.e, self.b * x + self.d * y + self.f)
+    }
+
+    /// 역변환. 크기가 0인 변환처럼 되돌릴 수 없으면 `None`입니다.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.a * self.d - self.b * self.c;
+        if det.abs() <= f32::EPSILON {
+            return None;
+        }
+        let (a, b, c, d) = (self.d / det, -self.b / det, -self.c / det, self.a / det);
+        Some(Self::new(a, b, c, d, -(a * self.e + c * self.f), -(b * self.e + d * self.f)))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// 회전이나 기울이기 없이 이동과 크기만 바꾸는 변환인지 (사각형이 축에 나란한 사각형으로 남음)
+    pub fn is_axis_aligned(&self) -> bool {
+        self.b == 0.0 && self.c == 0.0
+    }
+
+    /// 평행 이동 성분 (e, f)
+    pub fn translation_part(&self) -> (f32, f32) {
+        (self.e, self.f)
+    }
+
+    /// (x, y, width, height) 사각형을 변환한 네 꼭짓점의 경계 사각형 (x0, y0, x1, y1)
+    pub fn bounds_of(&self, x: f32, y: f32, width: f32, height: f32) -> [f32; 4] {
+        let corners = [
+            self.apply(x, y),
+            self.apply(x + width, y),
+            self.apply(x + width, y + height),
+            self.apply(x, y + height),
+        ];
+        corners
+            .iter()
+            .fold([f32::MAX, f32::MAX, f32::MIN, f32::MIN], |b, &(x, y)| [
+                b[0].min(x),
+                b[1].min(y),
+                b[2].max(x),
+                b[3].max(y),
+            ])
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
@@ -0,0 +1,282 @@
+use super::{ Edges, LayoutItem, LayoutParams, Rect };
+
+/// 자식을 나열하는 주 축 방향
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlexDirection {
+    /// 왼쪽에서 오른쪽으로
+    #[default]
+    Row,
+    /// 위에서 아래로
+    Column,
+}
+
+/// 주 축에서 남는 공간을 배분하는 방식
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JustifyContent {
+    #[default]
+    Start,
+    End,
+    Center,
+    /// 첫 자식과 마지막 자식을 양 끝에 붙이고 사이 간격을 균등하게
+    SpaceBetween,
+    /// 각 자식 양옆에 같은 간격 (양 끝은 절반)
+    SpaceAround,
+    /// 양 끝을 포함한 모든 간격을 균등하게
+    SpaceEvenly,
+}
+
+/// 교차 축 정렬 방식
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlignItems {
+    Start,
+    End,
+    Center,
+    /// 교차 축 전체를 채우도록 늘림 (최대 크기 제한 안에서)
+    #[default]
+    Stretch,
+}
+
+/// 행/열 방향의 flexbox 스타일 레이아웃.
+///
+/// 자식은 고유 크기(또는 `LayoutParams`의 고정 크기)에서 시작하여, 남는 공간은 `grow` 비율로
+/// 나눠 늘리고 부족한 공간은 `shrink * 기본 크기` 비율로 줄입니다. 최소/최대 크기에 걸린 자식은
+/// 고정하고 나머지 자식에게 다시 배분합니다. 한 줄 배치만 지원하며 줄바꿈(wrap)은 하지 않습니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FlexLayout {
+    pub direction: FlexDirection,
+    pub justify: JustifyContent,
+    pub align: AlignItems,
+    /// 인접한 자식 사이의 간격
+    pub gap: f32,
+    pub padding: Edges,
+}
+
+impl FlexLayout {
+    pub fn new(direction: FlexDirection) -> Self {
+        Self {
+            direction,
+            ..Default::default()
+        }
+    }
+
+    pub fn row() -> Self {
+        Self::new(FlexDirection::Row)
+    }
+
+    pub fn column() -> Self {
+        Self::new(FlexDirection::Column)
+    }
+
+    pub fn with_justify(mut self, justify: JustifyContent) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    pub fn with_align(mut self, align: AlignItems) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn with_padding(mut self, padding: Edges) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn compute(&self, width: f32, height: f32, items: &[LayoutItem]) -> Vec<Rect> {
+        if items.is_empty() {
+            return Vec::new();
+        }
+
+        let row = self.direction == FlexDirection::Row;
+        let (inner_main, inner_cross) = if row {
+            (width - self.padding.horizontal(), height - self.padding.vertical())
+        } else {
+            (height - self.padding.vertical(), width - self.padding.horizontal())
+        };
+        let (main_start, cross_start) = if row {
+            (self.padding.left, self.padding.top)
+        } else {
+            (self.padding.top, self.padding.left)
+        };
+
+        // 1. 주 축 기본 크기와 남는(부족한) 공간
+        let bases: Vec<f32> = items
+            .iter()
+            .map(|item| main_axis(row, item.base_size()))
+            .collect();
+        let used: f32 =
+            bases
+                .iter()
+                .zip(items)
+                .map(|(base, item)| base + main_margin(row, &item.params.margin))
+                .sum::<f32>() +
+            self.gap * ((items.len() - 1) as f32);
+        let free = inner_main - used;
+
+        // 2. grow/shrink로 주 축 크기 결정
+        let (sizes, remaining) = resolve_flexible_lengths(row, items, &bases, free);
+
+        // 3. justify로 시작 위치와 추가 간격 결정
+        let leftover = remaining.max(0.0);
+        let count = items.len() as f32;
+        let (offset, spacing) = match self.justify {
+            JustifyContent::Start => (0.0, 0.0),
+            JustifyContent::End => (leftover, 0.0),
+            JustifyContent::Center => (leftover / 2.0, 0.0),
+            JustifyContent::SpaceBetween if items.len() > 1 => (0.0, leftover / (count - 1.0)),
+            JustifyContent::SpaceBetween => (0.0, 0.0),
+            JustifyContent::SpaceAround => (leftover / count / 2.0, leftover / count),
+            JustifyContent::SpaceEvenly => (leftover / (count + 1.0), leftover / (count + 1.0)),
+        };
+
+        // 4. 자식마다 주 축 위치와 교차 축 크기/위치 계산
+        let mut cursor = main_start + offset;
+        let mut rects = Vec::with_capacity(items.len());
+        for (item, main_size) in items.iter().zip(sizes) {
+            let margin = &item.params.margin;
+            let (margin_main_start, margin_main_end, margin_cross_start, margin_cross_end) = if row {
+                (margin.left, margin.right, margin.top, margin.bottom)
+            } else {
+                (margin.top, margin.bottom, margin.left, margin.right)
+            };
+
+            cursor += margin_main_start;
+            let main_pos = cursor;
+            cursor += main_size + margin_main_end + self.gap + spacing;
+
+            let available_cross = inner_cross - margin_cross_start - margin_cross_end;
+            let base_cross = cross_axis(row, item.base_size());
+            let align = item.params.align_self.unwrap_or(self.align);
+            let fixed_cross = if row { item.params.height } else { item.params.width };
+            let cross_size = match align {
+                // 고정 크기가 지정된 자식은 늘리지 않음
+                AlignItems::Stretch if fixed_cross.is_none() =>
+                    clamp_cross(row, &item.params, available_cross),
+                _ => base_cross,
+            };
+            let cross_offset = match align {
+                AlignItems::Start | AlignItems::Stretch => 0.0,
+                AlignItems::End => available_cross - cross_size,
+                AlignItems::Center => (available_cross - cross_size) / 2.0,
+            };
+            let cross_pos = cross_start + margin_cross_start + cross_offset;
+
+            rects.push(if row {
+                Rect::new(main_pos, cross_pos, main_size, cross_size)
+            } else {
+                Rect::new(cross_pos, main_pos, cross_size, main_size)
+            });
+        }
+
+        rects
+    }
+
+    pub fn measure(&self, items: &[LayoutItem]) -> (f32, f32) {
+        let row = self.direction == FlexDirection::Row;
+        let mut main = 0.0;
+        let mut cross: f32 = 0.0;
+
+        for item in items {
+            let margin = &item.params.margin;
+            let size = item.base_size();
+            main += main_axis(row, size) + main_margin(row, margin);
+            cross = cross.max(cross_axis(row, size) + cross_margin(row, margin));
+        }
+        if !items.is_empty() {
+            main += self.gap * ((items.len() - 1) as f32);
+        }
+
+        if row {
+            (main + self.padding.horizontal(), cross + self.padding.vertical())
+        } else {
+            (cross + self.padding.horizontal(), main + self.padding.vertical())
+        }
+    }
+}
+
+// 남는 공간(free > 0)은 grow 비율로, 부족한 공간(free < 0)은 shrink * 기본 크기 비율로 나눕니다.
+// 최소/최대 크기에 걸린 자식은 고정하고 나머지에게 다시 배분하며, 배분하지 못한 공간을 함께 반환합니다.
+fn resolve_flexible_lengths(
+    row: bool,
+    items: &[LayoutItem],
+    bases: &[f32],
+    free: f32
+) -> (Vec<f32>, f32) {
+    let growing = free > 0.0;
+    let mut sizes = bases.to_vec();
+    let mut frozen = vec![false; items.len()];
+    let mut remaining = free;
+
+    while remaining.abs() > f32::EPSILON {
+        let weights: Vec<f32> = items
+            .iter()
+            .zip(bases)
+            .zip(&frozen)
+            .map(|((item, base), &frozen)| {
+                if frozen {
+                    0.0
+                } else if growing {
+                    item.params.grow
+                } else {
+                    item.params.shrink * base
+                }
+            })
+            .collect();
+        let total_weight: f32 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            break;
+        }
+
+        let mut violated = false;
+        let mut distributed = 0.0;
+        for (index, weight) in weights.iter().enumerate() {
+            if *weight <= 0.0 {
+                continue;
+            }
+            let target = sizes[index] + (remaining * weight) / total_weight;
+            let clamped = clamp_main(row, &items[index].params, target);
+            if clamped != target {
+                frozen[index] = true;
+                violated = true;
+            }
+            distributed += clamped - sizes[index];
+            sizes[index] = clamped;
+        }
+
+        remaining -= distributed;
+        if !violated {
+            break;
+        }
+    }
+
+    (sizes, remaining)
+}
+
+fn main_axis(row: bool, (width, height): (f32, f32)) -> f32 {
+    if row { width } else { height }
+}
+
+fn cross_axis(row: bool, (width, height): (f32, f32)) -> f32 {
+    if row { height } else { width }
+}
+
+fn main_margin(row: bool, margin: &Edges) -> f32 {
+    if row { margin.horizontal() } else { margin.vertical() }
+}
+
+fn cross_margin(row: bool, margin: &Edges) -> f32 {
+    if row { margin.vertical() } else { margin.horizontal() }
+}
+
+fn clamp_main(row: bool, params: &LayoutParams, value: f32) -> f32 {
+    if row { params.clamp_width(value) } else { params.clamp_height(value) }
+}
+
+fn clamp_cross(row: bool, params: &LayoutParams, value: f32) -> f32 {
+    if row { params.clamp_height(value) } else { params.clamp_width(value) }
+}
@@ -0,0 +1,373 @@
+use super::{ AlignItems, Edges, LayoutItem, Rect };
+
+/// 그리드의 행 또는 열 하나의 크기 지정 방식
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrackSize {
+    /// 고정 픽셀 크기
+    Fixed(f32),
+    /// 고정/자동 트랙을 뺀 나머지 공간을 비율로 나눠 가짐 (CSS의 `fr`)
+    Fraction(f32),
+    /// 트랙에 들어간 자식 중 가장 큰 기본 크기
+    Auto,
+}
+
+/// 자식이 차지하는 그리드 셀 위치 (0부터 시작하는 열/행 인덱스와 span)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GridPlacement {
+    pub column: usize,
+    pub row: usize,
+    pub column_span: usize,
+    pub row_span: usize,
+}
+
+impl GridPlacement {
+    pub fn new(column: usize, row: usize) -> Self {
+        Self { column, row, column_span: 1, row_span: 1 }
+    }
+
+    pub fn with_span(mut self, column_span: usize, row_span: usize) -> Self {
+        self.column_span = column_span.max(1);
+        self.row_span = row_span.max(1);
+        self
+    }
+}
+
+/// 행/열 트랙 정의에 따라 자식을 2차원으로 배치하는 그리드 레이아웃.
+///
+/// `LayoutParams::cell`이 지정된 자식은 해당 셀에, 그렇지 않은 자식은 추가된 순서대로 비어 있는
+/// 첫 셀에 행 우선으로 배치됩니다. 정의된 행보다 자식이 많으면 `Auto` 행이 자동으로 추가됩니다.
+/// 셀 안에서의 정렬은 `justify_items`/`align_items`(자식별로는 `justify_self`/`align_self`)로 정합니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridLayout {
+    pub columns: Vec<TrackSize>,
+    pub rows: Vec<TrackSize>,
+    pub column_gap: f32,
+    pub row_gap: f32,
+    pub padding: Edges,
+    /// 셀 안의 가로 정렬
+    pub justify_items: AlignItems,
+    /// 셀 안의 세로 정렬
+    pub align_items: AlignItems,
+}
+
+impl GridLayout {
+    pub fn new(columns: Vec<TrackSize>, rows: Vec<TrackSize>) -> Self {
+        Self {
+            columns,
+            rows,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_gap(mut self, column_gap: f32, row_gap: f32) -> Self {
+        self.column_gap = column_gap;
+        self.row_gap = row_gap;
+        self
+    }
+
+    pub fn with_padding(mut self, padding: Edges) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_justify_items(mut self, justify: AlignItems) -> Self {
+        self.justify_items = justify;
+        self
+    }
+
+    pub fn with_align_items(mut self, align: AlignItems) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    pub fn compute(&self, width: f32, height: f32, items: &[LayoutItem]) -> Vec<Rect> {
+        if items.is_empty() {
+            return Vec::new();
+        }
+
+        let (placements, rows) = self.place_items(items);
+        let columns = self.column_tracks();
+
+        let column_sizes = resolve_tracks(
+            &columns,
+            width - self.padding.horizontal(),
+            self.column_gap,
+            items,
+            &placements,
+            true
+        );
+        let row_sizes = resolve_tracks(
+            &rows,
+            height - self.padding.vertical(),
+            self.row_gap,
+            items,
+            &placements,
+            false
+        );
+        let column_offsets = track_offsets(&column_sizes, self.padding.left, self.column_gap);
+        let row_offsets = track_offsets(&row_sizes, self.padding.top, self.row_gap);
+
+        items
+            .iter()
+            .zip(&placements)
+            .map(|(item, cell)| {
+                let cell_x = column_offsets[cell.column];
+                let cell_y = row_offsets[cell.row];
+                let cell_width = span_size(
+                    &column_sizes,
+                    cell.column,
+                    cell.column_span,
+                    self.column_gap
+                );
+                let cell_height = span_size(&row_sizes, cell.row, cell.row_span, self.row_gap);
+
+                let params = &item.params;
+                let (base_width, base_height) = item.base_size();
+                let (x, width) = align_in_cell(
+                    params.justify_self.unwrap_or(self.justify_items),
+                    cell_x + params.margin.left,
+                    cell_width - params.margin.horizontal(),
+                    base_width,
+                    params.width.is_some(),
+                    |value| params.clamp_width(value)
+                );
+                let (y, height) = align_in_cell(
+                    params.align_self.unwrap_or(self.align_items),
+                    cell_y + params.margin.top,
+                    cell_height - params.margin.vertical(),
+                    base_height,
+                    params.height.is_some(),
+                    |value| params.clamp_height(value)
+                );
+                Rect::new(x, y, width, height)
+            })
+            .collect()
+    }
+
+    pub fn measure(&self, items: &[LayoutItem]) -> (f32, f32) {
+        let (placements, rows) = self.place_items(items);
+        let columns = self.column_tracks();
+
+        // 비율 트랙은 남는 공간이 없다고 보고 내용 크기만큼만 차지하도록 계산
+        let column_sizes = resolve_tracks(&columns, 0.0, self.column_gap, items, &placements, true);
+        let row_sizes = resolve_tracks(&rows, 0.0, self.row_gap, items, &placements, false);
+
+        (
+            total_size(&column_sizes, self.column_gap) + self.padding.horizontal(),
+            total_size(&row_sizes, self.row_gap) + self.padding.vertical(),
+        )
+    }
+
+    // 열 정의가 비어 있으면 한 열짜리 그리드로 취급
+    fn column_tracks(&self) -> Vec<TrackSize> {
+        if self.columns.is_empty() { vec![TrackSize::Auto] } else { self.columns.clone() }
+    }
+
+    // 자식마다 셀을 정하고, 필요한 만큼 Auto 행을 추가한 행 트랙 목록을 함께 반환합니다.
+    fn place_items(&self, items: &[LayoutItem]) -> (Vec<GridPlacement>, Vec<TrackSize>) {
+        let column_count = self.column_tracks().len();
+        let mut occupied: Vec<Vec<bool>> = Vec::new();
+        let mut placements = Vec::with_capacity(items.len());
+
+        // 1. 위치가 지정된 자식을 먼저 배치
+        for item in items {
+            let placement = item.params.cell.map(|cell| {
+                let column = cell.column.min(column_count - 1);
+                GridPlacement {
+                    column,
+                    row: cell.row,
+                    column_span: cell.column_span.clamp(1, column_count - column),
+                    row_span: cell.row_span.max(1),
+                }
+            });
+            if let Some(cell) = placement {
+                mark_occupied(&mut occupied, &cell, column_count);
+            }
+            placements.push(placement);
+        }
+
+        // 2. 나머지 자식은 행 우선으로 비어 있는 첫 셀에 배치
+        let mut cursor = (0, 0); // (row, column)
+        let placements: Vec<GridPlacement> = placements
+            .into_iter()
+            .map(|placement| {
+                if let Some(cell) = placement {
+                    return cell;
+                }
+                loop {
+                    let (row, column) = cursor;
+                    let cell = GridPlacement::new(column, row);
+                    cursor = if column + 1 < column_count { (row, column + 1) } else { (row + 1, 0) };
+                    if !is_occupied(&occupied, row, column) {
+                        mark_occupied(&mut occupied, &cell, column_count);
+                        return cell;
+                    }
+                }
+            })
+            .collect();
+
+        let row_count = placements
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.rows.len());
+        let mut rows = self.rows.clone();
+        rows.resize(row_count, TrackSize::Auto);
+
+        (placements, rows)
+    }
+}
+
+fn is_occupied(occupied: &[Vec<bool>], row: usize, column: usize) -> bool {
+    occupied
+        .get(row)
+        .and_then(|cells| cells.get(column))
+        .copied()
+        .unwrap_or(false)
+}
+
+fn mark_occupied(occupied: &mut Vec<Vec<bool>>, cell: &GridPlacement, column_count: usize) {
+    for row in cell.row..cell.row + cell.row_span {
+        if occupied.len() <= row {
+            occupied.resize(row + 1, vec![false; column_count]);
+        }
+        let end = (cell.column + cell.column_span).min(column_count);
+        occupied[row][cell.column..end].fill(true);
+    }
+}
+
+// 트랙 크기를 결정합니다. 고정 → 자동(내용 크기) → 비율(남은 공간) 순서로 계산하며,
+// 여러 트랙에 걸친 자식이 트랙 합보다 크면 모자란 만큼을 걸친 자동 트랙에 나눠 줍니다.
+fn resolve_tracks(
+    tracks: &[TrackSize],
+    available: f32,
+    gap: f32,
+    items: &[LayoutItem],
+    placements: &[GridPlacement],
+    horizontal: bool
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| if let TrackSize::Fixed(size) = track { *size } else { 0.0 })
+        .collect();
+
+    let content_size = |item: &LayoutItem| {
+        let (width, height) = item.base_size();
+        if horizontal {
+            width + item.params.margin.horizontal()
+        } else {
+            height + item.params.margin.vertical()
+        }
+    };
+    let span_of = |cell: &GridPlacement| {
+        if horizontal { (cell.column, cell.column_span) } else { (cell.row, cell.row_span) }
+    };
+    // 내용 크기로 정해지는 트랙 (측정할 때는 비율 트랙도 포함)
+    let is_content_sized = |track: &TrackSize| {
+        matches!(track, TrackSize::Auto) ||
+            (available <= 0.0 && matches!(track, TrackSize::Fraction(_)))
+    };
+
+    // 한 칸짜리 자식으로 자동 트랙 크기 결정
+    for (item, cell) in items.iter().zip(placements) {
+        let (start, span) = span_of(cell);
+        if span == 1 && is_content_sized(&tracks[start]) {
+            sizes[start] = sizes[start].max(content_size(item));
+        }
+    }
+
+    // 여러 칸에 걸친 자식이 들어가도록 자동 트랙을 늘림
+    for (item, cell) in items.iter().zip(placements) {
+        let (start, span) = span_of(cell);
+        if span < 2 {
+            continue;
+        }
+        let range = start..(start + span).min(tracks.len());
+        // 비율 트랙에 걸쳐 있으면 남는 공간은 비율 트랙이 차지하도록 둠
+        let spans_fraction = tracks[range.clone()]
+            .iter()
+            .any(|track| matches!(track, TrackSize::Fraction(_)));
+        if available > 0.0 && spans_fraction {
+            continue;
+        }
+        let auto_tracks: Vec<usize> = range
+            .clone()
+            .filter(|&index| is_content_sized(&tracks[index]))
+            .collect();
+        let current = span_size(&sizes, start, range.len(), gap);
+        let deficit = content_size(item) - current;
+        if deficit > 0.0 && !auto_tracks.is_empty() {
+            let share = deficit / (auto_tracks.len() as f32);
+            for index in auto_tracks {
+                sizes[index] += share;
+            }
+        }
+    }
+
+    // 남은 공간을 비율 트랙에 배분
+    if available > 0.0 {
+        let total_fraction: f32 = tracks
+            .iter()
+            .map(|track| if let TrackSize::Fraction(fr) = track { *fr } else { 0.0 })
+            .sum();
+        if total_fraction > 0.0 {
+            let used: f32 = sizes.iter().sum::<f32>() + gap * ((tracks.len().max(1) - 1) as f32);
+            let free = (available - used).max(0.0);
+            for (size, track) in sizes.iter_mut().zip(tracks) {
+                if let TrackSize::Fraction(fr) = track {
+                    *size = (free * fr) / total_fraction;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+fn track_offsets(sizes: &[f32], start: f32, gap: f32) -> Vec<f32> {
+    let mut offset = start;
+    sizes
+        .iter()
+        .map(|size| {
+            let current = offset;
+            offset += size + gap;
+            current
+        })
+        .collect()
+}
+
+fn span_size(sizes: &[f32], start: usize, span: usize, gap: f32) -> f32 {
+    let end = (start + span).min(sizes.len());
+    let count = end.saturating_sub(start);
+    sizes[start..end].iter().sum::<f32>() + gap * ((count.max(1) - 1) as f32)
+}
+
+fn total_size(sizes: &[f32], gap: f32) -> f32 {
+    span_size(sizes, 0, sizes.len(), gap)
+}
+
+// 셀(시작 위치, 크기) 안에서 자식의 위치와 크기를 정렬 방식에 맞게 계산
+fn align_in_cell<F>(
+    align: AlignItems,
+    start: f32,
+    available: f32,
+    base: f32,
+    fixed: bool,
+    clamp: F
+) -> (f32, f32)
+    where F: Fn(f32) -> f32
+{
+    let size = match align {
+        // 고정 크기가 지정된 자식은 늘리지 않음
+        AlignItems::Stretch if !fixed => clamp(available),
+        _ => base,
+    };
+    let offset = match align {
+        AlignItems::Start | AlignItems::Stretch => 0.0,
+        AlignItems::End => available - size,
+        AlignItems::Center => (available - size) / 2.0,
+    };
+    (start + offset, size)
+}
+
@@ -0,0 +1,173 @@
+pub mod flex;
+pub mod grid;
+
+pub use flex::{ AlignItems, FlexDirection, FlexLayout, JustifyContent };
+pub use grid::{ GridLayout, GridPlacement, TrackSize };
+
+/// 사각형의 네 변에 대한 여백 (padding, margin)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Edges {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Edges {
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self { left, top, right, bottom }
+    }
+
+    /// 네 변 모두 같은 여백
+    pub fn all(value: f32) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// 좌우와 상하 여백을 따로 지정
+    pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self::new(horizontal, vertical, horizontal, vertical)
+    }
+
+    /// 좌우 여백의 합
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// 상하 여백의 합
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+/// 레이아웃 계산 결과로 나오는 부모 기준 사각형
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
+}
+
+/// 부모 레이아웃 안에서 자식 하나가 배치되는 방식
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutParams {
+    /// 남는 공간을 나눠 가질 비율 (0이면 늘어나지 않음)
+    pub grow: f32,
+    /// 공간이 부족할 때 줄어드는 비율 (0이면 줄어들지 않음)
+    pub shrink: f32,
+    /// 고정 크기. 지정하지 않으면 위젯의 고유 크기를 사용합니다.
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub min_width: f32,
+    pub min_height: f32,
+    pub max_width: f32,
+    pub max_height: f32,
+    pub margin: Edges,
+    /// 교차 축(그리드에서는 셀 안의 세로) 정렬을 이 자식만 다르게 지정
+    pub align_self: Option<AlignItems>,
+    /// 그리드 셀 안의 가로 정렬을 이 자식만 다르게 지정
+    pub justify_self: Option<AlignItems>,
+    /// 그리드에서 차지할 셀. 지정하지 않으면 비어 있는 다음 셀에 배치됩니다.
+    pub cell: Option<GridPlacement>,
+}
+
+impl LayoutParams {
+    pub fn new() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            width: None,
+            height: None,
+            min_width: 0.0,
+            min_height: 0.0,
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
+            margin: Edges::default(),
+            align_self: None,
+            justify_self: None,
+            cell: None,
+        }
+    }
+
+    /// 지정한 그리드 셀에 배치되는 레이아웃 속성
+    pub fn in_cell(cell: GridPlacement) -> Self {
+        Self {
+            cell: Some(cell),
+            ..Self::new()
+        }
+    }
+
+    pub fn clamp_width(&self, width: f32) -> f32 {
+        width.min(self.max_width).max(self.min_width)
+    }
+
+    pub fn clamp_height(&self, height: f32) -> f32 {
+        height.min(self.max_height).max(self.min_height)
+    }
+}
+
+impl Default for LayoutParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 레이아웃 계산에 넘기는 자식 정보
+#[derive(Clone, Copy, Debug)]
+pub struct LayoutItem {
+    /// 늘이거나 줄이기 전의 기본 크기
+    pub size: (f32, f32),
+    pub params: LayoutParams,
+}
+
+impl LayoutItem {
+    /// 고정 크기 지정과 최소/최대 제한을 반영한 기본 크기
+    pub fn base_size(&self) -> (f32, f32) {
+        let width = self.params.width.unwrap_or(self.size.0);
+        let height = self.params.height.unwrap_or(self.size.1);
+        (self.params.clamp_width(width), self.params.clamp_height(height))
+    }
+}
+
+/// 컨테이너가 자식 위젯을 배치하는 방법
+#[derive(Clone, Debug, PartialEq)]
+pub enum Layout {
+    Flex(FlexLayout),
+    Grid(GridLayout),
+}
+
+impl Layout {
+    /// (width, height) 크기의 컨테이너 안에서 각 자식의 사각형을 계산합니다.
+    pub fn compute(&self, width: f32, height: f32, items: &[LayoutItem]) -> Vec<Rect> {
+        match self {
+            Layout::Flex(flex) => flex.compute(width, height, items),
+            Layout::Grid(grid) => grid.compute(width, height, items),
+        }
+    }
+
+    /// 자식을 늘이거나 줄이지 않았을 때 필요한 컨테이너 크기 (padding 포함)
+    pub fn measure(&self, items: &[LayoutItem]) -> (f32, f32) {
+        match self {
+            Layout::Flex(flex) => flex.measure(items),
+            Layout::Grid(grid) => grid.measure(items),
+        }
+    }
+}
+
+impl From<FlexLayout> for Layout {
+    fn from(flex: FlexLayout) -> Self {
+        Layout::Flex(flex)
+    }
+}
+
+impl From<GridLayout> for Layout {
+    fn from(grid: GridLayout) -> Self {
+        Layout::Grid(grid)
+    }
+}
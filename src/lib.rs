@@ -3,6 +3,8 @@ pub mod graphics;
 pub mod text;
 pub mod animation;
 pub mod widget;
+pub mod layout;
+pub mod event;
 pub mod figlet;
 pub mod style;
 use crate::core::{ GLContext, Window };
@@ -12,14 +14,13 @@ pub use crate::graphics::renderer::Renderer;
 pub use crate::text::font::FontRenderer;
 pub use crate::text::text_renderer::TextRenderer;
 pub use crate::animation::Animation;
-pub use crate::widget::Widget;
+pub use crate::widget::{ Widget, WidgetExt };
 pub use crate::widget::widgets::text_view::TextView;
 pub use crate::figlet::figlet_3d::FIGLET_3D_FONT;
 pub use crate::core::error_handler::initialize_error_handler;
 
 use log::info;
 use figlet_rs::FIGfont;
-use gl;
 
 pub const ENGINE_NAME: &str = "BASE-UI";
 pub const ENGINE_VERSION: &str = "1.0.2";
@@ -28,6 +29,25 @@ pub const ENGINE_COPYRIGHT: &str =
     "Copyright © 2025 BASE-UI GUI Engine by CHOI SIHUN, All rights reserved.";
 
 pub fn initialize(window: &Window) -> GLContext {
+    print_banner();
+
+    // OpenGL 컨텍스트 초기화
+    let gl_context = GLContext::new(window);
+    print_gl_info();
+    gl_context
+}
+
+/// 창 없이 초기화합니다 (CI, 테스트, 스크린샷). `HeadlessContext`가 현재 스레드에 바인딩되어 있어야 합니다.
+#[cfg(feature = "headless")]
+pub fn initialize_headless(context: &crate::core::HeadlessContext) -> GLContext {
+    print_banner();
+
+    let gl_context = GLContext::new_headless(context);
+    print_gl_info();
+    gl_context
+}
+
+fn print_banner() {
     core::logger::initialize();
 
     // FIGlet 폰트로 아스키 아트 생성
@@ -40,11 +60,10 @@ pub fn initialize(window: &Window) -> GLContext {
     info!("{}", ENGINE_COPYRIGHT);
     info!("Author: {}", ENGINE_AUTHOR);
     info!("----------------------------------------");
+}
 
-    // OpenGL 컨텍스트 초기화
-    let gl_context = GLContext::new(window);
-
-    // OpenGL 정보 출력
+// OpenGL 정보 출력
+fn print_gl_info() {
     unsafe {
         let version = gl::GetString(gl::VERSION);
         let renderer = gl::GetString(gl::RENDERER);
@@ -58,8 +77,6 @@ pub fn initialize(window: &Window) -> GLContext {
         info!("  GLSL Version: {}", std::ffi::CStr::from_ptr(glsl as *const i8).to_string_lossy());
     }
     info!("----------------------------------------");
-
-    gl_context
 }
 
 #[no_mangle]
@@ -5,16 +5,27 @@
 // 각 섹션은 라이브러리의 다른 기능을 시연합니다.
 
 use base_ui::widget::widgets::context_menu::{ ContextMenu, MenuItem };
-use base_ui::widget::widgets::{ Button, TextView, ImageView };
+use base_ui::widget::widgets::{ Button, TextView, TextInput, TextArea, ImageView, Grid, Panel };
 use base_ui::style::color::Color;
-use glutin::event::{ ElementState, Event, MouseButton, WindowEvent };
+use base_ui::text::{ FontStyle, FontWeight, RichText, TextAlign, TextEffect, TextOverflow, TextSpan };
+use base_ui::layout::{
+    AlignItems,
+    Edges,
+    FlexLayout,
+    GridLayout,
+    GridPlacement,
+    JustifyContent,
+    TrackSize,
+};
+use glutin::event::{ Event, WindowEvent };
 use glutin::event_loop::ControlFlow;
 use std::time::Instant;
-use log::{ info, debug };
+use log::{ info, debug, warn };
 
 use base_ui::core::{ initialize_error_handler, Window };
-use base_ui::graphics::Renderer;
-use base_ui::widget::Widget;
+use base_ui::event::EventDispatcher;
+use base_ui::graphics::{ BoxShadow, CornerRadius, Paint, Renderer };
+use base_ui::widget::{ Widget, WidgetExt };
 use base_ui::widget::widgets::shape::{ Shape, ShapeType };
 
 fn main() {
@@ -35,6 +46,26 @@ fn main() {
     let font_data = include_bytes!("assets/FiraCode-VariableFont_wght.ttf").to_vec();
     let mut renderer = Renderer::new(font_data);
 
+    // FiraCode에는 한글 글리프가 없으므로 시스템 한글 글꼴을 대체 글꼴로 등록
+    // (위젯은 생성될 때 글꼴을 가져가므로 위젯을 만들기 전에 등록)
+    let korean_font_paths = [
+        "/usr/share/fonts/truetype/nanum/NanumGothic.ttf",
+        "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+        "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+        "C:\\Windows\\Fonts\\malgun.ttf",
+        "/System/Library/Fonts/AppleSDGothicNeo.ttc",
+    ];
+    let korean_font = korean_font_paths.iter().find_map(|path| std::fs::read(path).ok());
+    match korean_font {
+        Some(data) => {
+            match renderer.register_font("Korean", FontWeight::NORMAL, FontStyle::Normal, data) {
+                Ok(()) => renderer.set_fallback_chain(&["Korean"]),
+                Err(e) => warn!("{}", e),
+            }
+        }
+        None => warn!("Korean font not found; Korean text will not render"),
+    }
+
     // 흰색 배경 설정
     renderer.set_background_color(1.0, 1.0, 1.0, 1.0);
 
@@ -48,10 +79,60 @@ fn main() {
     let mut label = TextView::new("Hello, Widget!", &renderer);
     label.set_position(200.0, 200.0);
     label.set_font_size(50.0, &renderer);
+    label.set_font_weight(FontWeight::MEDIUM, &renderer);
     label.set_background_color(Color::new(1.0, 0.3, 0.8, 0.3)); // 반투명 핑크
     label.set_text_color(Color::new(0.5, 0.4, 1.0, 1.0)); // 보라색
     label.set_hover_background_color(Color::new(0.5, 0.4, 1.0, 0.3)); // 반투명 보라
     label.set_hover_text_color(Color::new(1.0, 1.0, 1.0, 1.0)); // 흰색
+    // 거리 필드 글리프로 그려서 외곽선과 그림자를 적용
+    label.set_text_effect(
+        Some(
+            TextEffect::new()
+                .with_outline(1.5, [1.0, 1.0, 1.0, 0.9])
+                .with_shadow(2.0, 3.0, 3.0, [0.0, 0.0, 0.0, 0.5])
+        )
+    );
+
+    // 여러 줄 설명 텍스트 (자동 줄바꿈, 양쪽 정렬, 넘치면 말줄임표)
+    let mut description = TextView::new(
+        "BASE-UI is a lightweight OpenGL UI toolkit.\nWidgets, layouts, events and text editing are built on a batched renderer with a glyph atlas, so long text wraps, aligns and truncates inside its box.",
+        &renderer
+    );
+    description.set_position(50.0, 400.0);
+    description.set_font_size(20.0, &renderer);
+    description.set_size(420.0, 0.0);
+    description.set_word_wrap(true);
+    description.set_align(TextAlign::Justify);
+    description.set_line_spacing(1.2);
+    description.set_overflow(TextOverflow::Ellipsis);
+    description.set_size(420.0, 110.0);
+    description.set_background_color(Color::new(0.9, 0.95, 1.0, 1.0));
+
+    // 스팬마다 스타일이 다른 텍스트 (색, 굵기, 크기, 이탤릭, 밑줄, 취소선, 강조)
+    let mut highlight = TextView::new("", &renderer);
+    highlight.set_position(50.0, 530.0);
+    highlight.set_font_size(20.0, &renderer);
+    highlight.set_size(420.0, 0.0);
+    highlight.set_word_wrap(true);
+    highlight.set_rich_text(
+        RichText::new()
+            .with_span(
+                TextSpan::new("fn ")
+                    .with_color(Color::new(0.6, 0.2, 0.7, 1.0))
+                    .with_weight(FontWeight::BOLD)
+            )
+            .with_span(
+                TextSpan::new("main").with_color(Color::new(0.1, 0.4, 0.8, 1.0)).with_size(26.0)
+            )
+            .with_span(TextSpan::new("() renders "))
+            .with_span(TextSpan::new("rich").with_italic(true).with_underline(true))
+            .with_span(TextSpan::new(" text, "))
+            .with_span(TextSpan::new("not stacked views").with_strikethrough(true))
+            .with_span(
+                TextSpan::new(" in one pass.").with_background(Color::new(1.0, 0.9, 0.3, 0.8))
+            ),
+        &renderer
+    );
 
     // Button 생성 및 스타일링
     let mut button = Button::new("Click Me!", &renderer);
@@ -66,6 +147,8 @@ fn main() {
     button.set_hover_border_color(Color::new(0.2, 0.4, 0.9, 1.0)); // 밝은 파란색
     button.set_pressed_border_color(Color::new(0.05, 0.2, 0.6, 1.0)); // 어두운 파란색
     button.set_border_width(2.0);
+    button.set_corner_radius(12.0);
+    button.set_shadow(Some(BoxShadow::new(0.0, 4.0, 10.0, [0.0, 0.0, 0.0, 0.35])));
 
     // ImageView 생성 및 설정
     let mut image_view = ImageView::new();
@@ -86,7 +169,7 @@ fn main() {
     // 3. 컨텍스트 메뉴 설정
     // =========================================
 
-    let mut context_menu = ContextMenu::new();
+    let mut context_menu = ContextMenu::new(&renderer);
 
     // 메뉴 아이템 추가 및 이벤트 핸들러 설정
     let mut item1 = MenuItem::new("Open");
@@ -108,19 +191,101 @@ fn main() {
     rect.set_fill_color(Color::new(1.0, 0.0, 0.0, 1.0));
     rect.set_border_color(Color::new(0.0, 0.0, 0.0, 1.0));
     rect.set_border_width(2.0);
+    rect.set_corner_radius(CornerRadius::new(24.0, 4.0, 24.0, 4.0)); // 모서리마다 다른 반지름
+    rect.set_fill_paint(Some(Paint::vertical_gradient([1.0, 0.35, 0.3, 1.0], [0.7, 0.0, 0.0, 1.0])));
 
     // 초록색 원
     let mut circle = Shape::new(ShapeType::Circle);
-    circle.set_position(400.0, 100.0);
     circle.set_size(100.0, 100.0);
     circle.set_fill_color(Color::new(0.0, 1.0, 0.0, 1.0));
 
     // 파란색 삼각형
     let mut triangle = Shape::new(ShapeType::Triangle);
-    triangle.set_position(600.0, 100.0);
     triangle.set_size(100.0, 100.0);
     triangle.set_fill_color(Color::new(0.0, 0.0, 1.0, 1.0));
 
+    // 원과 삼각형을 가로로 배치하는 패널 (패널을 옮기면 자식도 함께 이동)
+    let mut panel = Panel::new();
+    panel.set_position(380.0, 80.0);
+    panel.set_size(340.0, 140.0);
+    panel.set_background_color(Color::new(0.9, 0.9, 0.9, 1.0));
+    panel.set_border_color(Color::new(0.6, 0.6, 0.6, 1.0));
+    panel.set_border_width(1.0);
+    panel.set_layout(
+        FlexLayout::row()
+            .with_justify(JustifyContent::SpaceEvenly)
+            .with_align(AlignItems::Center)
+            .with_padding(Edges::all(20.0))
+    );
+
+    // 라벨과 버튼을 2열로 배치하는 폼 그리드 (왼쪽 열은 내용 크기, 오른쪽 열은 남은 공간)
+    let mut form = Grid::with_layout(
+        GridLayout::new(vec![TrackSize::Auto, TrackSize::Fraction(1.0)], vec![])
+            .with_gap(10.0, 10.0)
+            .with_padding(Edges::all(10.0))
+            .with_align_items(AlignItems::Center)
+    );
+    form.set_position(50.0, 650.0);
+    form.set_size(460.0, 320.0);
+    form.set_background_color(Color::new(0.95, 0.95, 0.95, 1.0));
+    form.set_border_color(Color::new(0.6, 0.6, 0.6, 1.0));
+    form.set_border_width(1.0);
+
+    let mut name_label = TextView::new("Engine", &renderer);
+    name_label.set_font_size(24.0, &renderer);
+    let mut name_button = Button::new("BASE-UI", &renderer);
+    name_button.set_font_size(24.0, &renderer);
+    let mut version_label = TextView::new("Version", &renderer);
+    version_label.set_font_size(24.0, &renderer);
+    let mut version_button = Button::new("1.0.2", &renderer);
+    version_button.set_font_size(24.0, &renderer);
+    // 사용자 이름과 비밀번호 입력 필드
+    let mut user_label = TextView::new("사용자", &renderer);
+    user_label.set_font_size(24.0, &renderer);
+    let mut user_input = TextInput::new("name", &renderer);
+    user_input.set_font_size(24.0);
+    user_input.set_max_length(Some(20));
+    user_input.set_on_change(|text| {
+        println!("User changed: {}", text);
+    });
+    let mut password_label = TextView::new("비밀번호", &renderer);
+    password_label.set_font_size(24.0, &renderer);
+    let mut password_input = TextInput::new("password", &renderer);
+    password_input.set_font_size(24.0);
+    password_input.set_password(true);
+    password_input.set_on_submit(|_| {
+        println!("Password submitted!");
+    });
+
+    let mut submit_button = Button::new("Submit", &renderer);
+    submit_button.set_font_size(24.0, &renderer);
+    // 가변 글꼴(FiraCode)의 wght 축으로 굵게 표시
+    submit_button.set_font_weight(FontWeight::BOLD, &renderer);
+    submit_button.set_on_click(|| {
+        println!("Submit clicked!");
+    });
+
+    form.add_child(Box::new(name_label));
+    form.add_child(Box::new(name_button));
+    form.add_child(Box::new(version_label));
+    form.add_child(Box::new(version_button));
+    form.add_child(Box::new(user_label));
+    form.add_child(Box::new(user_input));
+    form.add_child(Box::new(password_label));
+    form.add_child(Box::new(password_input));
+    form.add_child_at(Box::new(submit_button), GridPlacement::new(0, 4).with_span(2, 1));
+    form.perform_layout();
+
+    // 여러 줄 메모 입력 영역 (자동 줄바꿈, 스크롤, 실행 취소)
+    let mut notes = TextArea::new("Write notes here...", &renderer);
+    notes.set_position(560.0, 650.0);
+    notes.set_size(420.0, 250.0);
+    notes.set_font_size(20.0);
+    notes.set_text("BASE-UI notes\n\nTab indents, Ctrl+Z undoes.");
+    notes.set_on_change(|text| {
+        println!("Notes changed: {} chars", text.chars().count());
+    });
+
     // =========================================
     // 5. 이벤트 핸들러 설정
     // =========================================
@@ -168,10 +333,40 @@ fn main() {
         println!("Triangle {}!", if is_hovered { "hovered" } else { "unhovered" });
     });
 
+    panel.set_on_click(|| {
+        println!("Panel clicked!");
+    });
+
+    panel.add_child(Box::new(circle));
+    panel.add_child(Box::new(triangle));
+    panel.perform_layout();
+
+    // =========================================
+    // 6. 위젯 컬렉션 구성
+    // =========================================
+
+    // 서로 다른 위젯을 하나의 컬렉션에 담아 동일하게 처리 (뒤에 있을수록 위에 그려짐)
+    let mut widgets: Vec<Box<dyn Widget>> = vec![
+        Box::new(label),
+        Box::new(button),
+        Box::new(image_view),
+        Box::new(rect),
+        Box::new(panel),
+        Box::new(description),
+        Box::new(highlight),
+        Box::new(form),
+        Box::new(notes),
+        Box::new(context_menu) // 메뉴는 항상 가장 위에 그려지고 이벤트를 먼저 받음
+    ];
+
+    // 페이드 아웃/인을 반복할 위젯의 인덱스와 다음 방향 (label, button, rect)
+    let mut fading: Vec<(usize, bool)> = vec![(0, true), (1, true), (3, true)];
+
     // =========================================
-    // 6. 애니메이션 및 이벤트 루프
+    // 7. 애니메이션 및 이벤트 루프
     // =========================================
 
+    let mut dispatcher = EventDispatcher::new();
     let mut last_frame = Instant::now();
 
     event_loop.run(move |event, _, control_flow| {
@@ -189,70 +384,22 @@ fn main() {
                         screen_size = window.size();
 
                         // 모든 위젯의 크기와 위치를 비율에 맞게 조정
-                        label.resize_by_window_size(scale_x, scale_y);
-                        button.resize_by_window_size(scale_x, scale_y);
-                        image_view.resize_by_window_size(scale_x, scale_y);
-
-                        rect.resize_by_window_size(scale_x, scale_y);
-                        circle.resize_by_window_size(scale_x, scale_y);
-                        triangle.resize_by_window_size(scale_x, scale_y);
+                        for widget in widgets.iter_mut() {
+                            widget.resize_by_window_size(scale_x, scale_y);
+                        }
                     }
                     WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
                         window.resize(*new_inner_size);
                         screen_size = window.size();
                     }
-                    WindowEvent::CursorMoved { position, .. } => {
-                        window.set_cursor_position(position);
-                        if let Some((x, y)) = window.get_cursor_position() {
-                            button.update_hover(x as f32, y as f32);
-                            label.update_hover(x as f32, y as f32);
-                            image_view.update_hover(x as f32, y as f32);
-                            // --------------------------------
-                            context_menu.handle_mouse_move(x as f32, y as f32);
-                            // --------------------------------
-                            rect.update_hover(x as f32, y as f32);
-                            circle.update_hover(x as f32, y as f32);
-                            triangle.update_hover(x as f32, y as f32);
-                        }
-                    }
-                    WindowEvent::MouseInput { state, button: MouseButton::Left, .. } => {
-                        if let Some((x, y)) = window.get_cursor_position() {
-                            match state {
-                                ElementState::Pressed => {
-                                    button.on_mouse_press(x as f32, y as f32);
-                                    label.on_mouse_press(x as f32, y as f32);
-                                    image_view.on_mouse_press(x as f32, y as f32);
-                                    // --------------------------------
-                                    context_menu.handle_click(x as f32, y as f32);
-                                    // --------------------------------
-                                    rect.on_mouse_press(x as f32, y as f32);
-                                    circle.on_mouse_press(x as f32, y as f32);
-                                    triangle.on_mouse_press(x as f32, y as f32);
-                                }
-                                ElementState::Released => {
-                                    button.on_mouse_release(x as f32, y as f32);
-                                    label.on_mouse_release(x as f32, y as f32);
-                                    image_view.on_mouse_release(x as f32, y as f32);
-                                    // --------------------------------
-                                    context_menu.handle_click(x as f32, y as f32);
-                                    // --------------------------------
-                                    rect.on_mouse_release(x as f32, y as f32);
-                                    circle.on_mouse_release(x as f32, y as f32);
-                                    triangle.on_mouse_release(x as f32, y as f32);
-                                }
-                            }
-                        }
-                    }
-                    WindowEvent::MouseInput {
-                        state: ElementState::Released,
-                        button: MouseButton::Right,
-                        ..
-                    } => {
-                        if let Some((x, y)) = window.get_cursor_position() {
-                            context_menu.show(x as f32, y as f32);
+                    other => {
+                        if let WindowEvent::CursorMoved { position, .. } = other {
+                            window.set_cursor_position(position);
                         }
+
+                        // 포인터 이벤트는 디스패처가 가장 위에 있는 위젯을 찾아 전달
+                        dispatcher.handle_window_event(&mut widgets, &other);
                     }
-                    _ => (),
                 }
             Event::MainEventsCleared => {
                 let now = Instant::now();
@@ -260,83 +407,35 @@ fn main() {
                 last_frame = now;
 
                 // 애니메이션 업데이트
-                label.update_animations(delta_time);
-                button.update_animations(delta_time);
-                image_view.update_animations(delta_time);
-                // --------------------------------
-                rect.update_animations(delta_time);
-                circle.update_animations(delta_time);
-                triangle.update_animations(delta_time);
-
-                // 페이드 아웃 후 페이드 인 애니메이션
-                if !button.has_fade_animation() {
-                    static mut FADE_OUT: bool = true;
-                    unsafe {
-                        if FADE_OUT {
-                            button.animate_fade(1.0, 0.0, 1.0); // 1.0(불투명) -> 0.0(투명)
-                            FADE_OUT = false;
-                        } else {
-                            button.animate_fade(0.0, 1.0, 1.0); // 0.0(투명) -> 1.0(불투명)
-                            FADE_OUT = true;
-                        }
-                    }
+                for widget in widgets.iter_mut() {
+                    widget.update_animations(delta_time);
                 }
 
-                if !label.has_fade_animation() {
-                    static mut FADE_OUT: bool = true;
-                    unsafe {
-                        if FADE_OUT {
-                            label.animate_fade(1.0, 0.0, 1.0); // 1.0(불투명) -> 0.0(투명)
-                            FADE_OUT = false;
-                        } else {
-                            label.animate_fade(0.0, 1.0, 1.0); // 0.0(투명) -> 1.0(불투명)
-                            FADE_OUT = true;
-                        }
-                    }
-                }
-
-                if !rect.has_fade_animation() {
-                    static mut FADE_OUT: bool = true;
-                    unsafe {
-                        if FADE_OUT {
-                            rect.animate_fade(1.0, 0.0, 1.0); // 1.0(불투명) -> 0.0(투명)
-                            FADE_OUT = false;
+                // 페이드 아웃 후 페이드 인 애니메이션
+                for (index, fade_out) in fading.iter_mut() {
+                    let widget = &mut widgets[*index];
+                    if !widget.has_fade_animation() {
+                        if *fade_out {
+                            widget.animate_fade(1.0, 0.0, 1.0); // 1.0(불투명) -> 0.0(투명)
                         } else {
-                            rect.animate_fade(0.0, 1.0, 1.0); // 0.0(투명) -> 1.0(불투명)
-                            FADE_OUT = true;
+                            widget.animate_fade(0.0, 1.0, 1.0); // 0.0(투명) -> 1.0(불투명)
                         }
+                        *fade_out = !*fade_out;
                     }
                 }
 
-                // 사이즈 랜덤
-                // if loop_count % 100 == 0 {
-                //     let random_size = rand::thread_rng().gen_range(100..300);
-                //     label.set_size(random_size as f32, 50.0);
-                //     button.set_size(random_size as f32, 50.0);
-                //     image_view.set_size(random_size as f32, 200.0);
-                // }
-
                 gl_context.clear(0.2, 0.3, 0.3, 1.0);
 
                 // 기본 렌더링
                 renderer.render(screen_size.0, screen_size.1);
 
                 // 위젯 렌더링 (텍스트 포함)
-                label.draw(&mut renderer, screen_size.0, screen_size.1);
-                button.draw(&mut renderer, screen_size.0, screen_size.1);
-                image_view.draw(&mut renderer, screen_size.0, screen_size.1);
-
-                // 메뉴 렌더링
-                context_menu.draw(&mut renderer, screen_size.0, screen_size.1);
-
-                // 사각형 렌더링
-                rect.draw(&mut renderer, screen_size.0, screen_size.1);
-
-                // 원 렌더링
-                circle.draw(&mut renderer, screen_size.0, screen_size.1);
+                for widget in widgets.iter() {
+                    widget.draw(&mut renderer, screen_size.0, screen_size.1);
+                }
 
-                // 삼각형 렌더링
-                triangle.draw(&mut renderer, screen_size.0, screen_size.1);
+                // 쌓인 드로우 리스트를 배치 단위로 그리기
+                renderer.end_frame();
 
                 window.swap_buffers();
             }
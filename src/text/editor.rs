@@ -0,0 +1,461 @@
+use std::ops::Range;
+
+/// 편집 가능한 텍스트와 캐럿/선택 영역 상태.
+///
+/// 캐럿과 선택 영역의 기준점(anchor)은 바이트가 아닌 문자(char) 인덱스입니다.
+/// 선택 영역은 anchor와 캐럿 사이이며, 둘이 같으면 선택된 텍스트가 없습니다.
+/// 렌더링과 무관한 편집 규칙만 담고 있어 `TextInput`, `TextArea` 등 편집 위젯이 공유합니다.
+///
+/// 텍스트를 바꾸는 편집은 실행 취소 기록에 남으며, 이어서 입력하거나 지운 글자는 하나의 기록으로
+/// 합쳐집니다. `set_text`는 기록을 비웁니다.
+#[derive(Clone, Debug, Default)]
+pub struct TextEditor {
+    text: String,
+    caret: usize,
+    anchor: usize,
+    max_length: Option<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    last_edit: Option<(EditKind, usize)>, // 마지막 편집 종류와 편집 후 캐럿 (기록 합치기용)
+}
+
+// 실행 취소로 되돌릴 편집 전 상태
+#[derive(Clone, Debug)]
+struct Snapshot {
+    text: String,
+    caret: usize,
+    anchor: usize,
+}
+
+// 연속된 편집을 하나의 실행 취소 기록으로 합칠 때 구분하는 편집 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Backspace,
+    Delete,
+    Other,
+}
+
+// 실행 취소 기록의 최대 개수
+const MAX_HISTORY: usize = 200;
+
+impl TextEditor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 텍스트 전체를 바꾸고 캐럿을 끝으로 옮깁니다. 최대 길이를 넘는 부분은 잘립니다.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = match self.max_length {
+            Some(max) => text.chars().take(max).collect(),
+            None => text.to_string(),
+        };
+        self.caret = self.char_count();
+        self.anchor = self.caret;
+        self.clear_history();
+    }
+
+    /// 문자 수
+    pub fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// 입력할 수 있는 최대 문자 수. 현재 텍스트가 더 길면 잘라냅니다.
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        if let Some(max) = max_length {
+            if self.char_count() > max {
+                let text = self.text.clone();
+                self.set_text(&text);
+            }
+        }
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    pub fn anchor(&self) -> usize {
+        self.anchor
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.caret != self.anchor
+    }
+
+    /// 선택된 문자 범위 (시작 <= 끝)
+    pub fn selection(&self) -> Option<Range<usize>> {
+        if self.has_selection() {
+            Some(self.caret.min(self.anchor)..self.caret.max(self.anchor))
+        } else {
+            None
+        }
+    }
+
+    pub fn selected_text(&self) -> &str {
+        match self.selection() {
+            Some(range) => &self.text[self.byte_range(range)],
+            None => "",
+        }
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.char_count();
+    }
+
+    /// 문자 범위를 선택합니다. 캐럿은 `end`에 놓입니다.
+    pub fn select(&mut self, start: usize, end: usize) {
+        let count = self.char_count();
+        self.anchor = start.min(count);
+        self.caret = end.min(count);
+    }
+
+    /// 캐럿을 `index`로 옮깁니다. `extend`가 true면 선택 영역을 넓히고, 아니면 선택을 해제합니다.
+    pub fn move_to(&mut self, index: usize, extend: bool) {
+        self.caret = index.min(self.char_count());
+        if !extend {
+            self.anchor = self.caret;
+        }
+    }
+
+    /// 한 글자 왼쪽으로. 선택 영역이 있으면 (확장하지 않을 때) 선택 시작으로 이동합니다.
+    pub fn move_left(&mut self, extend: bool) {
+        match self.selection() {
+            Some(range) if !extend => self.move_to(range.start, false),
+            _ => self.move_to(self.caret.saturating_sub(1), extend),
+        }
+    }
+
+    /// 한 글자 오른쪽으로. 선택 영역이 있으면 (확장하지 않을 때) 선택 끝으로 이동합니다.
+    pub fn move_right(&mut self, extend: bool) {
+        match self.selection() {
+            Some(range) if !extend => self.move_to(range.end, false),
+            _ => self.move_to(self.caret + 1, extend),
+        }
+    }
+
+    pub fn move_word_left(&mut self, extend: bool) {
+        let index = self.previous_word_boundary(self.caret);
+        self.move_to(index, extend);
+    }
+
+    pub fn move_word_right(&mut self, extend: bool) {
+        let index = self.next_word_boundary(self.caret);
+        self.move_to(index, extend);
+    }
+
+    pub fn move_home(&mut self, extend: bool) {
+        self.move_to(0, extend);
+    }
+
+    pub fn move_end(&mut self, extend: bool) {
+        self.move_to(self.char_count(), extend);
+    }
+
+    /// 캐럿 위치에 문자열을 넣습니다. 선택 영역이 있으면 먼저 지우고, 최대 길이를 넘는 부분은 버립니다.
+    /// 텍스트가 바뀌었으면 true를 반환합니다.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let kind = if self.has_selection() || text.contains(char::is_whitespace) {
+            EditKind::Other
+        } else {
+            EditKind::Insert
+        };
+        self.edit(kind, |editor| editor.insert_unrecorded(text))
+    }
+
+    /// 문자 범위를 `text`로 바꾸고 캐럿을 바뀐 텍스트 끝에 놓습니다 (들여쓰기 등 여러 줄 편집용).
+    /// 바꾼 결과가 최대 길이를 넘으면 `text`를 자르지 않고 아무것도 바꾸지 않은 채 false를 반환합니다.
+    pub fn replace(&mut self, range: Range<usize>, text: &str) -> bool {
+        if let Some(max) = self.max_length {
+            let count = self.char_count();
+            let removed = range.end.min(count).saturating_sub(range.start.min(count));
+            if count - removed + text.chars().count() > max {
+                return false;
+            }
+        }
+
+        self.edit(EditKind::Other, |editor| {
+            editor.select(range.start, range.end);
+            editor.insert_unrecorded(text)
+        })
+    }
+
+    fn insert_unrecorded(&mut self, text: &str) -> bool {
+        let removed = self.remove_selection();
+
+        let available = match self.max_length {
+            Some(max) => max.saturating_sub(self.char_count()),
+            None => usize::MAX,
+        };
+        let inserted: String = text.chars().take(available).collect();
+        if inserted.is_empty() {
+            return removed;
+        }
+
+        let byte = self.byte_index(self.caret);
+        self.text.insert_str(byte, &inserted);
+        self.move_to(self.caret + inserted.chars().count(), false);
+        true
+    }
+
+    /// 선택 영역 또는 캐럿 앞의 한 글자를 지웁니다.
+    pub fn backspace(&mut self) -> bool {
+        let kind = if self.has_selection() { EditKind::Other } else { EditKind::Backspace };
+        let start = self.caret.saturating_sub(1);
+        self.edit(kind, |editor| editor.delete_selection_or(start..editor.caret))
+    }
+
+    /// 선택 영역 또는 캐럿 뒤의 한 글자를 지웁니다.
+    pub fn delete(&mut self) -> bool {
+        let kind = if self.has_selection() { EditKind::Other } else { EditKind::Delete };
+        let end = (self.caret + 1).min(self.char_count());
+        self.edit(kind, |editor| editor.delete_selection_or(editor.caret..end))
+    }
+
+    /// 선택 영역 또는 캐럿 앞의 한 단어를 지웁니다.
+    pub fn backspace_word(&mut self) -> bool {
+        let start = self.previous_word_boundary(self.caret);
+        self.edit(EditKind::Other, |editor| editor.delete_selection_or(start..editor.caret))
+    }
+
+    /// 선택 영역 또는 캐럿 뒤의 한 단어를 지웁니다.
+    pub fn delete_word(&mut self) -> bool {
+        let end = self.next_word_boundary(self.caret);
+        self.edit(EditKind::Other, |editor| editor.delete_selection_or(editor.caret..end))
+    }
+
+    /// 선택된 텍스트를 지웁니다. 지운 것이 있으면 true를 반환합니다.
+    pub fn delete_selection(&mut self) -> bool {
+        self.edit(EditKind::Other, |editor| editor.remove_selection())
+    }
+
+    // 선택 영역이 있으면 지우고, 없으면 주어진 범위를 지웁니다.
+    fn delete_selection_or(&mut self, range: Range<usize>) -> bool {
+        if self.remove_selection() {
+            return true;
+        }
+        if range.is_empty() {
+            return false;
+        }
+        self.delete_range(range);
+        true
+    }
+
+    fn remove_selection(&mut self) -> bool {
+        match self.selection() {
+            Some(range) => {
+                self.delete_range(range);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// 마지막 편집을 되돌립니다. 되돌린 것이 있으면 true를 반환합니다.
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.redo_stack.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 되돌린 편집을 다시 실행합니다.
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.undo_stack.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_edit = None;
+    }
+
+    // 편집을 실행하고, 텍스트가 바뀌었으면 편집 전 상태를 실행 취소 기록에 남깁니다.
+    // 같은 종류의 편집이 이전 편집 위치에서 이어지면 기록을 새로 만들지 않습니다.
+    fn edit<F>(&mut self, kind: EditKind, apply: F) -> bool where F: FnOnce(&mut Self) -> bool {
+        let before = self.snapshot();
+        if !apply(self) {
+            return false;
+        }
+
+        let continues = kind != EditKind::Other && self.last_edit == Some((kind, before.caret));
+        if !continues {
+            self.undo_stack.push(before);
+            if self.undo_stack.len() > MAX_HISTORY {
+                self.undo_stack.remove(0);
+            }
+        }
+        self.redo_stack.clear();
+        self.last_edit = Some((kind, self.caret));
+        true
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot { text: self.text.clone(), caret: self.caret, anchor: self.anchor }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.text = snapshot.text;
+        self.caret = snapshot.caret;
+        self.anchor = snapshot.anchor;
+        self.last_edit = None;
+    }
+
+    /// `index`가 속한 줄('\n'으로 나뉜 줄)의 시작 문자 인덱스
+    pub fn line_start(&self, index: usize) -> usize {
+        let before = self.text.chars().take(index);
+        before
+            .enumerate()
+            .filter(|&(_, c)| c == '\n')
+            .map(|(i, _)| i + 1)
+            .last()
+            .unwrap_or(0)
+    }
+
+    /// `index`가 속한 줄의 끝 문자 인덱스 ('\n' 앞)
+    pub fn line_end(&self, index: usize) -> usize {
+        self.text
+            .chars()
+            .enumerate()
+            .skip(index)
+            .find(|&(_, c)| c == '\n')
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| self.char_count())
+    }
+
+    /// 문자 인덱스의 (줄, 열) 위치. 둘 다 0부터 시작합니다.
+    pub fn line_column(&self, index: usize) -> (usize, usize) {
+        let line = self.text
+            .chars()
+            .take(index)
+            .filter(|&c| c == '\n')
+            .count();
+        (line, index.min(self.char_count()) - self.line_start(index))
+    }
+
+    /// 줄 수 (빈 텍스트도 한 줄)
+    pub fn line_count(&self) -> usize {
+        self.text.matches('\n').count() + 1
+    }
+
+    /// `index` 앞쪽의 단어 시작 위치 (공백을 건너뛴 뒤 같은 종류의 문자가 끝나는 곳)
+    pub fn previous_word_boundary(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut index = index.min(chars.len());
+
+        while index > 0 && chars[index - 1].is_whitespace() {
+            index -= 1;
+        }
+        if index > 0 {
+            let class = char_class(chars[index - 1]);
+            while index > 0 && char_class(chars[index - 1]) == class {
+                index -= 1;
+            }
+        }
+        index
+    }
+
+    /// `index` 뒤쪽의 단어 끝 위치 (같은 종류의 문자를 지나 뒤따르는 공백까지)
+    pub fn next_word_boundary(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut index = index.min(chars.len());
+
+        if index < chars.len() && !chars[index].is_whitespace() {
+            let class = char_class(chars[index]);
+            while index < chars.len() && char_class(chars[index]) == class {
+                index += 1;
+            }
+        }
+        while index < chars.len() && chars[index].is_whitespace() {
+            index += 1;
+        }
+        index
+    }
+
+    /// `index`를 포함하는 단어의 문자 범위 (더블 클릭 선택 등)
+    pub fn word_at(&self, index: usize) -> Range<usize> {
+        let chars: Vec<char> = self.text.chars().collect();
+        if chars.is_empty() {
+            return 0..0;
+        }
+
+        let index = index.min(chars.len() - 1);
+        let class = char_class(chars[index]);
+        let mut start = index;
+        let mut end = index;
+        while start > 0 && char_class(chars[start - 1]) == class {
+            start -= 1;
+        }
+        while end < chars.len() && char_class(chars[end]) == class {
+            end += 1;
+        }
+        start..end
+    }
+
+    /// 문자 인덱스를 바이트 인덱스로 변환합니다.
+    pub fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map(|(byte, _)| byte)
+            .unwrap_or(self.text.len())
+    }
+
+    fn byte_range(&self, range: Range<usize>) -> Range<usize> {
+        self.byte_index(range.start)..self.byte_index(range.end)
+    }
+
+    fn delete_range(&mut self, range: Range<usize>) {
+        let bytes = self.byte_range(range.clone());
+        self.text.replace_range(bytes, "");
+        self.move_to(range.start, false);
+    }
+}
+
+// 단어 경계를 나눌 때 사용하는 문자 종류
+#[derive(PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+fn char_class(c: char) -> CharClass {
+    if c.is_whitespace() {
+        CharClass::Whitespace
+    } else if c.is_alphanumeric() || c == '_' {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
@@ -1,49 +1,427 @@
 use rusttype::{ Font, Scale, point, PositionedGlyph };
+use std::collections::HashMap;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::sync::{ Arc, Mutex, OnceLock };
+use super::font_registry::FontWeight;
+use super::metrics::TextMetrics;
+use super::variation::{ FontVariation, VariationAxis };
 
+/// 위젯의 글꼴 크기에 곱해 실제 글리프 픽셀 높이를 구하는 계수.
+/// 기존 위젯 크기와 호환되도록 글꼴 크기의 3/4을 줄 높이(ascent - descent) 픽셀로 사용합니다.
+pub const FONT_SCALE_FACTOR: f32 = 0.75;
+
+// 글리프 캐시 키에 사용할 폰트 고유 ID 발급기
+static NEXT_FONT_ID: AtomicUsize = AtomicUsize::new(0);
+
+// 같은 글꼴 데이터와 축 값의 인스턴스가 같은 ID(같은 글리프 캐시)를 쓰도록 기억해 둡니다.
+// 키는 (글꼴 데이터 주소, 축 태그와 값의 비트 패턴)
+type InstanceKey = (usize, Vec<([u8; 4], u32)>);
+static INSTANCE_IDS: OnceLock<Mutex<HashMap<InstanceKey, usize>>> = OnceLock::new();
+
+fn instance_id(data: &'static [u8], variations: &[FontVariation]) -> usize {
+    let key = (
+        data.as_ptr() as usize,
+        variations
+            .iter()
+            .map(|v| (v.axis, v.value.to_bits()))
+            .collect(),
+    );
+    let mut ids = INSTANCE_IDS.get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    *ids.entry(key).or_insert_with(|| NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// 배치된 글리프와 그 글리프를 가진 글꼴의 ID (대체 글꼴에서 온 글리프를 구분)
+pub struct FontGlyph {
+    pub font_id: usize,
+    pub glyph: PositionedGlyph<'static>,
+    /// 글리프가 나타내는 첫 문자의 인덱스. 합자는 여러 문자가 한 글리프를, 결합 문자는 여러 글리프가 한 문자를 가리킵니다.
+    pub cluster: usize,
+    /// 글리프의 펜 위치 (셰이핑 오프셋 제외)
+    pub x: f32,
+    pub advance: f32,
+    /// 오른쪽에서 왼쪽으로 쓰는 구간(아랍어, 히브리어 등)의 글리프인지 여부
+    pub rtl: bool,
+}
+
+/// 글꼴 하나와 대체 글꼴 체인.
+///
+/// 첫 번째 글꼴에 없는 문자는 `fallbacks`에서 순서대로 찾아 그리며, 기준선과 줄 높이는 첫 번째 글꼴을 따릅니다.
+/// 복제는 글꼴 데이터를 공유하므로 위젯마다 하나씩 가지고 있어도 됩니다.
+#[derive(Clone)]
 pub struct FontRenderer {
+    id: usize,
+    data: &'static [u8],
     font: Font<'static>,
+    #[cfg(feature = "shaping")]
+    face: Arc<rustybuzz::Face<'static>>,
+    variations: Vec<FontVariation>,
+    fallbacks: Vec<FontRenderer>,
 }
 
 impl FontRenderer {
     pub fn new(font_data: Vec<u8>) -> Self {
+        Self::try_new(font_data).expect("Error constructing Font")
+    }
+
+    /// 글꼴 데이터를 읽지 못하면 `None`을 반환합니다.
+    pub fn try_new(font_data: Vec<u8>) -> Option<Self> {
         let font_data = font_data.into_boxed_slice();
         let font_data: &'static [u8] = Box::leak(font_data);
-        let font = Font::try_from_bytes(font_data).expect("Error constructing Font");
+        let font = Font::try_from_bytes(font_data)?;
+
+        Some(Self {
+            id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
+            data: font_data,
+            font,
+            #[cfg(feature = "shaping")]
+            face: Arc::new(rustybuzz::Face::from_slice(font_data, 0)?),
+            variations: Vec::new(),
+            fallbacks: Vec::new(),
+        })
+    }
+
+    /// 가변 글꼴(축이 하나 이상 있는 글꼴)인지 여부
+    pub fn is_variable(&self) -> bool {
+        !self.variation_axes().is_empty()
+    }
+
+    /// 글꼴이 제공하는 가변 축 목록 (가변 글꼴이 아니면 비어 있음)
+    pub fn variation_axes(&self) -> Vec<VariationAxis> {
+        let Ok(face) = owned_ttf_parser::Face::from_slice(self.data, 0) else {
+            return Vec::new();
+        };
+        face.variation_axes()
+            .into_iter()
+            .map(|axis| VariationAxis {
+                axis: axis.tag.to_bytes(),
+                min_value: axis.min_value,
+                default_value: axis.def_value,
+                max_value: axis.max_value,
+                hidden: axis.hidden,
+            })
+            .collect()
+    }
+
+    pub fn axis(&self, axis: [u8; 4]) -> Option<VariationAxis> {
+        self.variation_axes()
+            .into_iter()
+            .find(|a| a.axis == axis)
+    }
+
+    /// 현재 인스턴스에 적용된 축 값
+    pub fn variations(&self) -> &[FontVariation] {
+        &self.variations
+    }
+
+    /// 축 값을 바꾼 인스턴스를 만듭니다. 기존 축 값에 덮어쓰며, 글꼴에 없는 축은 무시하고
+    /// 값은 축 범위로 제한합니다. 대체 글꼴에도 같은 값을 적용합니다 (해당 축이 있는 경우).
+    ///
+    /// 같은 글꼴과 같은 값의 인스턴스는 같은 ID를 가지므로 글리프 아틀라스를 공유합니다.
+    pub fn with_variations(&self, variations: &[FontVariation]) -> FontRenderer {
+        let axes = self.variation_axes();
+        let mut merged = self.variations.clone();
+        for variation in variations {
+            let Some(axis) = axes.iter().find(|a| a.axis == variation.axis) else {
+                continue;
+            };
+            let value = axis.clamp(variation.value);
+            match merged.iter_mut().find(|v| v.axis == variation.axis) {
+                Some(existing) => existing.value = value,
+                None => merged.push(FontVariation { axis: variation.axis, value }),
+            }
+        }
+
+        // 기본값과 같은 축은 지정하지 않은 것과 같습니다.
+        merged.retain(|v| axes.iter().any(|a| a.axis == v.axis && a.default_value != v.value));
+
+        let mut instance = self.clone();
+        instance.fallbacks = self.fallbacks
+            .iter()
+            .map(|font| font.with_variations(variations))
+            .collect();
+        if merged == self.variations {
+            return instance;
+        }
+
+        let Ok(mut face) = owned_ttf_parser::Face::from_slice(self.data, 0) else {
+            return instance;
+        };
+        for variation in &merged {
+            face.set_variation(owned_ttf_parser::Tag::from_bytes(&variation.axis), variation.value);
+        }
+        instance.font = Font::Ref(Arc::new(face));
+
+        #[cfg(feature = "shaping")]
+        if let Some(mut face) = rustybuzz::Face::from_slice(self.data, 0) {
+            let variations: Vec<rustybuzz::Variation> = merged
+                .iter()
+                .map(|v| rustybuzz::Variation {
+                    tag: rustybuzz::Tag::from_bytes(&v.axis),
+                    value: v.value,
+                })
+                .collect();
+            face.set_variations(&variations);
+            instance.face = Arc::new(face);
+        }
+
+        instance.id = instance_id(self.data, &merged);
+        instance.variations = merged;
+        instance
+    }
+
+    /// 축 값을 지정하지 않았을 때의 굵기 (가변 글꼴은 `wght` 축 기본값, 아니면 `FontWeight::NORMAL`)
+    pub fn default_weight(&self) -> FontWeight {
+        self.axis(FontVariation::WEIGHT)
+            .map(|axis| FontWeight(axis.default_value.round() as u16))
+            .unwrap_or(FontWeight::NORMAL)
+    }
+
+    /// `wght` 축을 `weight`로 맞춘 인스턴스 (가변 글꼴이 아니면 그대로)
+    pub fn with_weight(&self, weight: FontWeight) -> FontRenderer {
+        self.with_variations(&[FontVariation::weight(weight.0 as f32)])
+    }
+
+    /// 대체 글꼴 체인을 바꾼 복사본 (대체 글꼴 자신의 체인은 사용하지 않습니다)
+    pub fn with_fallbacks(mut self, fallbacks: Vec<FontRenderer>) -> Self {
+        self.fallbacks = fallbacks
+            .into_iter()
+            .map(|mut font| {
+                font.fallbacks.clear();
+                font
+            })
+            .collect();
+        self
+    }
+
+    pub fn fallbacks(&self) -> &[FontRenderer] {
+        &self.fallbacks
+    }
+
+    /// 이 글꼴(대체 글꼴 제외)에 문자의 글리프가 있는지 여부
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.font.glyph(c).id().0 != 0
+    }
 
-        Self { font }
+    // 문자를 그릴 글꼴 (체인에 아무 글꼴도 없으면 첫 번째 글꼴의 빈 글리프)
+    pub(crate) fn font_for(&self, c: char) -> &FontRenderer {
+        if c.is_control() || self.has_glyph(c) {
+            return self;
+        }
+        self.fallbacks
+            .iter()
+            .find(|font| font.has_glyph(c))
+            .unwrap_or(self)
+    }
+
+    /// 글리프 아틀라스에서 이 폰트를 구분하는 고유 ID
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn font(&self) -> &Font<'static> {
+        &self.font
+    }
+
+    #[cfg(feature = "shaping")]
+    pub(crate) fn face(&self) -> &rustybuzz::Face<'static> {
+        &self.face
+    }
+
+    /// 글꼴 크기(`scale` 인자)를 rusttype 픽셀 크기로 변환한 값.
+    /// 모든 배치와 측정은 `font_size * FONT_SCALE_FACTOR` 픽셀 높이의 글꼴을 사용합니다.
+    pub fn pixel_scale(&self, scale: f32) -> Scale {
+        Scale::uniform(scale * FONT_SCALE_FACTOR)
     }
 
     pub fn render_text(&self, text: &str, scale: f32) -> Vec<PositionedGlyph<'static>> {
-        let scale = Scale::uniform(scale * 0.75);
-        let v_metrics = self.font.v_metrics(scale);
-        let offset = point(0.0, v_metrics.ascent);
+        self.layout_glyphs(text, scale)
+            .into_iter()
+            .map(|g| g.glyph)
+            .collect()
+    }
 
-        self.font.layout(text, scale, offset).collect()
+    /// 한 줄의 글리프를 화면 순서로 배치합니다. y는 첫 번째 글꼴의 기준선(ascent)입니다.
+    ///
+    /// `shaping` 기능을 켜면 rustybuzz로 셰이핑(합자, 한글 자모 조합, 아랍어 연결)하고
+    /// 양방향 텍스트를 재배열합니다. 끄면 `layout_unshaped`와 같습니다.
+    pub fn layout_glyphs(&self, text: &str, scale: f32) -> Vec<FontGlyph> {
+        #[cfg(feature = "shaping")]
+        {
+            super::shaping::shape_line(self, text, scale)
+        }
+        #[cfg(not(feature = "shaping"))]
+        {
+            self.layout_unshaped(text, scale)
+        }
     }
 
-    pub fn calculate_text_size(&self, text: &str, scale: f32) -> (f32, f32) {
-        let scale = Scale::uniform(scale * 0.75);
-        let v_metrics = self.font.v_metrics(scale);
-        let glyphs: Vec<_> = self.font.layout(text, scale, point(0.0, v_metrics.ascent)).collect();
+    /// 셰이핑 없이 문자마다 글리프 하나를 왼쪽에서 오른쪽으로 배치합니다. 글리프가 없는 문자는
+    /// 대체 글꼴에서 가져오며, 커닝은 같은 글꼴의 글리프끼리만 적용합니다.
+    pub fn layout_unshaped(&self, text: &str, scale: f32) -> Vec<FontGlyph> {
+        let scale = self.pixel_scale(scale);
+        let baseline = self.font.v_metrics(scale).ascent;
+
+        let mut glyphs = Vec::with_capacity(text.len());
+        let mut x = 0.0;
+        let mut previous: Option<(usize, rusttype::GlyphId)> = None;
+        for (index, c) in text.chars().enumerate() {
+            let source = self.font_for(c);
+            let glyph = source.font.glyph(c).scaled(scale);
+
+            if let Some((font_id, previous_id)) = previous {
+                if font_id == source.id {
+                    x += source.font.pair_kerning(scale, previous_id, glyph.id());
+                }
+            }
+            previous = Some((source.id, glyph.id()));
+
+            let advance = glyph.h_metrics().advance_width;
+            glyphs.push(FontGlyph {
+                font_id: source.id,
+                glyph: glyph.positioned(point(x, baseline)),
+                cluster: index,
+                x,
+                advance,
+                rtl: false,
+            });
+            x += advance;
+        }
+        glyphs
+    }
+
+    /// 문자열을 한 줄로 배치했을 때의 너비, 세로 메트릭, 글리프/캐럿 위치를 계산합니다.
+    ///
+    /// 캐럿 위치는 문자(논리 순서)마다 계산합니다. 합자처럼 여러 문자가 한 글리프인 클러스터는 너비를
+    /// 문자 수로 나누고, 오른쪽에서 왼쪽으로 쓰는 문자는 오른쪽 끝을 캐럿 위치로 사용합니다.
+    pub fn measure(&self, text: &str, scale: f32) -> TextMetrics {
+        let v_metrics = self.font.v_metrics(self.pixel_scale(scale));
+        let glyphs = self.layout_glyphs(text, scale);
+        let char_count = text.chars().count();
+
+        // 클러스터(첫 문자 인덱스)별 가로 범위와 방향
+        let mut clusters: Vec<Option<(f32, f32, bool)>> = vec![None; char_count];
+        for g in glyphs.iter().filter(|g| g.cluster < char_count) {
+            let extent = clusters[g.cluster].get_or_insert((g.x, g.x + g.advance, g.rtl));
+            extent.0 = extent.0.min(g.x);
+            extent.1 = extent.1.max(g.x + g.advance);
+        }
+
+        let mut glyph_offsets = Vec::with_capacity(char_count);
+        let mut caret_positions = Vec::with_capacity(char_count + 1);
+        let mut end = 0.0;
+        let mut index = 0;
+        while index < char_count {
+            let (left, right, rtl) = clusters[index].unwrap_or((end, end, false));
+            let count = (index + 1..char_count).find(|&i| clusters[i].is_some()).unwrap_or(char_count) - index;
+            let step = (right - left) / (count as f32);
+            for k in 0..count {
+                let k = k as f32;
+                if rtl {
+                    glyph_offsets.push(right - (k + 1.0) * step);
+                    caret_positions.push(right - k * step);
+                } else {
+                    glyph_offsets.push(left + k * step);
+                    caret_positions.push(left + k * step);
+                }
+            }
+            end = if rtl { left } else { right };
+            index += count;
+        }
+        caret_positions.push(end);
+
+        let advance_width = glyphs
+            .iter()
+            .map(|g| g.x + g.advance)
+            .fold(0.0, f32::max);
+
+        let ink_bounds = glyphs
+            .iter()
+            .filter_map(|g| g.glyph.pixel_bounding_box())
+            .fold(None, |bounds: Option<(f32, f32)>, bb| {
+                let (min_x, max_x) = (bb.min.x as f32, bb.max.x as f32);
+                Some(match bounds {
+                    Some((lo, hi)) => (lo.min(min_x), hi.max(max_x)),
+                    None => (min_x, max_x),
+                })
+            });
 
-        if glyphs.is_empty() {
-            return (0.0, 0.0);
+        TextMetrics {
+            advance_width,
+            ascent: v_metrics.ascent,
+            descent: v_metrics.descent,
+            line_gap: v_metrics.line_gap,
+            glyph_offsets,
+            caret_positions,
+            ink_bounds,
         }
+    }
+
+    /// 텍스트의 (너비, 높이). 너비는 끝 공백을 포함한 advance 너비, 높이는 ascent - descent입니다.
+    pub fn calculate_text_size(&self, text: &str, scale: f32) -> (f32, f32) {
+        self.measure(text, scale).size()
+    }
+
+    /// 한 줄의 높이 (ascent - descent)
+    pub fn line_height(&self, scale: f32) -> f32 {
+        let v_metrics = self.font.v_metrics(self.pixel_scale(scale));
+        v_metrics.ascent - v_metrics.descent
+    }
+
+    /// 문자 경계마다의 캐럿 x 위치 (`render_text`의 펜 위치 기준).
+    /// 길이는 문자 수 + 1이며, i번째 값은 i번째 문자 앞의 위치, 마지막 값은 텍스트 끝입니다.
+    pub fn caret_positions(&self, text: &str, scale: f32) -> Vec<f32> {
+        self.measure(text, scale).caret_positions
+    }
+
+    /// x 위치에 가장 가까운 문자 경계의 인덱스 (마우스로 캐럿을 놓을 때 사용)
+    pub fn index_at(&self, text: &str, scale: f32, x: f32) -> usize {
+        self.measure(text, scale).hit_test(x)
+    }
+}
 
-        let min_x = glyphs
-            .first()
-            .and_then(|g| g.pixel_bounding_box())
-            .map(|bb| bb.min.x as f32)
-            .unwrap_or(0.0);
-        let max_x = glyphs
-            .last()
-            .and_then(|g| g.pixel_bounding_box())
-            .map(|bb| bb.max.x as f32)
-            .unwrap_or(0.0);
+/// 캐럿 위치 목록에서 x에 가장 가까운 인덱스
+/// (양방향 텍스트에서는 위치가 정렬되어 있지 않으므로 거리로 비교합니다)
+pub fn nearest_index(positions: &[f32], x: f32) -> usize {
+    positions
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
 
-        let width = max_x - min_x;
-        let height = v_metrics.ascent - v_metrics.descent;
+/// 캐럿 위치 목록이 차지하는 가로 범위 (가장 왼쪽 x, 가장 오른쪽 x).
+/// 오른쪽에서 왼쪽으로 쓰는 문자가 있으면 첫 값과 마지막 값이 양 끝이 아닐 수 있습니다.
+pub fn caret_extent(positions: &[f32]) -> (f32, f32) {
+    positions
+        .iter()
+        .fold(None, |extent: Option<(f32, f32)>, &x| {
+            Some(match extent {
+                Some((left, right)) => (left.min(x), right.max(x)),
+                None => (x, x),
+            })
+        })
+        .unwrap_or((0.0, 0.0))
+}
 
-        (width, height.abs())
+/// 문자 범위 `start..end`가 화면에서 차지하는 가로 구간 (왼쪽 x, 오른쪽 x) 목록.
+/// 양방향 텍스트에서는 논리 순서로 이어진 범위가 떨어진 여러 구간으로 보일 수 있어
+/// 문자마다 구간을 구한 뒤 맞닿은 구간끼리 합칩니다.
+pub fn selection_spans(positions: &[f32], start: usize, end: usize) -> Vec<(f32, f32)> {
+    let end = end.min(positions.len().saturating_sub(1));
+    let mut spans: Vec<(f32, f32)> = (start..end)
+        .map(|i| (positions[i].min(positions[i + 1]), positions[i].max(positions[i + 1])))
+        .collect();
+    spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<(f32, f32)> = Vec::with_capacity(spans.len());
+    for (left, right) in spans {
+        match merged.last_mut() {
+            Some(last) if left <= last.1 + 0.5 => last.1 = last.1.max(right),
+            _ => merged.push((left, right)),
+        }
     }
+    merged
 }
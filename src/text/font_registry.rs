@@ -0,0 +1,236 @@
+use super::font::FontRenderer;
+use super::variation::FontVariation;
+use log::debug;
+
+/// 기본 글꼴(`Renderer::new`에 넘긴 글꼴)이 등록되는 글꼴 패밀리 이름
+pub const DEFAULT_FONT_FAMILY: &str = "default";
+
+/// 글꼴 굵기 (CSS와 같은 100 ~ 900 값)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FontWeight(pub u16);
+
+impl FontWeight {
+    pub const THIN: FontWeight = FontWeight(100);
+    pub const LIGHT: FontWeight = FontWeight(300);
+    pub const NORMAL: FontWeight = FontWeight(400);
+    pub const MEDIUM: FontWeight = FontWeight(500);
+    pub const SEMI_BOLD: FontWeight = FontWeight(600);
+    pub const BOLD: FontWeight = FontWeight(700);
+    pub const BLACK: FontWeight = FontWeight(900);
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        FontWeight::NORMAL
+    }
+}
+
+/// 글꼴 스타일
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// 위젯이 사용할 글꼴을 패밀리 이름, 굵기, 스타일로 지정합니다.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontQuery {
+    pub family: String,
+    pub weight: FontWeight,
+    pub style: FontStyle,
+}
+
+impl FontQuery {
+    pub fn new(family: &str) -> Self {
+        Self {
+            family: family.to_string(),
+            weight: FontWeight::NORMAL,
+            style: FontStyle::Normal,
+        }
+    }
+
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_style(mut self, style: FontStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Default for FontQuery {
+    fn default() -> Self {
+        Self::new(DEFAULT_FONT_FAMILY)
+    }
+}
+
+// 등록된 글꼴 파일 하나
+struct FontFace {
+    family: String,
+    weight: FontWeight,
+    style: FontStyle,
+    font: FontRenderer,
+}
+
+/// 패밀리 이름, 굵기, 스타일로 글꼴을 등록하고 찾는 저장소.
+///
+/// 가변 글꼴은 기본 굵기(보통 `FontWeight::NORMAL`)로 한 번만 등록하면 다른 굵기와 기울임 요청을
+/// `wght`, `ital`, `slnt` 축 값으로 맞춰 줍니다.
+///
+/// `resolve`는 요청한 패밀리에서 가장 가까운 글꼴을 고른 뒤, 대체 글꼴 체인(`set_fallback_chain`)의
+/// 각 패밀리에서도 같은 굵기/스타일에 가까운 글꼴을 골라 붙입니다. 결과 `FontRenderer`는 글리프마다
+/// 첫 번째 글꼴에 없는 문자를 체인 순서대로 찾아 그립니다 (예: 영문 글꼴 + 한글 글꼴).
+pub struct FontRegistry {
+    faces: Vec<FontFace>,
+    fallback_chain: Vec<String>,
+}
+
+impl FontRegistry {
+    pub fn new() -> Self {
+        Self {
+            faces: Vec::new(),
+            fallback_chain: Vec::new(),
+        }
+    }
+
+    /// 글꼴 파일(TTF/OTF)을 등록합니다. 같은 패밀리/굵기/스타일이 이미 있으면 교체합니다.
+    pub fn register(
+        &mut self,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+        font_data: Vec<u8>
+    ) -> Result<(), String> {
+        let font = FontRenderer::try_new(font_data).ok_or_else(||
+            format!("Failed to parse font data for family '{}'", family)
+        )?;
+        self.register_font(family, weight, style, font);
+        Ok(())
+    }
+
+    /// 이미 만든 `FontRenderer`를 등록합니다.
+    pub fn register_font(
+        &mut self,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+        font: FontRenderer
+    ) {
+        debug!("Registering font: {} {:?} {:?}", family, weight, style);
+        self.faces.retain(|face| {
+            !(face.family == family && face.weight == weight && face.style == style)
+        });
+        self.faces.push(FontFace {
+            family: family.to_string(),
+            weight,
+            style,
+            font: font.with_fallbacks(Vec::new()),
+        });
+    }
+
+    /// 등록된 패밀리 이름 (등록 순서, 중복 없음)
+    pub fn families(&self) -> Vec<&str> {
+        let mut families: Vec<&str> = Vec::new();
+        for face in &self.faces {
+            if !families.contains(&face.family.as_str()) {
+                families.push(&face.family);
+            }
+        }
+        families
+    }
+
+    pub fn has_family(&self, family: &str) -> bool {
+        self.faces.iter().any(|face| face.family == family)
+    }
+
+    pub fn fallback_chain(&self) -> &[String] {
+        &self.fallback_chain
+    }
+
+    /// 글리프가 없을 때 차례로 찾아볼 패밀리 목록
+    pub fn set_fallback_chain(&mut self, families: &[&str]) {
+        self.fallback_chain = families
+            .iter()
+            .map(|family| family.to_string())
+            .collect();
+    }
+
+    /// 패밀리 안에서 요청에 가장 가까운 글꼴 (대체 글꼴 없음)
+    pub fn find(&self, query: &FontQuery) -> Option<FontRenderer> {
+        self.best_match(&query.family, query.weight, query.style)
+    }
+
+    /// 요청한 글꼴에 대체 글꼴 체인을 붙여 반환합니다.
+    /// 패밀리가 없으면 기본 패밀리를, 그것도 없으면 체인의 첫 글꼴을 기본으로 사용합니다.
+    pub fn resolve(&self, query: &FontQuery) -> Option<FontRenderer> {
+        let mut chain = vec![query.family.as_str(), DEFAULT_FONT_FAMILY];
+        chain.extend(self.fallback_chain.iter().map(String::as_str));
+
+        let mut fonts: Vec<FontRenderer> = Vec::new();
+        for family in chain {
+            if let Some(font) = self.best_match(family, query.weight, query.style) {
+                if !fonts.iter().any(|f| f.id() == font.id()) {
+                    fonts.push(font);
+                }
+            }
+        }
+
+        if fonts.is_empty() {
+            return None;
+        }
+        let primary = fonts.remove(0);
+        Some(primary.with_fallbacks(fonts))
+    }
+
+    // CSS 글꼴 매칭을 단순화한 규칙: 스타일이 같은 글꼴을 우선하고, 그중 굵기 차이가 가장 작은 글꼴
+    // (차이가 같으면 요청이 500 이하면 가는 쪽, 초과면 굵은 쪽).
+    // 고른 글꼴이 가변 글꼴이면 굵기와 스타일을 축 값으로 맞춘 인스턴스를 반환합니다.
+    fn best_match(&self, family: &str, weight: FontWeight, style: FontStyle) -> Option<FontRenderer> {
+        let face = self.faces
+            .iter()
+            .filter(|face| face.family == family)
+            .min_by_key(|face| {
+                let style_penalty = match (style, face.style) {
+                    (a, b) if a == b => 0,
+                    (FontStyle::Italic, FontStyle::Oblique) | (FontStyle::Oblique, FontStyle::Italic) => 1,
+                    _ => 2,
+                };
+                let distance = (face.weight.0 as i32 - weight.0 as i32).unsigned_abs();
+                let prefers_lighter = weight.0 <= 500;
+                let wrong_direction = if prefers_lighter {
+                    face.weight > weight
+                } else {
+                    face.weight < weight
+                };
+                (style_penalty, distance, wrong_direction)
+            })?;
+
+        let mut variations = Vec::new();
+        if face.weight != weight {
+            variations.push(FontVariation::weight(weight.0 as f32));
+        }
+        if face.style == FontStyle::Normal && style != FontStyle::Normal {
+            // 이탤릭 축이 없으면 기울기 축을 가장 많이 기울인 값으로 사용합니다.
+            if face.font.axis(FontVariation::ITALIC).is_some() {
+                variations.push(FontVariation::italic(1.0));
+            } else if let Some(slant) = face.font.axis(FontVariation::SLANT) {
+                variations.push(FontVariation::slant(slant.min_value));
+            }
+        }
+        if variations.is_empty() {
+            Some(face.font.clone())
+        } else {
+            Some(face.font.with_variations(&variations))
+        }
+    }
+}
+
+impl Default for FontRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,348 @@
+use crate::graphics::Texture;
+use log::{ debug, warn };
+use std::collections::HashMap;
+
+/// 아틀라스 페이지 한 장의 크기 (픽셀, 정사각형)
+pub const ATLAS_PAGE_SIZE: i32 = 1024;
+
+/// 가로 방향 서브픽셀 위치를 몇 단계로 양자화할지 결정합니다.
+pub const SUBPIXEL_STEPS: u8 = 4;
+
+// 인접 글리프끼리 선형 필터링으로 번지지 않도록 두는 여백
+const GLYPH_PADDING: i32 = 1;
+
+/// 아틀라스에 캐시된 글리프를 식별하는 키
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    pub font_id: usize,
+    pub glyph_id: u16,
+    /// 픽셀 스케일(`f32::to_bits`)
+    pub size: u32,
+    /// 0..SUBPIXEL_STEPS 범위의 가로 서브픽셀 오프셋
+    pub subpixel: u8,
+}
+
+/// 래스터화된 단일 채널(커버리지) 글리프 비트맵
+pub struct RasterizedGlyph {
+    pub width: i32,
+    pub height: i32,
+    /// 글리프 원점(펜 위치, 베이스라인)에서 비트맵 좌상단까지의 오프셋
+    pub offset_x: i32,
+    pub offset_y: i32,
+    pub pixels: Vec<u8>,
+}
+
+/// 아틀라스 안에서 글리프가 차지하는 위치 정보
+#[derive(Clone, Copy, Debug)]
+pub struct GlyphEntry {
+    pub page: usize,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub offset_x: i32,
+    pub offset_y: i32,
+    shelf: usize,
+    last_used: u64,
+}
+
+impl GlyphEntry {
+    /// 공백처럼 그릴 픽셀이 없는 글리프인지 여부
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// 아틀라스 텍스처 기준 정규화된 UV 좌표 (u0, v0, u1, v1)
+    pub fn uv_rect(&self) -> [f32; 4] {
+        let size = ATLAS_PAGE_SIZE as f32;
+        [
+            (self.x as f32) / size,
+            (self.y as f32) / size,
+            ((self.x + self.width) as f32) / size,
+            ((self.y + self.height) as f32) / size,
+        ]
+    }
+}
+
+// 높이가 같은 글리프들을 가로로 채워 넣는 한 줄(shelf)
+struct Shelf {
+    y: i32,
+    height: i32,
+    cursor_x: i32,
+    free_slots: Vec<(i32, i32)>, // 제거된 글리프가 남긴 (x, width) 빈 칸
+    glyph_count: usize,
+}
+
+impl Shelf {
+    fn allocate(&mut self, width: i32) -> Option<i32> {
+        if let Some(index) = self.free_slots.iter().position(|&(_, w)| w >= width) {
+            let (x, w) = self.free_slots[index];
+            if w > width {
+                self.free_slots[index] = (x + width, w - width);
+            } else {
+                self.free_slots.swap_remove(index);
+            }
+            self.glyph_count += 1;
+            return Some(x);
+        }
+
+        if self.cursor_x + width <= ATLAS_PAGE_SIZE {
+            let x = self.cursor_x;
+            self.cursor_x += width;
+            self.glyph_count += 1;
+            return Some(x);
+        }
+
+        None
+    }
+
+    fn release(&mut self, x: i32, width: i32) {
+        self.glyph_count -= 1;
+        if self.glyph_count == 0 {
+            // 비어 있는 줄은 처음 상태로 되돌려 조각난 빈 칸을 한 번에 회수합니다.
+            self.cursor_x = 0;
+            self.free_slots.clear();
+        } else if x + width == self.cursor_x {
+            self.cursor_x = x;
+        } else {
+            self.free_slots.push((x, width));
+        }
+    }
+}
+
+struct AtlasPage {
+    texture: Texture,
+    shelves: Vec<Shelf>,
+    next_y: i32,
+}
+
+impl AtlasPage {
+    fn new() -> Self {
+        let texture = Texture::new();
+        let empty = vec![0u8; (ATLAS_PAGE_SIZE * ATLAS_PAGE_SIZE) as usize];
+        texture.upload_data(ATLAS_PAGE_SIZE, ATLAS_PAGE_SIZE, &empty);
+
+        Self {
+            texture,
+            shelves: Vec::new(),
+            next_y: 0,
+        }
+    }
+}
+
+/// 래스터화된 글리프를 큰 텍스처 몇 장에 모아 두고 재사용하는 캐시.
+///
+/// 글리프는 (폰트, 글리프 ID, 크기, 서브픽셀 오프셋)으로 식별되며 shelf 방식으로 배치됩니다.
+/// 공간이 부족하면 현재 프레임에서 사용되지 않은 글리프부터 가장 오래된 순서(LRU)로 제거하고,
+/// 그래도 자리가 없으면 새 페이지를 추가합니다.
+pub struct GlyphAtlas {
+    pages: Vec<AtlasPage>,
+    entries: HashMap<GlyphKey, GlyphEntry>,
+    frame: u64,
+}
+
+impl GlyphAtlas {
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            entries: HashMap::new(),
+            frame: 0,
+        }
+    }
+
+    /// 새 프레임을 시작합니다. 현재 프레임에서 사용된 글리프는 제거 대상에서 제외됩니다.
+    pub fn begin_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// 페이지 인덱스에 해당하는 OpenGL 텍스처 ID
+    pub fn texture_id(&self, page: usize) -> u32 {
+        self.pages[page].texture.id()
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn glyph_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 모든 페이지와 캐시된 글리프를 비웁니다.
+    pub fn clear(&mut self) {
+        self.pages.clear();
+        self.entries.clear();
+    }
+
+    /// 캐시된 글리프를 반환하고, 없으면 `rasterize`로 비트맵을 만들어 아틀라스에 올립니다.
+    pub fn get_or_insert<F>(&mut self, key: GlyphKey, rasterize: F) -> GlyphEntry
+        where F: FnOnce() -> Option<RasterizedGlyph>
+    {
+        let frame = self.frame;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = frame;
+            return *entry;
+        }
+
+        let entry = match rasterize().and_then(|glyph| self.insert(glyph)) {
+            Some(entry) => entry,
+            None =>
+                GlyphEntry {
+                    page: 0,
+                    x: 0,
+                    y: 0,
+                    width: 0,
+                    height: 0,
+                    offset_x: 0,
+                    offset_y: 0,
+                    shelf: 0,
+                    last_used: frame,
+                }
+        };
+
+        self.entries.insert(key, entry);
+        entry
+    }
+
+    // 그릴 픽셀이 없거나 페이지보다 큰 글리프는 올리지 않고 None을 반환합니다 (빈 글리프로 캐시됨).
+    fn insert(&mut self, glyph: RasterizedGlyph) -> Option<GlyphEntry> {
+        if glyph.width <= 0 || glyph.height <= 0 {
+            return None;
+        }
+        let width = glyph.width + GLYPH_PADDING;
+        let height = glyph.height + GLYPH_PADDING;
+        if width > ATLAS_PAGE_SIZE || height > ATLAS_PAGE_SIZE {
+            warn!(
+                "Glyph {}x{} is larger than the atlas page ({}px), skipping",
+                glyph.width,
+                glyph.height,
+                ATLAS_PAGE_SIZE
+            );
+            return None;
+        }
+
+        // 제거된 글리프의 자리를 재사용할 수 있으므로 여백까지 함께 덮어써서 이전 픽셀이 번지지 않게 합니다.
+        let mut pixels = vec![0u8; (width * height) as usize];
+        for (row, source) in glyph.pixels.chunks_exact(glyph.width as usize).enumerate() {
+            let start = row * (width as usize);
+            pixels[start..start + source.len()].copy_from_slice(source);
+        }
+
+        let (page, shelf, x, y) = self.allocate(width, height);
+        self.pages[page].texture.upload_sub_data(x, y, width, height, &pixels);
+
+        Some(GlyphEntry {
+            page,
+            x,
+            y,
+            width: glyph.width,
+            height: glyph.height,
+            offset_x: glyph.offset_x,
+            offset_y: glyph.offset_y,
+            shelf,
+            last_used: self.frame,
+        })
+    }
+
+    fn allocate(&mut self, width: i32, height: i32) -> (usize, usize, i32, i32) {
+        if self.pages.is_empty() {
+            self.pages.push(AtlasPage::new());
+        }
+
+        // 1. 높이가 비슷한 기존 줄에 넣어 세로 공간 낭비를 줄입니다.
+        if let Some(slot) = self.allocate_in_shelves(width, height, true) {
+            return slot;
+        }
+
+        // 2. 남은 세로 공간에 새 줄을 엽니다.
+        if let Some(slot) = self.allocate_new_shelf(width, height) {
+            return slot;
+        }
+
+        // 3. 높이가 넉넉한 아무 줄에나 넣습니다.
+        if let Some(slot) = self.allocate_in_shelves(width, height, false) {
+            return slot;
+        }
+
+        // 4. 이번 프레임에 쓰이지 않은 글리프를 오래된 순서대로 제거하며 자리를 찾습니다.
+        let mut stale: Vec<(GlyphKey, u64)> = self.entries
+            .iter()
+            .filter(|(_, entry)| entry.last_used < self.frame && !entry.is_empty())
+            .map(|(key, entry)| (*key, entry.last_used))
+            .collect();
+        stale.sort_by_key(|&(_, last_used)| last_used);
+
+        for (key, _) in stale {
+            self.evict(&key);
+            if let Some(slot) = self.allocate_in_shelves(width, height, false) {
+                return slot;
+            }
+        }
+
+        // 5. 모든 글리프가 사용 중이면 새 페이지를 추가합니다.
+        debug!("Glyph atlas full, adding page #{}", self.pages.len() + 1);
+        self.pages.push(AtlasPage::new());
+        self.allocate_new_shelf(width, height).expect("glyph fits in an empty atlas page")
+    }
+
+    fn allocate_in_shelves(
+        &mut self,
+        width: i32,
+        height: i32,
+        tight: bool
+    ) -> Option<(usize, usize, i32, i32)> {
+        for (page_index, page) in self.pages.iter_mut().enumerate() {
+            for (shelf_index, shelf) in page.shelves.iter_mut().enumerate() {
+                let fits = shelf.height >= height && (!tight || shelf.height <= height + height / 2);
+                if !fits {
+                    continue;
+                }
+                if let Some(x) = shelf.allocate(width) {
+                    return Some((page_index, shelf_index, x, shelf.y));
+                }
+            }
+        }
+        None
+    }
+
+    fn allocate_new_shelf(&mut self, width: i32, height: i32) -> Option<(usize, usize, i32, i32)> {
+        // 줄 높이를 4픽셀 단위로 올려 비슷한 크기의 글리프가 같은 줄을 공유하도록 합니다.
+        let shelf_height = (height + 3) & !3;
+
+        for (page_index, page) in self.pages.iter_mut().enumerate() {
+            if page.next_y + shelf_height > ATLAS_PAGE_SIZE {
+                continue;
+            }
+
+            let mut shelf = Shelf {
+                y: page.next_y,
+                height: shelf_height,
+                cursor_x: 0,
+                free_slots: Vec::new(),
+                glyph_count: 0,
+            };
+            let x = shelf.allocate(width)?;
+            let y = shelf.y;
+
+            page.next_y += shelf_height;
+            page.shelves.push(shelf);
+            return Some((page_index, page.shelves.len() - 1, x, y));
+        }
+        None
+    }
+
+    fn evict(&mut self, key: &GlyphKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            if !entry.is_empty() {
+                let shelf = &mut self.pages[entry.page].shelves[entry.shelf];
+                shelf.release(entry.x, entry.width + GLYPH_PADDING);
+            }
+        }
+    }
+}
+
+impl Default for GlyphAtlas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
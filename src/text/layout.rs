@@ -0,0 +1,390 @@
+use crate::text::font::caret_extent;
+use crate::text::FontRenderer;
+
+/// 줄바꿈 결과의 한 줄.
+///
+/// `start..end`는 전체 텍스트에서 이 줄이 차지하는 문자 범위이며 줄 끝의 '\n'은 포함하지 않습니다.
+/// 자동 줄바꿈된 줄은 끝의 공백을 포함하므로 다음 줄의 `start`와 `end`가 같습니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLine {
+    pub start: usize,
+    pub end: usize,
+    /// 줄 안의 문자 경계 x 위치 (줄의 왼쪽 끝 기준, 길이는 문자 수 + 1)
+    pub caret_positions: Vec<f32>,
+    /// 폭 제한 때문에 나뉜 줄인지 ('\n'으로 끝나거나 마지막 줄이면 false)
+    pub is_wrapped: bool,
+}
+
+impl TextLine {
+    /// 줄의 너비 (끝 공백 포함)
+    pub fn width(&self) -> f32 {
+        caret_extent(&self.caret_positions).1
+    }
+
+    /// 캐럿을 놓을 수 있는 마지막 인덱스. 자동 줄바꿈된 줄의 끝은 다음 줄의 시작과 같으므로 한 칸 앞입니다.
+    pub fn last_caret(&self) -> usize {
+        if self.is_wrapped && self.end > self.start { self.end - 1 } else { self.end }
+    }
+}
+
+/// 텍스트를 '\n'과 최대 너비(`max_width`)에 따라 줄로 나눕니다.
+///
+/// 공백 뒤에서 줄을 바꾸며, 한 단어가 최대 너비보다 길면 글자 단위로 나눕니다.
+/// 줄 끝의 공백은 너비를 넘어도 그 줄에 남습니다. `max_width`가 `None`이면 '\n'에서만 나눕니다.
+pub fn wrap_text(
+    font: &FontRenderer,
+    text: &str,
+    scale: f32,
+    max_width: Option<f32>
+) -> Vec<TextLine> {
+    let mut lines = Vec::new();
+    let mut paragraph_start = 0;
+
+    for paragraph in text.split('\n') {
+        let chars: Vec<char> = paragraph.chars().collect();
+        let positions = font.caret_positions(paragraph, scale);
+        let mut line_start = 0;
+        let mut break_at = None; // 마지막 줄바꿈 가능 위치 (공백 다음)
+
+        for (i, &c) in chars.iter().enumerate() {
+            if c.is_whitespace() {
+                break_at = Some(i + 1);
+                continue;
+            }
+
+            let overflows = max_width.is_some_and(|width| {
+                let (left, right) = caret_extent(&positions[line_start..=i + 1]);
+                right - left > width
+            });
+            if overflows && i > line_start {
+                let end = match break_at {
+                    Some(index) if index > line_start => index,
+                    _ => i,
+                };
+                lines.push(line(paragraph_start, line_start, end, &positions, true));
+                line_start = end;
+                break_at = None;
+            }
+        }
+
+        lines.push(line(paragraph_start, line_start, chars.len(), &positions, false));
+        paragraph_start += chars.len() + 1;
+    }
+    lines
+}
+
+// 문단 안의 문자 범위로 줄을 만듭니다.
+fn line(offset: usize, start: usize, end: usize, positions: &[f32], is_wrapped: bool) -> TextLine {
+    let (origin, _) = caret_extent(&positions[start..=end]);
+    TextLine {
+        start: offset + start,
+        end: offset + end,
+        caret_positions: positions[start..=end]
+            .iter()
+            .map(|x| x - origin)
+            .collect(),
+        is_wrapped,
+    }
+}
+
+/// 문자 인덱스가 있는 줄의 번호. 자동 줄바꿈 경계에서는 다음 줄을 선택합니다.
+pub fn line_index_of(lines: &[TextLine], index: usize) -> usize {
+    lines
+        .iter()
+        .rposition(|line| line.start <= index)
+        .unwrap_or(0)
+}
+
+/// 줄의 가로 정렬
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// 자동 줄바꿈된 줄은 단어 간격을 늘려 양쪽 끝을 맞춥니다 (문단의 마지막 줄은 왼쪽 정렬).
+    Justify,
+}
+
+/// 텍스트 블록의 세로 정렬 (높이가 지정된 경우)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// 텍스트가 영역을 넘칠 때의 처리
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextOverflow {
+    /// 넘친 부분도 그대로 배치 (`TextView`는 기본적으로 위젯 영역 밖을 잘라서 그림)
+    #[default]
+    Visible,
+    /// 영역에 들어오는 줄과 글자만 표시
+    Clip,
+    /// 잘린 부분을 말줄임표(…)로 표시
+    Ellipsis,
+}
+
+/// `TextLayout` 계산 옵션
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextLayoutOptions {
+    /// 영역 너비. 줄바꿈과 정렬, 가로 넘침의 기준이며 `None`이면 가장 긴 줄의 너비를 사용합니다.
+    pub width: Option<f32>,
+    /// 영역 높이. 세로 정렬과 세로 넘침의 기준입니다.
+    pub height: Option<f32>,
+    /// 영역 너비에 맞춰 자동 줄바꿈할지 여부 ('\n'은 항상 줄을 바꿈)
+    pub wrap: bool,
+    pub align: TextAlign,
+    pub vertical_align: VerticalAlign,
+    /// 줄 간격 배율 (1.0이면 글꼴의 줄 높이)
+    pub line_spacing: f32,
+    pub overflow: TextOverflow,
+}
+
+impl TextLayoutOptions {
+    pub fn new() -> Self {
+        Self {
+            width: None,
+            height: None,
+            wrap: false,
+            align: TextAlign::Left,
+            vertical_align: VerticalAlign::Top,
+            line_spacing: 1.0,
+            overflow: TextOverflow::Visible,
+        }
+    }
+
+    pub fn with_size(mut self, width: Option<f32>, height: Option<f32>) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_vertical_align(mut self, vertical_align: VerticalAlign) -> Self {
+        self.vertical_align = vertical_align;
+        self
+    }
+
+    pub fn with_line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+impl Default for TextLayoutOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 한 번에 그리는 텍스트 조각 (양쪽 정렬이면 단어 하나, 아니면 줄 전체)
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextRun {
+    pub text: String,
+    /// 영역 왼쪽 기준 x 위치
+    pub x: f32,
+}
+
+/// 배치가 끝난 한 줄
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutLine {
+    pub runs: Vec<TextRun>,
+    /// 영역 위쪽 기준 줄의 y 위치 (줄의 위쪽)
+    pub y: f32,
+    /// 끝 공백을 제외한 줄의 너비
+    pub width: f32,
+}
+
+/// 여러 줄 텍스트의 배치 결과.
+///
+/// 좌표는 모두 텍스트 영역의 좌상단 기준이며, `Renderer::draw_text`에 영역 위치를 더해서 그립니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    pub lines: Vec<LayoutLine>,
+    /// 가장 긴 줄의 너비
+    pub width: f32,
+    /// 표시되는 줄 전체의 높이
+    pub height: f32,
+    /// 넘친 줄이나 글자가 잘렸는지 여부
+    pub truncated: bool,
+}
+
+const ELLIPSIS: char = '…';
+
+impl TextLayout {
+    /// '\n'과 옵션에 따라 텍스트를 줄로 나누고 정렬합니다.
+    pub fn new(font: &FontRenderer, text: &str, scale: f32, options: &TextLayoutOptions) -> Self {
+        let wrap_width = if options.wrap { options.width } else { None };
+        let wrapped = wrap_text(font, text, scale, wrap_width);
+        let chars: Vec<char> = text.chars().collect();
+
+        let line_height = font.line_height(scale);
+        let advance = line_height * options.line_spacing;
+
+        // 높이 안에 들어오는 줄 수
+        let mut visible_count = wrapped.len();
+        if let (Some(height), TextOverflow::Clip | TextOverflow::Ellipsis) = (options.height, options.overflow) {
+            let fitting = if height < line_height {
+                0
+            } else {
+                ((height - line_height) / advance + 1e-3).floor() as usize + 1
+            };
+            visible_count = visible_count.min(fitting.max(1));
+        }
+        let mut truncated = visible_count < wrapped.len();
+
+        // 각 줄의 표시 텍스트와 글자 위치 (가로 넘침 처리 포함)
+        let mut contents: Vec<(String, Vec<f32>, bool)> = Vec::with_capacity(visible_count);
+        for (index, line) in wrapped.iter().take(visible_count).enumerate() {
+            let text: String = chars[line.start..line.end].iter().collect();
+            let text = text.trim_end().to_string();
+            let is_last_visible = index + 1 == visible_count;
+
+            let mut content = text;
+            if let Some(width) = options.width {
+                let force_ellipsis = truncated && is_last_visible && options.overflow == TextOverflow::Ellipsis;
+                let (fitted, cut) = fit_to_width(font, &content, scale, width, options.overflow, force_ellipsis);
+                content = fitted;
+                truncated |= cut;
+            }
+
+            let positions = font.caret_positions(&content, scale);
+            contents.push((content, positions, line.is_wrapped));
+        }
+
+        let width = contents
+            .iter()
+            .map(|(_, positions, _)| caret_extent(positions).1)
+            .fold(0.0, f32::max);
+        let box_width = options.width.unwrap_or(width);
+
+        let height = if contents.is_empty() {
+            0.0
+        } else {
+            (contents.len() - 1) as f32 * advance + line_height
+        };
+        let offset_y = match (options.height, options.vertical_align) {
+            (Some(box_height), VerticalAlign::Middle) => ((box_height - height) / 2.0).max(0.0),
+            (Some(box_height), VerticalAlign::Bottom) => (box_height - height).max(0.0),
+            _ => 0.0,
+        };
+
+        let lines = contents
+            .into_iter()
+            .enumerate()
+            .map(|(index, (content, positions, is_wrapped))| {
+                let line_width = caret_extent(&positions).1;
+                let y = offset_y + index as f32 * advance;
+                let runs = match options.align {
+                    TextAlign::Justify if is_wrapped => justify(&content, &positions, box_width),
+                    align => {
+                        let free = box_width - line_width;
+                        let x = match align {
+                            TextAlign::Center => free / 2.0,
+                            TextAlign::Right => free,
+                            _ => 0.0,
+                        };
+                        vec![TextRun { text: content, x }]
+                    }
+                };
+                LayoutLine { runs, y, width: line_width }
+            })
+            .collect();
+
+        Self { lines, width, height, truncated }
+    }
+}
+
+// 줄이 너비를 넘으면 넘침 모드에 따라 자릅니다. 잘렸으면 두 번째 값이 true입니다.
+// `force_ellipsis`가 true면 (뒤에 잘린 줄이 있으므로) 너비 안에 들어와도 말줄임표를 붙입니다.
+fn fit_to_width(
+    font: &FontRenderer,
+    text: &str,
+    scale: f32,
+    width: f32,
+    overflow: TextOverflow,
+    force_ellipsis: bool
+) -> (String, bool) {
+    let metrics = font.measure(text, scale);
+    let positions = metrics.caret_positions;
+    let text_width = metrics.advance_width;
+    if text_width <= width && !force_ellipsis {
+        return (text.to_string(), false);
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    match overflow {
+        TextOverflow::Visible => (text.to_string(), false),
+        TextOverflow::Clip => {
+            let count = fitting_prefix(&positions, width);
+            (chars[..count].iter().collect(), true)
+        }
+        TextOverflow::Ellipsis => {
+            let ellipsis_width = font.measure(&ELLIPSIS.to_string(), scale).advance_width;
+            let count = fitting_prefix(&positions, width - ellipsis_width);
+            let mut fitted: String = chars[..count].iter().collect::<String>().trim_end().to_string();
+            fitted.push(ELLIPSIS);
+            (fitted, true)
+        }
+    }
+}
+
+/// 앞에서부터 몇 글자까지 `width` 안에 들어가는지 (캐럿 위치의 가로 범위로 비교)
+pub(crate) fn fitting_prefix(positions: &[f32], width: f32) -> usize {
+    (1..positions.len())
+        .take_while(|&count| {
+            let (left, right) = caret_extent(&positions[..=count]);
+            right - left <= width
+        })
+        .count()
+}
+
+// 단어 사이 공백을 늘려 줄의 양쪽 끝을 영역에 맞춥니다.
+fn justify(text: &str, positions: &[f32], box_width: f32) -> Vec<TextRun> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words: Vec<(usize, usize)> = Vec::new();
+    let mut start = None;
+    for (i, c) in chars.iter().enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                words.push((s, i));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, chars.len()));
+    }
+
+    let line_width = caret_extent(positions).1;
+    let gaps = words.len().saturating_sub(1);
+    let extra = if gaps > 0 { ((box_width - line_width) / gaps as f32).max(0.0) } else { 0.0 };
+
+    words
+        .into_iter()
+        .enumerate()
+        .map(|(index, (start, end))| TextRun {
+            text: chars[start..end].iter().collect(),
+            x: positions[start] + extra * index as f32,
+        })
+        .collect()
+}
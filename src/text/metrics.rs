@@ -0,0 +1,59 @@
+use crate::text::font::nearest_index;
+
+/// 문자열 하나를 한 줄로 배치했을 때의 측정 결과.
+///
+/// 모든 값은 픽셀 단위이며, x 좌표는 텍스트를 그리는 위치(`Renderer::draw_text`의 x) 기준입니다.
+/// 세로 값은 글꼴 전체의 값이라 문자열 내용과 관계없이 같은 크기에서 항상 같습니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextMetrics {
+    /// 펜이 이동한 거리 (끝 공백 포함). 텍스트 너비로 사용합니다.
+    pub advance_width: f32,
+    /// 기준선에서 글꼴의 가장 높은 곳까지의 거리 (양수)
+    pub ascent: f32,
+    /// 기준선에서 글꼴의 가장 낮은 곳까지의 거리 (기준선 아래이므로 음수)
+    pub descent: f32,
+    /// 글꼴이 권장하는 줄 사이 추가 간격
+    pub line_gap: f32,
+    /// 각 글리프(문자)가 그려지는 펜 위치
+    pub glyph_offsets: Vec<f32>,
+    /// 문자 경계마다의 캐럿 위치 (길이는 문자 수 + 1). 논리 순서라서 오른쪽에서 왼쪽으로 쓰는
+    /// 문자가 있으면 증가하지 않으며, 마지막 값도 `advance_width`가 아닐 수 있습니다.
+    pub caret_positions: Vec<f32>,
+    /// 실제로 픽셀이 칠해지는 가로 범위 (min_x, max_x). 공백만 있으면 `None`
+    pub ink_bounds: Option<(f32, f32)>,
+}
+
+impl TextMetrics {
+    /// 한 줄의 높이 (ascent - descent). 텍스트를 세로로 가운데 맞출 때 사용합니다.
+    pub fn height(&self) -> f32 {
+        self.ascent - self.descent
+    }
+
+    /// 여러 줄을 쌓을 때 다음 줄까지의 거리 (height + line_gap)
+    pub fn line_advance(&self) -> f32 {
+        self.height() + self.line_gap
+    }
+
+    /// (너비, 높이)
+    pub fn size(&self) -> (f32, f32) {
+        (self.advance_width, self.height())
+    }
+
+    /// 문자 인덱스 앞의 캐럿 x 위치 (범위를 넘으면 끝 위치)
+    pub fn caret_x(&self, index: usize) -> f32 {
+        let last = self.caret_positions.len().saturating_sub(1);
+        self.caret_positions.get(index.min(last)).copied().unwrap_or(0.0)
+    }
+
+    /// x 위치에 가장 가까운 문자 경계의 인덱스 (마우스로 캐럿을 놓을 때 사용)
+    pub fn hit_test(&self, x: f32) -> usize {
+        nearest_index(&self.caret_positions, x)
+    }
+
+    /// x 위치에 있는 문자의 인덱스. 텍스트 밖이면 `None`
+    pub fn char_at(&self, x: f32) -> Option<usize> {
+        self.caret_positions
+            .windows(2)
+            .position(|pair| x >= pair[0].min(pair[1]) && x < pair[0].max(pair[1]))
+    }
+}
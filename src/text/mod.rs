@@ -1,5 +1,33 @@
+pub mod editor;
 pub mod font;
+pub mod font_registry;
+pub mod glyph_atlas;
+pub mod layout;
+pub mod metrics;
+pub mod rich_text;
+pub mod sdf;
+#[cfg(feature = "shaping")]
+mod shaping;
 pub mod text_renderer;
+pub mod variation;
 
-pub use font::FontRenderer;
+pub use editor::TextEditor;
+pub use font::{ caret_extent, selection_spans, FontGlyph, FontRenderer, FONT_SCALE_FACTOR };
+pub use font_registry::{ FontQuery, FontRegistry, FontStyle, FontWeight, DEFAULT_FONT_FAMILY };
+pub use glyph_atlas::GlyphAtlas;
+pub use layout::{
+    wrap_text,
+    LayoutLine,
+    TextAlign,
+    TextLayout,
+    TextLayoutOptions,
+    TextLine,
+    TextOverflow,
+    TextRun,
+    VerticalAlign,
+};
+pub use metrics::TextMetrics;
+pub use rich_text::{ RichLine, RichRun, RichText, RichTextLayout, SpanStyle, TextSpan };
+pub use sdf::{ TextEffect, TextRenderMode, SDF_BASE_SIZE, SDF_SPREAD };
 pub use text_renderer::TextRenderer;
+pub use variation::{ FontVariation, VariationAxis };
@@ -0,0 +1,569 @@
+use crate::style::color::Color;
+use crate::text::layout::{ TextAlign, TextLayoutOptions, TextOverflow, VerticalAlign };
+use crate::text::{ FontRenderer, FontWeight, TextMetrics };
+
+/// 스팬 하나의 스타일. `None`인 값은 텍스트를 그리는 위젯의 설정(글자 색, 크기, 굵기)을 따릅니다.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpanStyle {
+    pub color: Option<Color>,
+    pub size: Option<f32>,
+    pub weight: Option<FontWeight>,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    /// 글자 뒤에 칠할 강조 색
+    pub background: Option<Color>,
+}
+
+/// 같은 스타일로 그리는 텍스트 조각
+#[derive(Clone, Debug)]
+pub struct TextSpan {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+impl TextSpan {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: SpanStyle::default(),
+        }
+    }
+
+    pub fn with_style(mut self, style: SpanStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.style.color = Some(color);
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.style.size = Some(size);
+        self
+    }
+
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.style.weight = Some(weight);
+        self
+    }
+
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.style.italic = italic;
+        self
+    }
+
+    pub fn with_underline(mut self, underline: bool) -> Self {
+        self.style.underline = underline;
+        self
+    }
+
+    pub fn with_strikethrough(mut self, strikethrough: bool) -> Self {
+        self.style.strikethrough = strikethrough;
+        self
+    }
+
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.style.background = Some(color);
+        self
+    }
+}
+
+/// 스타일이 다른 스팬들로 이루어진 텍스트.
+///
+/// 스팬 경계는 줄바꿈 위치와 관계없으며, 한 단어가 여러 스팬에 걸쳐 있어도 한 단어로 줄을 바꿉니다.
+#[derive(Clone, Debug, Default)]
+pub struct RichText {
+    spans: Vec<TextSpan>,
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        Self { spans: Vec::new() }
+    }
+
+    pub fn with_span(mut self, span: TextSpan) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    pub fn push(&mut self, span: TextSpan) {
+        self.spans.push(span);
+    }
+
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// 모든 스팬의 텍스트를 이은 문자열
+    pub fn text(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.text.as_str())
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|span| span.text.is_empty())
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        Self::new().with_span(TextSpan::new(text))
+    }
+}
+
+/// 한 번에 그리는 같은 스팬의 텍스트 조각
+#[derive(Clone, Debug, PartialEq)]
+pub struct RichRun {
+    /// `RichText::spans`의 인덱스
+    pub span: usize,
+    pub text: String,
+    /// 영역 왼쪽 기준 x 위치
+    pub x: f32,
+    /// 영역 위쪽 기준으로 텍스트를 그릴 y 위치 (`draw_text`의 y, 기준선 - ascent)
+    pub y: f32,
+    pub width: f32,
+    /// 글꼴 크기 (`draw_text`의 scale)
+    pub size: f32,
+    /// 이 조각의 글꼴 크기에서의 ascent (기준선 = y + ascent)
+    pub ascent: f32,
+}
+
+/// 배치가 끝난 한 줄. 줄 높이는 줄에서 가장 큰 글자에 맞춰집니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RichLine {
+    pub runs: Vec<RichRun>,
+    /// 영역 위쪽 기준 줄의 y 위치 (줄의 위쪽)
+    pub y: f32,
+    /// 줄의 높이 (가장 큰 ascent - 가장 작은 descent)
+    pub height: f32,
+    /// 영역 위쪽 기준 기준선의 y 위치
+    pub baseline: f32,
+    /// 끝 공백을 제외한 줄의 너비
+    pub width: f32,
+}
+
+/// `RichText`의 배치 결과. 좌표는 모두 텍스트 영역의 좌상단 기준입니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichTextLayout {
+    pub lines: Vec<RichLine>,
+    /// 가장 긴 줄의 너비
+    pub width: f32,
+    /// 표시되는 줄 전체의 높이
+    pub height: f32,
+    /// 넘친 줄이나 글자가 잘렸는지 여부
+    pub truncated: bool,
+}
+
+const ELLIPSIS: &str = "…";
+
+// 줄바꿈 단위 조각: 공백 뒤나 스팬 경계에서 나뉩니다.
+#[derive(Clone, Debug)]
+struct Fragment {
+    span: usize,
+    text: String,
+    width: f32, // 끝 공백 포함
+    trimmed_width: f32, // 끝 공백 제외
+    ends_with_space: bool, // 뒤에서 줄을 바꿀 수 있는지 여부
+}
+
+// 줄로 나눈 조각들. `span`은 빈 줄의 높이를 정할 스팬
+struct BrokenLine {
+    fragments: Vec<Fragment>,
+    span: usize,
+    is_wrapped: bool,
+}
+
+impl RichTextLayout {
+    /// 스팬마다 `fonts[i]`(스팬의 굵기/스타일에 맞춘 글꼴)와 크기(`style.size` 또는 `base_size`)로
+    /// 줄을 나누고 정렬합니다. 옵션의 의미는 `TextLayout`과 같습니다.
+    pub fn new(
+        text: &RichText,
+        fonts: &[FontRenderer],
+        base_size: f32,
+        options: &TextLayoutOptions
+    ) -> Self {
+        let spans = text.spans();
+        if spans.is_empty() || fonts.len() < spans.len() {
+            return Self::default();
+        }
+        let size_of = |span: usize| spans[span].style.size.unwrap_or(base_size);
+        let measure = |span: usize, text: &str| fonts[span].measure(text, size_of(span));
+
+        let wrap_width = if options.wrap { options.width } else { None };
+        let broken = break_lines(spans, &measure, wrap_width);
+
+        // 줄마다 세로 메트릭 (빈 줄은 줄이 속한 스팬의 글꼴)
+        let metrics: Vec<(f32, f32)> = broken
+            .iter()
+            .map(|line| {
+                let mut used: Vec<usize> = line.fragments
+                    .iter()
+                    .map(|f| f.span)
+                    .collect();
+                if used.is_empty() {
+                    used.push(line.span);
+                }
+                used.iter().fold((0.0f32, 0.0f32), |(ascent, descent), &span| {
+                    let m = measure(span, "");
+                    (ascent.max(m.ascent), descent.min(m.descent))
+                })
+            })
+            .collect();
+
+        // 높이 안에 들어오는 줄 수
+        let mut visible_count = broken.len();
+        if let (Some(height), TextOverflow::Clip | TextOverflow::Ellipsis) = (options.height, options.overflow) {
+            let mut top = 0.0;
+            let mut fitting = 0;
+            for (ascent, descent) in &metrics {
+                let line_height = ascent - descent;
+                if top + line_height > height + 1e-3 {
+                    break;
+                }
+                fitting += 1;
+                top += line_height * options.line_spacing;
+            }
+            visible_count = visible_count.min(fitting.max(1));
+        }
+        let mut truncated = visible_count < broken.len();
+
+        let mut lines = Vec::with_capacity(visible_count);
+        let mut top = 0.0;
+        let mut width: f32 = 0.0;
+        for (index, line) in broken.into_iter().take(visible_count).enumerate() {
+            let (ascent, descent) = metrics[index];
+            let mut fragments = trim_end(line.fragments, &measure);
+
+            if let Some(box_width) = options.width {
+                let force_ellipsis =
+                    truncated && index + 1 == visible_count && options.overflow == TextOverflow::Ellipsis;
+                truncated |= fit_to_width(&mut fragments, &measure, box_width, options.overflow, force_ellipsis);
+            }
+
+            let line_width: f32 = fragments
+                .iter()
+                .map(|f| f.width)
+                .sum();
+            width = width.max(line_width);
+            let baseline = top + ascent;
+
+            // 조각의 x 위치 (양쪽 정렬이면 줄바꿈 가능한 공백마다 간격을 늘림)
+            let box_width = options.width.unwrap_or(line_width);
+            let free = box_width - line_width;
+            let gaps = fragments
+                .iter()
+                .take(fragments.len().saturating_sub(1))
+                .filter(|f| f.ends_with_space)
+                .count();
+            let (mut x, extra) = match options.align {
+                TextAlign::Justify if line.is_wrapped && gaps > 0 => (0.0, (free / gaps as f32).max(0.0)),
+                TextAlign::Center => (free / 2.0, 0.0),
+                TextAlign::Right => (free, 0.0),
+                _ => (0.0, 0.0),
+            };
+
+            let mut runs: Vec<RichRun> = Vec::new();
+            for fragment in fragments {
+                let run_ascent = measure(fragment.span, "").ascent;
+                let next_x = x + fragment.width + if fragment.ends_with_space { extra } else { 0.0 };
+                match runs.last_mut() {
+                    // 같은 스팬이 이어지면 한 조각으로 합쳐서 그립니다.
+                    Some(run) if run.span == fragment.span && (run.x + run.width - x).abs() < 0.01 => {
+                        run.text.push_str(&fragment.text);
+                        run.width += fragment.width;
+                    }
+                    _ => runs.push(RichRun {
+                        span: fragment.span,
+                        text: fragment.text,
+                        x,
+                        y: baseline - run_ascent,
+                        width: fragment.width,
+                        size: size_of(fragment.span),
+                        ascent: run_ascent,
+                    }),
+                }
+                x = next_x;
+            }
+
+            let height = ascent - descent;
+            lines.push(RichLine { runs, y: top, height, baseline, width: line_width });
+            top += height * options.line_spacing;
+        }
+
+        let height = lines
+            .last()
+            .map(|line| line.y + line.height)
+            .unwrap_or(0.0);
+        let offset_y = match (options.height, options.vertical_align) {
+            (Some(box_height), VerticalAlign::Middle) => ((box_height - height) / 2.0).max(0.0),
+            (Some(box_height), VerticalAlign::Bottom) => (box_height - height).max(0.0),
+            _ => 0.0,
+        };
+        for line in &mut lines {
+            line.y += offset_y;
+            line.baseline += offset_y;
+            for run in &mut line.runs {
+                run.y += offset_y;
+            }
+        }
+
+        Self { lines, width, height, truncated }
+    }
+}
+
+// 스팬들을 '\n'과 최대 너비에 따라 줄로 나눕니다. 줄바꿈 규칙은 `wrap_text`와 같습니다.
+fn break_lines<M>(spans: &[TextSpan], measure: &M, max_width: Option<f32>) -> Vec<BrokenLine>
+    where M: Fn(usize, &str) -> TextMetrics
+{
+    let mut lines = Vec::new();
+    let mut current = BrokenLine { fragments: Vec::new(), span: 0, is_wrapped: false };
+    let mut word: Vec<Fragment> = Vec::new();
+
+    for (span, text_span) in spans.iter().enumerate() {
+        for (index, paragraph) in text_span.text.split('\n').enumerate() {
+            if index > 0 {
+                place_word(&mut lines, &mut current, std::mem::take(&mut word), measure, max_width);
+                let finished = std::mem::replace(
+                    &mut current,
+                    BrokenLine { fragments: Vec::new(), span, is_wrapped: false }
+                );
+                lines.push(finished);
+            }
+            current.span = if current.fragments.is_empty() { span } else { current.span };
+
+            for token in split_after_whitespace(paragraph) {
+                let metrics = measure(span, token);
+                let trimmed = token.trim_end();
+                let ends_with_space = trimmed.len() < token.len();
+                word.push(Fragment {
+                    span,
+                    text: token.to_string(),
+                    width: metrics.advance_width,
+                    trimmed_width: if ends_with_space {
+                        metrics.caret_x(trimmed.chars().count())
+                    } else {
+                        metrics.advance_width
+                    },
+                    ends_with_space,
+                });
+                if ends_with_space {
+                    place_word(&mut lines, &mut current, std::mem::take(&mut word), measure, max_width);
+                }
+            }
+        }
+    }
+    place_word(&mut lines, &mut current, word, measure, max_width);
+    lines.push(current);
+    lines
+}
+
+// 공백이 끝나는 곳마다 나눕니다. 예: "a  b c" → ["a  ", "b ", "c"]
+fn split_after_whitespace(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut previous_space = false;
+    for (index, c) in text.char_indices() {
+        let space = c.is_whitespace();
+        if previous_space && !space {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        previous_space = space;
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+// 단어(줄바꿈 기회 없이 이어진 조각들)를 현재 줄에 놓고, 넘치면 새 줄을 시작합니다.
+// 단어 자체가 최대 너비보다 길면 글자 단위로 나눕니다.
+fn place_word<M>(
+    lines: &mut Vec<BrokenLine>,
+    current: &mut BrokenLine,
+    word: Vec<Fragment>,
+    measure: &M,
+    max_width: Option<f32>
+)
+    where M: Fn(usize, &str) -> TextMetrics
+{
+    let Some(last) = word.last() else {
+        return;
+    };
+    let Some(max_width) = max_width else {
+        current.fragments.extend(word);
+        return;
+    };
+
+    let line_width: f32 = current.fragments
+        .iter()
+        .map(|f| f.width)
+        .sum();
+    let word_width: f32 =
+        word[..word.len() - 1]
+            .iter()
+            .map(|f| f.width)
+            .sum::<f32>() + last.trimmed_width;
+
+    if line_width + word_width <= max_width {
+        current.fragments.extend(word);
+        return;
+    }
+    if !current.fragments.is_empty() {
+        start_wrapped_line(lines, current, word[0].span);
+        if word_width <= max_width {
+            current.fragments.extend(word);
+            return;
+        }
+    }
+
+    // 글자 단위로 나누기
+    for fragment in word {
+        let metrics = measure(fragment.span, &fragment.text);
+        let chars: Vec<char> = fragment.text.chars().collect();
+        let mut start = 0;
+        let mut line_width: f32 = current.fragments
+            .iter()
+            .map(|f| f.width)
+            .sum();
+        for i in 0..chars.len() {
+            let piece_width = metrics.caret_x(i + 1) - metrics.caret_x(start);
+            let overflows = !chars[i].is_whitespace() && line_width + piece_width > max_width;
+            if overflows && (i > start || !current.fragments.is_empty()) {
+                if i > start {
+                    current.fragments.push(piece(&fragment, &chars, start, i, &metrics));
+                }
+                start_wrapped_line(lines, current, fragment.span);
+                start = i;
+                line_width = 0.0;
+            }
+        }
+        current.fragments.push(piece(&fragment, &chars, start, chars.len(), &metrics));
+    }
+}
+
+fn start_wrapped_line(lines: &mut Vec<BrokenLine>, current: &mut BrokenLine, span: usize) {
+    let mut finished = std::mem::replace(current, BrokenLine { fragments: Vec::new(), span, is_wrapped: false });
+    finished.is_wrapped = true;
+    lines.push(finished);
+}
+
+// 조각의 글자 범위 start..end로 새 조각을 만듭니다.
+fn piece(
+    fragment: &Fragment,
+    chars: &[char],
+    start: usize,
+    end: usize,
+    metrics: &TextMetrics
+) -> Fragment {
+    let text: String = chars[start..end].iter().collect();
+    let trimmed_len = text.trim_end().chars().count();
+    let width = metrics.caret_x(end) - metrics.caret_x(start);
+    Fragment {
+        span: fragment.span,
+        trimmed_width: metrics.caret_x(start + trimmed_len) - metrics.caret_x(start),
+        ends_with_space: trimmed_len < end - start && end == chars.len() && fragment.ends_with_space,
+        text,
+        width,
+    }
+}
+
+// 줄 끝 공백을 지웁니다 (양쪽 정렬과 너비 계산에서 제외).
+fn trim_end<M>(mut fragments: Vec<Fragment>, measure: &M) -> Vec<Fragment>
+    where M: Fn(usize, &str) -> TextMetrics
+{
+    while let Some(last) = fragments.last_mut() {
+        let trimmed = last.text.trim_end();
+        if trimmed.is_empty() {
+            fragments.pop();
+            continue;
+        }
+        if trimmed.len() < last.text.len() {
+            last.text = trimmed.to_string();
+            last.width = measure(last.span, &last.text).advance_width;
+            last.trimmed_width = last.width;
+        }
+        last.ends_with_space = false;
+        break;
+    }
+    fragments
+}
+
+// 줄이 너비를 넘으면 넘침 모드에 따라 자릅니다. 잘렸으면 true를 반환합니다.
+// 말줄임표는 잘린 곳의 스팬 스타일로 그립니다.
+fn fit_to_width<M>(
+    fragments: &mut Vec<Fragment>,
+    measure: &M,
+    width: f32,
+    overflow: TextOverflow,
+    force_ellipsis: bool
+) -> bool
+    where M: Fn(usize, &str) -> TextMetrics
+{
+    let line_width: f32 = fragments
+        .iter()
+        .map(|f| f.width)
+        .sum();
+    if overflow == TextOverflow::Visible || (line_width <= width && !force_ellipsis) {
+        return false;
+    }
+
+    let mut x = 0.0;
+    let mut kept = Vec::new();
+    let mut last_span = fragments.last().map(|f| f.span).unwrap_or(0);
+    for fragment in fragments.drain(..) {
+        let ellipsis_width = if overflow == TextOverflow::Ellipsis {
+            measure(fragment.span, ELLIPSIS).advance_width
+        } else {
+            0.0
+        };
+        let limit = width - ellipsis_width;
+        if x + fragment.width <= limit {
+            x += fragment.width;
+            last_span = fragment.span;
+            kept.push(fragment);
+            continue;
+        }
+
+        // 이 조각에서 자름
+        last_span = fragment.span;
+        let metrics = measure(fragment.span, &fragment.text);
+        let chars: Vec<char> = fragment.text.chars().collect();
+        let count = (1..=chars.len())
+            .take_while(|&i| x + metrics.caret_x(i) <= limit)
+            .count();
+        if count > 0 {
+            let text: String = chars[..count].iter().collect();
+            let width = metrics.caret_x(count);
+            kept.push(Fragment { text, width, trimmed_width: width, ends_with_space: false, ..fragment });
+        }
+        break;
+    }
+
+    if overflow == TextOverflow::Ellipsis {
+        kept = trim_end(kept, measure);
+        let span = kept.last().map(|f| f.span).unwrap_or(last_span);
+        let ellipsis_width = measure(span, ELLIPSIS).advance_width;
+        match kept.last_mut() {
+            Some(last) if last.span == span => {
+                last.text.push_str(ELLIPSIS);
+                last.width += ellipsis_width;
+                last.trimmed_width = last.width;
+            }
+            _ => kept.push(Fragment {
+                span,
+                text: ELLIPSIS.to_string(),
+                width: ellipsis_width,
+                trimmed_width: ellipsis_width,
+                ends_with_space: false,
+            }),
+        }
+    }
+    *fragments = kept;
+    true
+}
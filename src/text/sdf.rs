@@ -0,0 +1,200 @@
+use super::glyph_atlas::RasterizedGlyph;
+use rusttype::{ point, PositionedGlyph, Scale };
+
+/// SDF 글리프를 래스터화하는 기준 픽셀 크기. 이 크기로 한 번만 만든 거리 필드를
+/// 어떤 글자 크기로도 늘리거나 줄여서 그립니다.
+pub const SDF_BASE_SIZE: f32 = 64.0;
+
+/// 글리프 윤곽선 바깥(과 안쪽)으로 저장하는 거리 범위 (기준 크기의 픽셀).
+/// 외곽선, 글로우, 그림자는 이 범위를 글자 크기 비율로 줄인 거리까지만 표현됩니다.
+pub const SDF_SPREAD: f32 = 16.0;
+
+/// 텍스트를 아틀라스에 올리는 방식
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TextRenderMode {
+    /// 글자 크기마다 정확한 픽셀 크기로 래스터화한 커버리지 비트맵 (작은 글자가 가장 선명함)
+    #[default]
+    Bitmap,
+    /// 부호 있는 거리 필드. 한 번 만든 글리프를 크기와 상관없이 선명하게 확대/축소하며
+    /// 애니메이션이나 DPI 변경 중에도 다시 래스터화하지 않습니다.
+    Sdf,
+}
+
+/// SDF 텍스트에 적용하는 외곽선, 글로우, 그림자 효과.
+///
+/// 거리와 오프셋은 화면 픽셀 단위이며, 색상의 알파가 0이거나 크기가 0인 효과는 그리지 않습니다.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct TextEffect {
+    pub outline_width: f32,
+    pub outline_color: [f32; 4],
+    pub glow_radius: f32,
+    pub glow_color: [f32; 4],
+    pub shadow_offset: [f32; 2],
+    pub shadow_softness: f32,
+    pub shadow_color: [f32; 4],
+}
+
+impl TextEffect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 글자 윤곽선 바깥쪽으로 `width` 픽셀 두께의 외곽선
+    pub fn with_outline(mut self, width: f32, color: [f32; 4]) -> Self {
+        self.outline_width = width.max(0.0);
+        self.outline_color = color;
+        self
+    }
+
+    /// 글자(외곽선 포함) 주변으로 `radius` 픽셀까지 옅어지는 글로우
+    pub fn with_glow(mut self, radius: f32, color: [f32; 4]) -> Self {
+        self.glow_radius = radius.max(0.0);
+        self.glow_color = color;
+        self
+    }
+
+    /// (dx, dy)만큼 떨어진 그림자. `softness`는 그림자 가장자리가 흐려지는 폭(픽셀)입니다.
+    pub fn with_shadow(mut self, dx: f32, dy: f32, softness: f32, color: [f32; 4]) -> Self {
+        self.shadow_offset = [dx, dy];
+        self.shadow_softness = softness.max(0.0);
+        self.shadow_color = color;
+        self
+    }
+
+    /// 글자 뒤에 그릴 효과가 하나라도 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        let outline = self.outline_width > 0.0 && self.outline_color[3] > 0.0;
+        let glow = self.glow_radius > 0.0 && self.glow_color[3] > 0.0;
+        !outline && !glow && self.shadow_color[3] <= 0.0
+    }
+}
+
+/// 글리프를 `SDF_BASE_SIZE`로 래스터화한 뒤 부호 있는 거리 필드로 변환합니다.
+///
+/// 결과 비트맵은 `SDF_SPREAD`만큼 여백이 붙어 있고, 값 128이 윤곽선, 255 쪽이 글리프 안쪽입니다.
+/// 오프셋은 기준 크기의 픽셀이므로 그릴 때 `scale / SDF_BASE_SIZE`를 곱해야 합니다.
+pub(crate) fn rasterize_sdf_glyph(glyph: &PositionedGlyph<'static>) -> Option<RasterizedGlyph> {
+    let glyph = glyph
+        .unpositioned()
+        .unscaled()
+        .clone()
+        .scaled(Scale::uniform(SDF_BASE_SIZE))
+        .positioned(point(0.0, 0.0));
+    let bb = glyph.pixel_bounding_box()?;
+
+    let spread = SDF_SPREAD as i32;
+    let width = bb.width() + spread * 2;
+    let height = bb.height() + spread * 2;
+    let mut coverage = vec![0.0f32; (width * height) as usize];
+    glyph.draw(|x, y, v| {
+        let idx = ((y as i32) + spread) * width + (x as i32) + spread;
+        coverage[idx as usize] = v;
+    });
+
+    // 안쪽 픽셀에서 가장 가까운 바깥 픽셀까지, 바깥 픽셀에서 가장 가까운 안쪽 픽셀까지의 거리
+    let inside: Vec<bool> = coverage
+        .iter()
+        .map(|&v| v >= 0.5)
+        .collect();
+    let to_inside = distance_transform(&inside, width as usize, height as usize);
+    let outside: Vec<bool> = inside
+        .iter()
+        .map(|&v| !v)
+        .collect();
+    let to_outside = distance_transform(&outside, width as usize, height as usize);
+
+    let pixels = (0..coverage.len())
+        .map(|i| {
+            // 픽셀 중심과 윤곽선 사이의 반 픽셀을 빼서 윤곽선이 정확히 128에 오도록 맞춥니다.
+            let distance = if inside[i] {
+                to_outside[i].sqrt() - 0.5
+            } else {
+                0.5 - to_inside[i].sqrt()
+            };
+            let value = 0.5 + distance / (SDF_SPREAD * 2.0);
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8
+        })
+        .collect();
+
+    Some(RasterizedGlyph {
+        width,
+        height,
+        offset_x: bb.min.x - spread,
+        offset_y: bb.min.y - spread,
+        pixels,
+    })
+}
+
+// 각 픽셀에서 가장 가까운 `seeds` 픽셀까지의 제곱 거리 (Felzenszwalb & Huttenlocher)
+fn distance_transform(seeds: &[bool], width: usize, height: usize) -> Vec<f32> {
+    let mut grid: Vec<f32> = seeds
+        .iter()
+        .map(|&seed| if seed { 0.0 } else { f32::INFINITY })
+        .collect();
+
+    let size = width.max(height);
+    let mut line = vec![0.0f32; size];
+    let mut result = vec![0.0f32; size];
+    let mut parabolas = vec![0usize; size];
+    let mut bounds = vec![0.0f32; size + 1];
+
+    for x in 0..width {
+        for y in 0..height {
+            line[y] = grid[y * width + x];
+        }
+        distance_transform_1d(&line[..height], &mut result, &mut parabolas, &mut bounds);
+        for y in 0..height {
+            grid[y * width + x] = result[y];
+        }
+    }
+    for y in 0..height {
+        line[..width].copy_from_slice(&grid[y * width..(y + 1) * width]);
+        distance_transform_1d(&line[..width], &mut result, &mut parabolas, &mut bounds);
+        grid[y * width..(y + 1) * width].copy_from_slice(&result[..width]);
+    }
+    grid
+}
+
+// 1차원 포물선 하한(lower envelope)으로 제곱 거리를 구합니다.
+fn distance_transform_1d(f: &[f32], d: &mut [f32], v: &mut [usize], z: &mut [f32]) {
+    let n = f.len();
+    let Some(first) = f.iter().position(|value| value.is_finite()) else {
+        d[..n].fill(f32::INFINITY);
+        return;
+    };
+
+    let mut k = 0;
+    v[0] = first;
+    z[0] = f32::NEG_INFINITY;
+    z[1] = f32::INFINITY;
+    for q in first + 1..n {
+        if !f[q].is_finite() {
+            continue;
+        }
+        // z[0]이 음의 무한대이므로 k는 0 아래로 내려가지 않습니다.
+        let mut s = intersection(f, q, v[k]);
+        while s <= z[k] {
+            k -= 1;
+            s = intersection(f, q, v[k]);
+        }
+        k += 1;
+        v[k] = q;
+        z[k] = s;
+        z[k + 1] = f32::INFINITY;
+    }
+
+    k = 0;
+    for (q, distance) in d[..n].iter_mut().enumerate() {
+        while z[k + 1] < (q as f32) {
+            k += 1;
+        }
+        let offset = (q as f32) - (v[k] as f32);
+        *distance = offset * offset + f[v[k]];
+    }
+}
+
+// 포물선 q와 p가 만나는 위치
+fn intersection(f: &[f32], q: usize, p: usize) -> f32 {
+    let (qf, pf) = (q as f32, p as f32);
+    (f[q] + qf * qf - (f[p] + pf * pf)) / (2.0 * (qf - pf))
+}
@@ -0,0 +1,72 @@
+#version 330 core
+in vec2 TexCoord;
+in vec4 Color;
+out vec4 FragColor;
+
+// 거리 필드 아틀라스 (0.5가 글리프 윤곽선, 1.0 쪽이 안쪽)
+uniform sampler2D glyphAtlas;
+// 아틀라스 값 0 ~ 1이 나타내는 거리 범위의 절반 (아틀라스 텍셀)
+uniform float sdfSpread;
+// 0이면 글자 자체는 그리지 않고 효과만 그립니다 (효과를 모든 글자 뒤에 먼저 그릴 때).
+uniform float drawFill;
+uniform float outlineWidth;
+uniform vec4 outlineColor;
+uniform float glowRadius;
+uniform vec4 glowColor;
+uniform vec2 shadowOffset;
+uniform float shadowSoftness;
+uniform vec4 shadowColor;
+
+// 화면 1픽셀이 덮는 아틀라스 텍셀 수 (글자를 확대/축소한 비율)
+float texelsPerPixel() {
+    vec2 texels = fwidth(TexCoord) * vec2(textureSize(glyphAtlas, 0));
+    return max((texels.x + texels.y) * 0.5, 1e-4);
+}
+
+// 윤곽선까지의 부호 있는 거리 (화면 픽셀, 안쪽이 양수)
+float pixelDistance(vec2 uv, float scale) {
+    float value = texture(glyphAtlas, uv).r;
+    return (value - 0.5) * 2.0 * sdfSpread / scale;
+}
+
+// 알파를 곱하지 않은 색상끼리의 source-over 합성
+vec4 over(vec4 top, vec4 bottom) {
+    float alpha = top.a + bottom.a * (1.0 - top.a);
+    if (alpha <= 0.0) {
+        return vec4(0.0);
+    }
+    vec3 rgb = (top.rgb * top.a + bottom.rgb * bottom.a * (1.0 - top.a)) / alpha;
+    return vec4(rgb, alpha);
+}
+
+void main() {
+    float scale = texelsPerPixel();
+    float dist = pixelDistance(TexCoord, scale);
+    vec4 color = vec4(0.0);
+
+    if (shadowColor.a > 0.0) {
+        vec2 uvOffset = shadowOffset * scale / vec2(textureSize(glyphAtlas, 0));
+        float shadowDist = pixelDistance(TexCoord - uvOffset, scale) + outlineWidth;
+        float softness = max(shadowSoftness, 1.0);
+        float shadow = clamp((shadowDist + softness * 0.5) / softness, 0.0, 1.0);
+        color = over(vec4(shadowColor.rgb, shadowColor.a * shadow), color);
+    }
+
+    if (glowRadius > 0.0) {
+        float glow = 1.0 - clamp(-(dist + outlineWidth) / glowRadius, 0.0, 1.0);
+        color = over(vec4(glowColor.rgb, glowColor.a * glow * glow), color);
+    }
+
+    if (outlineWidth > 0.0) {
+        float outline = clamp(dist + outlineWidth + 0.5, 0.0, 1.0);
+        color = over(vec4(outlineColor.rgb, outlineColor.a * outline), color);
+    }
+
+    if (drawFill > 0.5) {
+        float fill = clamp(dist + 0.5, 0.0, 1.0);
+        color = over(vec4(Color.rgb, fill), color);
+    }
+
+    // 정점 알파(위젯 투명도)는 효과까지 포함해서 한 번에 적용합니다.
+    FragColor = vec4(color.rgb, color.a * Color.a);
+}
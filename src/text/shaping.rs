@@ -0,0 +1,93 @@
+use super::font::{ FontGlyph, FontRenderer };
+use rustybuzz::{ Direction, UnicodeBuffer };
+use rusttype::{ point, GlyphId };
+use std::ops::Range;
+use unicode_bidi::BidiInfo;
+
+// 한 번에 셰이핑하는 구간: 같은 방향, 같은 글꼴의 연속된 문자들
+struct Segment<'a> {
+    range: Range<usize>, // 바이트 범위
+    font: &'a FontRenderer,
+    rtl: bool,
+}
+
+/// 한 줄을 양방향 알고리즘으로 재배열하고 구간마다 rustybuzz로 셰이핑합니다 (`shaping` 기능).
+///
+/// 결과 글리프는 화면에 그릴 순서(왼쪽에서 오른쪽)이며, `cluster`는 원래 텍스트의 문자 인덱스입니다.
+pub(crate) fn shape_line(font: &FontRenderer, text: &str, scale: f32) -> Vec<FontGlyph> {
+    let scale = font.pixel_scale(scale);
+    let baseline = font.font().v_metrics(scale).ascent;
+    let char_starts: Vec<usize> = text
+        .char_indices()
+        .map(|(start, _)| start)
+        .collect();
+
+    let mut glyphs = Vec::with_capacity(text.len());
+    let mut x = 0.0;
+    for segment in visual_segments(font, text) {
+        let source = segment.font;
+        // rustybuzz는 글꼴 단위로 값을 주므로 rusttype과 같은 픽셀 배율로 바꿉니다.
+        let v_metrics = source.font().v_metrics_unscaled();
+        let units = scale.x / (v_metrics.ascent - v_metrics.descent);
+
+        let mut buffer = UnicodeBuffer::new();
+        buffer.push_str(&text[segment.range.clone()]);
+        buffer.set_direction(if segment.rtl { Direction::RightToLeft } else { Direction::LeftToRight });
+        buffer.guess_segment_properties();
+        let output = rustybuzz::shape(source.face(), &[], buffer);
+
+        for (info, position) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+            let advance = (position.x_advance as f32) * units;
+            let offset = point(
+                x + (position.x_offset as f32) * units,
+                baseline - (position.y_offset as f32) * units
+            );
+            let byte = segment.range.start + (info.cluster as usize);
+            glyphs.push(FontGlyph {
+                font_id: source.id(),
+                glyph: source
+                    .font()
+                    .glyph(GlyphId(info.glyph_id as u16))
+                    .scaled(scale)
+                    .positioned(offset),
+                cluster: char_starts.partition_point(|&start| start < byte),
+                x,
+                advance,
+                rtl: segment.rtl,
+            });
+            x += advance;
+        }
+    }
+    glyphs
+}
+
+// 방향 구간(bidi run)을 화면 순서로 나열하고, 각 구간을 다시 글꼴(대체 글꼴)별로 나눕니다.
+// 오른쪽에서 왼쪽으로 쓰는 구간은 글꼴별 조각의 순서도 뒤집어야 화면 순서가 됩니다.
+fn visual_segments<'a>(font: &'a FontRenderer, text: &str) -> Vec<Segment<'a>> {
+    let bidi = BidiInfo::new(text, None);
+    let mut segments = Vec::new();
+
+    for paragraph in &bidi.paragraphs {
+        let (levels, runs) = bidi.visual_runs(paragraph, paragraph.range.clone());
+        for run in runs {
+            let rtl = levels[run.start].is_rtl();
+            let mut pieces: Vec<Segment> = Vec::new();
+            for (offset, c) in text[run.clone()].char_indices() {
+                let start = run.start + offset;
+                let end = start + c.len_utf8();
+                let source = font.font_for(c);
+                match pieces.last_mut() {
+                    Some(piece) if piece.font.id() == source.id() || c.is_whitespace() => {
+                        piece.range.end = end;
+                    }
+                    _ => pieces.push(Segment { range: start..end, font: source, rtl }),
+                }
+            }
+            if rtl {
+                pieces.reverse();
+            }
+            segments.extend(pieces);
+        }
+    }
+    segments
+}
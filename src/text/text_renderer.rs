@@ -1,23 +1,132 @@
-use super::font::FontRenderer;
+use super::font::{ FontGlyph, FontRenderer };
+use super::glyph_atlas::{ GlyphAtlas, GlyphKey, RasterizedGlyph, SUBPIXEL_STEPS };
+use super::sdf::{ rasterize_sdf_glyph, SDF_BASE_SIZE };
 use crate::graphics::Shader;
-use rusttype::PositionedGlyph;
+use rusttype::{ point, PositionedGlyph };
+
+/// 아틀라스에 올라간 글리프 하나를 화면에 그리기 위한 사각형 정보
+#[derive(Clone, Copy, Debug)]
+pub struct GlyphQuad {
+    /// 글리프가 들어 있는 아틀라스 텍스처
+    pub texture_id: u32,
+    /// 화면 좌표계 기준 사각형 (x0, y0, x1, y1)
+    pub rect: [f32; 4],
+    /// 아틀라스 텍스처 기준 UV (u0, v0, u1, v1)
+    pub uv: [f32; 4],
+}
 
 pub struct TextRenderer {
     font_renderer: FontRenderer,
     shader: Shader,
+    atlas: GlyphAtlas,
+    sdf_atlas: GlyphAtlas, // 크기와 상관없이 공유하는 거리 필드 글리프
+    vao: u32,
+    vbo: u32,
+    ebo: u32,
 }
 
 impl TextRenderer {
     pub fn new(font_data: Vec<u8>) -> Self {
+        let (mut vao, mut vbo, mut ebo) = (0, 0, 0);
+        unsafe {
+            gl::GenVertexArrays(1, &mut vao);
+            gl::GenBuffers(1, &mut vbo);
+            gl::GenBuffers(1, &mut ebo);
+
+            gl::BindVertexArray(vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
+
+            // 정점 속성: location 0 = position (vec3), location 1 = tex coord (vec2)
+            gl::VertexAttribPointer(
+                0,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                (5 * std::mem::size_of::<f32>()) as i32,
+                std::ptr::null()
+            );
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(
+                1,
+                2,
+                gl::FLOAT,
+                gl::FALSE,
+                (5 * std::mem::size_of::<f32>()) as i32,
+                (3 * std::mem::size_of::<f32>()) as *const _
+            );
+            gl::EnableVertexAttribArray(1);
+        }
+
         Self {
             font_renderer: FontRenderer::new(font_data),
             shader: Shader::new(
                 include_str!("shaders/text.vert"),
                 include_str!("shaders/text.frag")
             ),
+            atlas: GlyphAtlas::new(),
+            sdf_atlas: GlyphAtlas::new(),
+            vao,
+            vbo,
+            ebo,
         }
     }
 
+    /// 프레임 시작 시 호출합니다. 글리프 아틀라스의 LRU 기준 프레임을 갱신합니다.
+    pub fn begin_frame(&mut self) {
+        self.atlas.begin_frame();
+        self.sdf_atlas.begin_frame();
+    }
+
+    /// 문자열을 배치하고 각 글리프를 아틀라스에 캐시한 뒤, 화면에 그릴 사각형 목록을 반환합니다.
+    ///
+    /// (x, y)는 텍스트 영역의 좌상단이며, 공백처럼 픽셀이 없는 글리프는 결과에 포함되지 않습니다.
+    pub fn layout_quads(&mut self, text: &str, x: f32, y: f32, scale: f32) -> Vec<GlyphQuad> {
+        let glyphs = self.font_renderer.layout_glyphs(text, scale);
+        self.cache_glyphs(&glyphs, x, y)
+    }
+
+    /// `layout_quads`와 같지만 기본 글꼴 대신 `font`(위젯이 지정한 글꼴)로 배치합니다.
+    pub fn layout_quads_with_font(
+        &mut self,
+        font: &FontRenderer,
+        text: &str,
+        x: f32,
+        y: f32,
+        scale: f32
+    ) -> Vec<GlyphQuad> {
+        let glyphs = font.layout_glyphs(text, scale);
+        self.cache_glyphs(&glyphs, x, y)
+    }
+
+    /// `layout_quads_with_font`와 같지만 거리 필드(SDF) 아틀라스의 글리프를 사용합니다.
+    ///
+    /// 글리프는 `SDF_BASE_SIZE`로 한 번만 만들어지고 `scale`에 맞춰 사각형만 늘어나므로,
+    /// 크기가 계속 바뀌는 텍스트도 다시 래스터화하지 않습니다. 결과는 `sdf.frag`로 그려야 합니다.
+    pub fn layout_sdf_quads(
+        &mut self,
+        font: &FontRenderer,
+        text: &str,
+        x: f32,
+        y: f32,
+        scale: f32
+    ) -> Vec<GlyphQuad> {
+        let glyphs = font.layout_glyphs(text, scale);
+        glyphs
+            .iter()
+            .filter_map(|g| self.cache_sdf_glyph(g.font_id, &g.glyph, x, y))
+            .collect()
+    }
+
+    fn cache_glyphs(&mut self, glyphs: &[FontGlyph], x: f32, y: f32) -> Vec<GlyphQuad> {
+        glyphs
+            .iter()
+            .filter_map(|g| self.cache_glyph(g.font_id, &g.glyph, x, y))
+            .collect()
+    }
+
+    /// 문자열 전체를 아틀라스 페이지당 한 번의 드로우 콜로 그립니다.
+    #[allow(clippy::too_many_arguments)]
     pub fn render_text(
         &mut self,
         text: &str,
@@ -28,175 +137,219 @@ impl TextRenderer {
         screen_height: f32,
         color: [f32; 4]
     ) {
+        let quads = self.layout_quads(text, x, y, scale);
+        if quads.is_empty() {
+            return;
+        }
+
         self.shader.use_program();
         self.shader.set_vec4("textColor", &color);
 
-        let glyphs = self.font_renderer.render_text(text, scale);
-        for glyph in glyphs.iter() {
-            if let Some(bb) = glyph.pixel_bounding_box() {
-                // 글리프의 위치에 x, y 오프셋을 더합니다
-                let adjusted_bb = rusttype::Rect {
-                    min: rusttype::Point {
-                        x: bb.min.x + (x as i32),
-                        y: bb.min.y + (y as i32),
-                    },
-                    max: rusttype::Point {
-                        x: bb.max.x + (x as i32),
-                        y: bb.max.y + (y as i32),
-                    },
-                };
-                self.draw_glyph_with_bb(glyph, &adjusted_bb, screen_width, screen_height);
+        // 같은 아틀라스 페이지를 쓰는 글리프끼리 묶어서 그립니다.
+        let mut textures: Vec<u32> = quads
+            .iter()
+            .map(|quad| quad.texture_id)
+            .collect();
+        textures.sort_unstable();
+        textures.dedup();
+
+        for texture_id in textures {
+            let mut vertices: Vec<f32> = Vec::with_capacity(quads.len() * 20);
+            let mut indices: Vec<u32> = Vec::with_capacity(quads.len() * 6);
+
+            for quad in quads.iter().filter(|quad| quad.texture_id == texture_id) {
+                // OpenGL의 좌표계는 정규화 장치 좌표(NDC, -1 ~ 1)입니다.
+                let ndc_x0 = (quad.rect[0] / screen_width) * 2.0 - 1.0;
+                let ndc_y0 = 1.0 - (quad.rect[1] / screen_height) * 2.0;
+                let ndc_x1 = (quad.rect[2] / screen_width) * 2.0 - 1.0;
+                let ndc_y1 = 1.0 - (quad.rect[3] / screen_height) * 2.0;
+                let [u0, v0, u1, v1] = quad.uv;
+
+                let base = (vertices.len() / 5) as u32;
+                vertices.extend_from_slice(
+                    &[
+                        // 위치(x, y, z)         // 텍스처 좌표 (u, v)
+                        ndc_x0,
+                        ndc_y1,
+                        0.0,
+                        u0,
+                        v1, // 좌측 하단
+                        ndc_x1,
+                        ndc_y1,
+                        0.0,
+                        u1,
+                        v1, // 우측 하단
+                        ndc_x1,
+                        ndc_y0,
+                        0.0,
+                        u1,
+                        v0, // 우측 상단
+                        ndc_x0,
+                        ndc_y0,
+                        0.0,
+                        u0,
+                        v0, // 좌측 상단
+                    ]
+                );
+                indices.extend_from_slice(&[base, base + 1, base + 2, base + 2, base + 3, base]);
+            }
+
+            unsafe {
+                gl::BindVertexArray(self.vao);
+
+                gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+                gl::BufferData(
+                    gl::ARRAY_BUFFER,
+                    (vertices.len() * std::mem::size_of::<f32>()) as isize,
+                    vertices.as_ptr() as *const _,
+                    gl::STREAM_DRAW
+                );
+
+                gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.ebo);
+                gl::BufferData(
+                    gl::ELEMENT_ARRAY_BUFFER,
+                    (indices.len() * std::mem::size_of::<u32>()) as isize,
+                    indices.as_ptr() as *const _,
+                    gl::STREAM_DRAW
+                );
+
+                gl::ActiveTexture(gl::TEXTURE0);
+                gl::BindTexture(gl::TEXTURE_2D, texture_id);
+                gl::DrawElements(
+                    gl::TRIANGLES,
+                    indices.len() as i32,
+                    gl::UNSIGNED_INT,
+                    std::ptr::null()
+                );
             }
         }
     }
 
-    /// 주어진 glyph를 래스터화하여 OpenGL 텍스처를 생성하고, 해당 glyph의 사각형(quad)을 그리는 함수.
+    /// 글리프를 아틀라스에서 찾거나 래스터화해서 올린 뒤, 화면 좌표의 사각형을 계산합니다.
     ///
-    /// - `font_renderer`: 폰트 데이터를 관리하는 객체 (래스터화 전에 glyph의 배치 정보가 필요함)
-    /// - `glyph`: 렌더링할 개별 글리프 (rusttype::PositionedGlyph)
-    /// - `screen_width`, `screen_height`: 현재 화면의 픽셀 크기
-    fn draw_glyph_with_bb(
-        &self,
-        glyph: &PositionedGlyph<'_>,
-        bb: &rusttype::Rect<i32>,
-        screen_width: f32,
-        screen_height: f32
-    ) {
-        let width = bb.width() as usize;
-        let height = bb.height() as usize;
+    /// 가로 위치는 `SUBPIXEL_STEPS` 단계로 양자화하여 같은 글리프라도 위치에 따라 따로 캐시합니다.
+    fn cache_glyph(
+        &mut self,
+        font_id: usize,
+        glyph: &PositionedGlyph<'static>,
+        x: f32,
+        y: f32
+    ) -> Option<GlyphQuad> {
+        let position = glyph.position();
+        let pen_x = x + position.x;
+        let mut pixel_x = pen_x.floor();
+        let mut subpixel = ((pen_x - pixel_x) * (SUBPIXEL_STEPS as f32)).round() as u8;
+        if subpixel >= SUBPIXEL_STEPS {
+            pixel_x += 1.0;
+            subpixel = 0;
+        }
+        let pixel_y = (y + position.y).round();
 
-        // 픽셀 데이터를 저장할 버퍼 (단일 채널, 8비트)
-        let mut pixel_data = vec![0u8; width * height];
+        let key = GlyphKey {
+            font_id,
+            glyph_id: glyph.id().0,
+            size: glyph.scale().x.to_bits(),
+            subpixel,
+        };
+        let entry = self.atlas.get_or_insert(key, || rasterize_glyph(glyph, subpixel));
+        if entry.is_empty() {
+            return None;
+        }
 
-        // glyph.draw() 콜백을 통해 각 픽셀의 커버리지 값을 0.0~1.0 범위의 f32로 받아 0~255로 변환합니다.
-        glyph.draw(|x, y, v| {
-            let idx = (y as usize) * width + (x as usize);
-            pixel_data[idx] = (v * 255.0) as u8;
-        });
+        let x0 = pixel_x + (entry.offset_x as f32);
+        let y0 = pixel_y + (entry.offset_y as f32);
 
-        // OpenGL 텍스처 생성 및 업로드
-        let mut tex_id: u32 = 0;
-        unsafe {
-            gl::GenTextures(1, &mut tex_id);
-
-            gl::BindTexture(gl::TEXTURE_2D, tex_id);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as i32);
-            // 1바이트 정렬 보장을 위해 설정 (픽셀 데이터가 1바이트 단위이므로)
-            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
-            gl::TexImage2D(
-                gl::TEXTURE_2D,
-                0,
-                gl::RED as i32,
-                width as i32,
-                height as i32,
-                0,
-                gl::RED,
-                gl::UNSIGNED_BYTE,
-                pixel_data.as_ptr() as *const _
-            );
+        Some(GlyphQuad {
+            texture_id: self.atlas.texture_id(entry.page),
+            rect: [x0, y0, x0 + (entry.width as f32), y0 + (entry.height as f32)],
+            uv: entry.uv_rect(),
+        })
+    }
+
+    // SDF 글리프는 위치를 양자화하지 않고 기준 크기 대비 비율로 사각형을 늘립니다.
+    fn cache_sdf_glyph(
+        &mut self,
+        font_id: usize,
+        glyph: &PositionedGlyph<'static>,
+        x: f32,
+        y: f32
+    ) -> Option<GlyphQuad> {
+        let key = GlyphKey {
+            font_id,
+            glyph_id: glyph.id().0,
+            size: SDF_BASE_SIZE.to_bits(),
+            subpixel: 0,
+        };
+        let entry = self.sdf_atlas.get_or_insert(key, || rasterize_sdf_glyph(glyph));
+        if entry.is_empty() {
+            return None;
         }
 
-        let x0 = bb.min.x as f32;
-        let y0 = bb.min.y as f32;
-        let x1 = bb.max.x as f32;
-        let y1 = bb.max.y as f32;
-
-        // OpenGL의 좌표계는 정규화 장치 좌표(NDC, -1 ~ 1)입니다.
-        let ndc_x0 = (x0 / screen_width) * 2.0 - 1.0;
-        let ndc_y0 = 1.0 - (y0 / screen_height) * 2.0;
-        let ndc_x1 = (x1 / screen_width) * 2.0 - 1.0;
-        let ndc_y1 = 1.0 - (y1 / screen_height) * 2.0;
-
-        // 사각형의 정점 데이터: 각 정점에 3차원 위치와 2차원 텍스처 좌표 (총 5개 요소)
-        let vertices: [f32; 20] = [
-            // 위치(x, y, z)         // 텍스처 좌표 (u, v)
-            ndc_x0,
-            ndc_y1,
-            0.0,
-            0.0,
-            1.0, // 좌측 하단
-            ndc_x1,
-            ndc_y1,
-            0.0,
-            1.0,
-            1.0, // 우측 하단
-            ndc_x1,
-            ndc_y0,
-            0.0,
-            1.0,
-            0.0, // 우측 상단
-            ndc_x0,
-            ndc_y0,
-            0.0,
-            0.0,
-            0.0, // 좌측 상단
-        ];
-        // 사각형을 그리기 위한 인덱스
-        let indices: [u32; 6] = [0, 1, 2, 2, 3, 0];
-
-        // VAO, VBO, EBO를 생성하여 사각형 드로잉 준비
-        let (mut vao, mut vbo, mut ebo) = (0, 0, 0);
-        unsafe {
-            gl::GenVertexArrays(1, &mut vao);
-            gl::GenBuffers(1, &mut vbo);
-            gl::GenBuffers(1, &mut ebo);
+        let ratio = glyph.scale().x / SDF_BASE_SIZE;
+        let position = glyph.position();
+        let x0 = x + position.x + (entry.offset_x as f32) * ratio;
+        let y0 = y + position.y + (entry.offset_y as f32) * ratio;
 
-            gl::BindVertexArray(vao);
+        Some(GlyphQuad {
+            texture_id: self.sdf_atlas.texture_id(entry.page),
+            rect: [x0, y0, x0 + (entry.width as f32) * ratio, y0 + (entry.height as f32) * ratio],
+            uv: entry.uv_rect(),
+        })
+    }
 
-            // VBO 설정
-            gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                (vertices.len() * std::mem::size_of::<f32>()) as isize,
-                vertices.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
+    /// 기본 글꼴 (`Renderer::new`에 넘긴 글꼴과 대체 글꼴 체인)
+    pub fn font_renderer(&self) -> &FontRenderer {
+        &self.font_renderer
+    }
 
-            // EBO 설정
-            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
-            gl::BufferData(
-                gl::ELEMENT_ARRAY_BUFFER,
-                (indices.len() * std::mem::size_of::<u32>()) as isize,
-                indices.as_ptr() as *const _,
-                gl::STATIC_DRAW
-            );
+    /// 기본 글꼴을 바꿉니다 (글꼴 저장소의 대체 글꼴 체인이 바뀌었을 때).
+    pub fn set_font_renderer(&mut self, font_renderer: FontRenderer) {
+        self.font_renderer = font_renderer;
+    }
 
-            // 정점 속성: location 0 = position (vec3), location 1 = tex coord (vec2)
-            gl::VertexAttribPointer(
-                0,
-                3,
-                gl::FLOAT,
-                gl::FALSE,
-                (5 * std::mem::size_of::<f32>()) as i32,
-                std::ptr::null()
-            );
-            gl::EnableVertexAttribArray(0);
-            gl::VertexAttribPointer(
-                1,
-                2,
-                gl::FLOAT,
-                gl::FALSE,
-                (5 * std::mem::size_of::<f32>()) as i32,
-                (3 * std::mem::size_of::<f32>()) as *const _
-            );
-            gl::EnableVertexAttribArray(1);
+    pub fn glyph_atlas(&self) -> &GlyphAtlas {
+        &self.atlas
+    }
 
-            // 드로우 호출: 텍스처가 활성화된 상태에서 사각형을 그립니다.
-            gl::BindTexture(gl::TEXTURE_2D, tex_id);
-            gl::BindVertexArray(vao);
-            gl::DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, std::ptr::null());
+    /// `TextRenderMode::Sdf`로 그린 글리프의 거리 필드 아틀라스
+    pub fn sdf_atlas(&self) -> &GlyphAtlas {
+        &self.sdf_atlas
+    }
+}
 
-            gl::DeleteBuffers(1, &vbo);
-            gl::DeleteBuffers(1, &ebo);
-            gl::DeleteVertexArrays(1, &vao);
-            gl::DeleteTextures(1, &tex_id);
+impl Drop for TextRenderer {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteBuffers(1, &self.ebo);
+            gl::DeleteVertexArrays(1, &self.vao);
         }
     }
+}
 
-    pub fn font_renderer(&self) -> &FontRenderer {
-        &self.font_renderer
-    }
+/// 글리프를 원점 기준 `subpixel / SUBPIXEL_STEPS` 픽셀만큼 밀어서 커버리지 비트맵으로 래스터화합니다.
+fn rasterize_glyph(glyph: &PositionedGlyph<'static>, subpixel: u8) -> Option<RasterizedGlyph> {
+    let offset = (subpixel as f32) / (SUBPIXEL_STEPS as f32);
+    let glyph = glyph.unpositioned().clone().positioned(point(offset, 0.0));
+    let bb = glyph.pixel_bounding_box()?;
+
+    let width = bb.width();
+    let height = bb.height();
+
+    // 픽셀 데이터를 저장할 버퍼 (단일 채널, 8비트)
+    let mut pixels = vec![0u8; (width * height) as usize];
+
+    // glyph.draw() 콜백을 통해 각 픽셀의 커버리지 값을 0.0~1.0 범위의 f32로 받아 0~255로 변환합니다.
+    glyph.draw(|x, y, v| {
+        let idx = (y as usize) * (width as usize) + (x as usize);
+        pixels[idx] = (v * 255.0) as u8;
+    });
+
+    Some(RasterizedGlyph {
+        width,
+        height,
+        offset_x: bb.min.x,
+        offset_y: bb.min.y,
+        pixels,
+    })
 }
@@ -0,0 +1,72 @@
+/// 가변 글꼴 축 하나의 값 (예: `wght` = 700).
+///
+/// 축 태그는 OpenType의 4글자 태그입니다. 등록 축(`wght`, `wdth`, `slnt`, `ital`)은 생성 함수가 있으며,
+/// 글꼴이 직접 정의한 축(예: `opsz`, `GRAD`)은 `FontVariation::new`로 지정합니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontVariation {
+    pub axis: [u8; 4],
+    pub value: f32,
+}
+
+impl FontVariation {
+    /// 굵기 (보통 100 ~ 900)
+    pub const WEIGHT: [u8; 4] = *b"wght";
+    /// 너비 (보통 50 ~ 200, 100이 기본 너비의 퍼센트)
+    pub const WIDTH: [u8; 4] = *b"wdth";
+    /// 기울기 각도 (도, 음수가 오른쪽으로 기울어짐)
+    pub const SLANT: [u8; 4] = *b"slnt";
+    /// 이탤릭 (0 또는 1)
+    pub const ITALIC: [u8; 4] = *b"ital";
+
+    /// 4글자보다 짧은 태그는 공백으로 채우고, 긴 태그는 앞 4글자만 사용합니다.
+    pub fn new(axis: &str, value: f32) -> Self {
+        let mut tag = *b"    ";
+        for (slot, byte) in tag.iter_mut().zip(axis.bytes()) {
+            *slot = byte;
+        }
+        Self { axis: tag, value }
+    }
+
+    pub fn weight(value: f32) -> Self {
+        Self { axis: Self::WEIGHT, value }
+    }
+
+    pub fn width(value: f32) -> Self {
+        Self { axis: Self::WIDTH, value }
+    }
+
+    pub fn slant(value: f32) -> Self {
+        Self { axis: Self::SLANT, value }
+    }
+
+    pub fn italic(value: f32) -> Self {
+        Self { axis: Self::ITALIC, value }
+    }
+
+    /// 축 태그 문자열 (예: "wght")
+    pub fn axis_name(&self) -> String {
+        String::from_utf8_lossy(&self.axis).into_owned()
+    }
+}
+
+/// 가변 글꼴이 제공하는 축과 값의 범위
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VariationAxis {
+    pub axis: [u8; 4],
+    pub min_value: f32,
+    pub default_value: f32,
+    pub max_value: f32,
+    /// 글꼴이 사용자 인터페이스에 보이지 않도록 표시한 축
+    pub hidden: bool,
+}
+
+impl VariationAxis {
+    pub fn axis_name(&self) -> String {
+        String::from_utf8_lossy(&self.axis).into_owned()
+    }
+
+    /// 값을 축 범위로 제한합니다.
+    pub fn clamp(&self, value: f32) -> f32 {
+        value.clamp(self.min_value, self.max_value)
+    }
+}
@@ -1,10 +1,18 @@
 pub mod widgets;
 
 use crate::animation::animation::{ Vec2Animation, FadeAnimation };
-use crate::graphics::Renderer;
+use crate::event::{ EventPhase, Key, Modifiers, MouseButton, UiEvent };
+use crate::graphics::{ Renderer, Transform };
 use crate::style::color::Color;
 use nalgebra_glm as glm;
 
+/// 텍스트 변경/제출 콜백 (현재 텍스트를 인자로 받음)
+pub type TextHandler = Box<dyn FnMut(&str) + 'static>;
+
+/// 모든 위젯이 구현하는 공통 트레이트.
+///
+/// 제네릭 메서드가 없어 `Vec<Box<dyn Widget>>`처럼 서로 다른 위젯을 한 컬렉션에 담아
+/// 동일하게 다룰 수 있습니다. 클로저를 받는 편의 메서드는 [`WidgetExt`]에 있습니다.
 pub trait Widget {
     // 위젯 정보 반환
     fn get_position(&self) -> (f32, f32);
@@ -23,16 +31,81 @@ pub trait Widget {
     fn position(&self) -> (f32, f32);
     fn size(&self) -> (f32, f32);
 
-    // 클릭 이벤트 핸들러 설정
-    fn set_on_click<F>(&mut self, _callback: F) where F: FnMut() + 'static {
+    // 클릭 이벤트 핸들러 설정 (박싱된 콜백)
+    fn set_on_click_boxed(&mut self, _callback: Box<dyn FnMut() + 'static>) {
         // 기본 구현은 아무것도 하지 않음
     }
 
-    // hover 이벤트 핸들러 설정
-    fn set_on_hover<F>(&mut self, _callback: F) where F: FnMut(bool) + 'static {
+    // hover 이벤트 핸들러 설정 (박싱된 콜백)
+    fn set_on_hover_boxed(&mut self, _callback: Box<dyn FnMut(bool) + 'static>) {
         // 기본 구현은 아무것도 하지 않음
     }
 
+    // hover 상태 변경 (상태가 바뀌면 hover 콜백 호출)
+    fn set_hovered(&mut self, _hovered: bool) {}
+
+    // 마우스 위치에 따라 hover 상태 업데이트
+    fn update_hover(&mut self, x: f32, y: f32) {
+        let hovered = self.contains_point(x, y);
+        self.set_hovered(hovered);
+    }
+
+    // `EventDispatcher`가 전달하는 UI 이벤트 처리. 이벤트를 처리했으면 true를 반환하여 전파를 멈춥니다.
+    // 기본 구현은 대상(Target) 단계에서만 hover와 왼쪽 버튼 누름/뗌을 기존 메서드로 연결합니다.
+    fn handle_event(&mut self, event: &UiEvent, phase: EventPhase) -> bool {
+        if phase != EventPhase::Target {
+            return false;
+        }
+
+        match *event {
+            UiEvent::PointerEnter { .. } => {
+                self.set_hovered(true);
+                false
+            }
+            UiEvent::PointerLeave { .. } => {
+                self.set_hovered(false);
+                false
+            }
+            UiEvent::PointerDown { x, y, button: MouseButton::Left } => self.on_mouse_press(x, y),
+            UiEvent::PointerUp { x, y, button: MouseButton::Left } => self.on_mouse_release(x, y),
+            UiEvent::KeyDown { key, modifiers } => self.on_key_down(key, modifiers),
+            UiEvent::KeyUp { key, modifiers } => self.on_key_up(key, modifiers),
+            UiEvent::Char { character } => self.on_char(character),
+            _ => false,
+        }
+    }
+
+    // 키보드 포커스를 받을 수 있는지 (Tab 이동과 클릭 포커스 대상)
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    // Tab 이동 순서. 값이 작은 위젯부터, 같으면 트리 순서대로 포커스를 받습니다.
+    fn tab_index(&self) -> i32 {
+        0
+    }
+
+    fn is_focused(&self) -> bool {
+        false
+    }
+
+    // 포커스 상태 변경 (`EventDispatcher`가 호출)
+    fn set_focused(&mut self, _focused: bool) {}
+
+    // 포커스된 위젯의 키 입력 처리. 처리했으면 true를 반환합니다.
+    fn on_key_down(&mut self, _key: Key, _modifiers: Modifiers) -> bool {
+        false
+    }
+
+    fn on_key_up(&mut self, _key: Key, _modifiers: Modifiers) -> bool {
+        false
+    }
+
+    // 포커스된 위젯의 문자 입력 처리
+    fn on_char(&mut self, _character: char) -> bool {
+        false
+    }
+
     // 새로운 마우스 이벤트 처리 메서드들
     fn on_mouse_press(&mut self, _x: f32, _y: f32) -> bool {
         false // 기본적으로는 이벤트를 처리하지 않음
@@ -42,14 +115,74 @@ pub trait Widget {
         false
     }
 
-    // 마우스 포인터가 위젯 영역 내에 있는지 확인하는 헬퍼 메서드
+    // 위젯 중심을 기준으로 적용할 2D 변환 (회전, 크기, 기울이기). 기본값은 변환 없음
+    fn transform(&self) -> Transform {
+        Transform::IDENTITY
+    }
+
+    fn set_transform(&mut self, _transform: Transform) {}
+
+    // 부모 좌표계에서 위젯을 그릴 때 적용하는 변환 (`transform`을 위젯 중심 기준으로 적용)
+    fn transform_matrix(&self) -> Transform {
+        let transform = self.transform();
+        if transform.is_identity() {
+            return transform;
+        }
+        let (x, y) = self.position();
+        let (width, height) = self.size();
+        let (center_x, center_y) = (x + width / 2.0, y + height / 2.0);
+        Transform::translation(center_x, center_y).multiply(&transform).translate(-center_x, -center_y)
+    }
+
+    // 부모 좌표계의 점을 변환하기 전의 위치로 되돌립니다 (위치/크기와 같은 좌표계).
+    // 되돌릴 수 없는 변환(크기 0)이면 어떤 영역에도 속하지 않는 점을 반환합니다.
+    fn to_local(&self, x: f32, y: f32) -> (f32, f32) {
+        let transform = self.transform_matrix();
+        if transform.is_identity() {
+            return (x, y);
+        }
+        transform
+            .inverse()
+            .map_or((f32::NAN, f32::NAN), |inverse| inverse.apply(x, y))
+    }
+
+    // 마우스 포인터가 위젯 영역 내에 있는지 확인하는 헬퍼 메서드 (위젯 변환을 되돌려서 비교)
     fn contains_point(&self, x: f32, y: f32) -> bool {
+        let (x, y) = self.to_local(x, y);
         let (widget_x, widget_y) = self.position();
         let (width, height) = self.size();
 
         x >= widget_x && x <= widget_x + width && y >= widget_y && y <= widget_y + height
     }
 
+    // 히트 테스트 대상인지 확인 (기본값은 위젯 영역 포함 여부)
+    fn hit_test(&self, x: f32, y: f32) -> bool {
+        self.contains_point(x, y)
+    }
+
+    // 자식 위젯 목록 (컨테이너가 아니면 비어 있음)
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut []
+    }
+
+    // 자식 위젯 좌표의 기준점 (부모 좌표계). 자식의 위치는 이 점에 대한 상대 좌표입니다.
+    fn children_origin(&self) -> (f32, f32) {
+        self.position()
+    }
+
+    // 레이아웃이 기본 크기로 사용하는 콘텐츠 크기 (텍스트 크기 + 여백 등).
+    // None이면 부모 레이아웃은 위젯이 추가될 때의 크기를 기본 크기로 사용합니다.
+    fn intrinsic_size(&self) -> Option<(f32, f32)> {
+        None
+    }
+
+    // 자식 위젯의 위치와 크기를 다시 계산 (레이아웃을 가진 컨테이너만 동작)
+    fn perform_layout(&mut self) {}
+
     fn animate_position(&mut self, target_x: f32, target_y: f32, duration: f32) {
         let current_pos = self.position();
         let start = glm::vec2(current_pos.0, current_pos.1);
@@ -65,6 +198,14 @@ pub trait Widget {
     fn set_fade_animation(&mut self, animation: FadeAnimation);
     fn update_animations(&mut self, delta_time: f32);
 
+    fn has_fade_animation(&self) -> bool {
+        false
+    }
+
+    fn has_position_animation(&self) -> bool {
+        false
+    }
+
     fn resize_by_window_size(&mut self, scale_x: f32, scale_y: f32) {
         let (old_width, old_height) = self.size();
         let (old_x, old_y) = self.position();
@@ -79,3 +220,84 @@ pub trait Widget {
         self.set_size(relative_width, relative_height);
     }
 }
+
+/// (x, y)에서 가장 위에 있는(가장 나중에 그려지는) 위젯을 찾아 트리 경로를 반환합니다.
+///
+/// 경로는 `widgets`부터 시작하는 자식 인덱스 목록이며, 좌표는 `widgets`가 속한 좌표계 기준입니다.
+/// 자식 위젯이 맞으면 자식을, 아니면 위젯 자신을 반환합니다. 변환된 위젯은 역변환한 좌표로 검사합니다.
+pub fn hit_test_path(widgets: &[Box<dyn Widget>], x: f32, y: f32) -> Option<Vec<usize>> {
+    for (index, widget) in widgets.iter().enumerate().rev() {
+        if !widget.hit_test(x, y) {
+            continue;
+        }
+
+        let (local_x, local_y) = widget.to_local(x, y);
+        let (origin_x, origin_y) = widget.children_origin();
+        let mut path = vec![index];
+        if let Some(child_path) = hit_test_path(widget.children(), local_x - origin_x, local_y - origin_y) {
+            path.extend(child_path);
+        }
+        return Some(path);
+    }
+    None
+}
+
+/// 포커스를 받을 수 있는 위젯의 경로를 Tab 이동 순서대로 반환합니다.
+///
+/// 트리를 깊이 우선(부모 먼저)으로 순회한 순서를 `tab_index`로 안정 정렬합니다.
+pub fn focusable_paths(widgets: &[Box<dyn Widget>]) -> Vec<Vec<usize>> {
+    fn collect(
+        widgets: &[Box<dyn Widget>],
+        prefix: &mut Vec<usize>,
+        out: &mut Vec<(i32, Vec<usize>)>
+    ) {
+        for (index, widget) in widgets.iter().enumerate() {
+            prefix.push(index);
+            if widget.is_focusable() {
+                out.push((widget.tab_index(), prefix.clone()));
+            }
+            collect(widget.children(), prefix, out);
+            prefix.pop();
+        }
+    }
+
+    let mut paths = Vec::new();
+    collect(widgets, &mut Vec::new(), &mut paths);
+    paths.sort_by_key(|(tab_index, _)| *tab_index);
+    paths
+        .into_iter()
+        .map(|(_, path)| path)
+        .collect()
+}
+
+/// 트리 경로에 해당하는 위젯을 반환합니다.
+pub fn widget_at_path<'a>(
+    widgets: &'a mut [Box<dyn Widget>],
+    path: &[usize]
+) -> Option<&'a mut Box<dyn Widget>> {
+    let (first, rest) = path.split_first()?;
+    let widget = widgets.get_mut(*first)?;
+    if rest.is_empty() {
+        Some(widget)
+    } else {
+        widget_at_path(widget.children_mut(), rest)
+    }
+}
+
+/// 클로저를 직접 받는 `Widget` 확장 메서드.
+///
+/// 제네릭 메서드는 트레이트 객체에서 호출할 수 없으므로 `Widget`과 분리되어 있으며,
+/// `Box<dyn Widget>`을 포함한 모든 위젯에 자동으로 구현됩니다.
+pub trait WidgetExt: Widget {
+    // 클릭 이벤트 핸들러 설정
+    fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
+        self.set_on_click_boxed(Box::new(callback));
+    }
+
+    // hover 이벤트 핸들러 설정
+    fn set_on_hover<F>(&mut self, callback: F) where F: FnMut(bool) + 'static {
+        self.set_on_hover_boxed(Box::new(callback));
+    }
+}
+
+impl<W: Widget + ?Sized> WidgetExt for W {}
@@ -1,25 +1,34 @@
 use crate::animation::animation::{ Animation, Vec2Animation, FadeAnimation };
+use crate::event::{ Key, Modifiers };
 use crate::widget::Widget;
-use crate::graphics::Renderer;
+use crate::graphics::{ BoxShadow, CornerRadius, LayerEffect, Paint, Renderer, Transform };
 use crate::style::color::Color;
+use crate::text::{ FontQuery, FontRenderer, FontVariation, FontWeight, TextEffect };
 use log::{ debug, info };
-use std::sync::Arc;
-use std::cell::RefCell;
 
 pub struct Button {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
+    intrinsic_size: (f32, f32), // 텍스트 크기로 계산한 기본 크기 (레이아웃에서 사용)
     text: String,
+    font: FontRenderer,
+    font_query: FontQuery, // 굵기만 바꿀 때 다시 찾을 글꼴 패밀리와 스타일
     font_size: f32,
+    text_effect: Option<TextEffect>, // 지정하면 텍스트를 SDF로 그리고 외곽선/글로우/그림자를 적용
     background_color: Color,
+    background_paint: Option<Paint>, // 기본 상태의 배경을 그라디언트 등으로 채울 때
     text_color: Color,
     border_color: Color,
     border_width: f32, // 외곽선 두께
+    corner_radius: CornerRadius, // 배경(테두리 안쪽)의 모서리 반지름
+    shadow: Option<BoxShadow>,
     padding: f32, // 텍스트와 버튼 경계 사이의 여백
     is_pressed: bool,
-    on_click: Option<Arc<RefCell<dyn FnMut() + 'static>>>,
+    is_focused: bool,
+    focusable: bool, // Tab 이동과 클릭으로 포커스를 받을지 여부
+    on_click: Option<Box<dyn FnMut() + 'static>>,
     pressed_background_color: Color,
     pressed_border_color: Color,
     pressed_text_color: Color,
@@ -27,10 +36,11 @@ pub struct Button {
     hover_background_color: Color,
     hover_border_color: Color,
     hover_text_color: Color,
-    on_hover: Option<Arc<RefCell<dyn FnMut(bool) + 'static>>>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
     position_animation: Option<Vec2Animation>,
     fade_animation: Option<FadeAnimation>,
     opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
 }
 
 impl Button {
@@ -45,14 +55,23 @@ impl Button {
             y: 0.0,
             width: 0.0,
             height: 0.0,
+            intrinsic_size: (0.0, 0.0),
             text: text.to_string(),
+            font: renderer.text_renderer().font_renderer().clone(),
+            font_query: FontQuery::default().with_weight(renderer.default_font_weight()),
             font_size: 32.0,
+            text_effect: None,
             background_color,
+            background_paint: None,
             text_color,
             border_color,
             border_width: 2.0, // 기본 테두리 두께
+            corner_radius: CornerRadius::default(),
+            shadow: None,
             padding: 10.0, // 기본 패딩
             is_pressed: false,
+            is_focused: false,
+            focusable: true,
             on_click: None,
             pressed_background_color: Color::new(0.6, 0.6, 0.6, 1.0),
             pressed_border_color: Color::new(0.2, 0.2, 0.2, 1.0),
@@ -65,21 +84,20 @@ impl Button {
             position_animation: None,
             fade_animation: None,
             opacity: 1.0,
+            transform: Transform::IDENTITY,
         };
         btn.update_size(renderer);
         info!("Button created with size: {}x{}", btn.width, btn.height);
         btn
     }
 
-    fn update_size(&mut self, renderer: &Renderer) {
-        let (text_width, text_height) = renderer
-            .text_renderer()
-            .font_renderer()
-            .calculate_text_size(&self.text, self.font_size);
+    fn update_size(&mut self, _renderer: &Renderer) {
+        let (text_width, text_height) = self.font.measure(&self.text, self.font_size).size();
 
         // 텍스트 크기에 패딩을 추가하여 버튼 크기 설정
         self.width = text_width + self.padding * 2.0;
         self.height = text_height + self.padding * 2.0;
+        self.intrinsic_size = (self.width, self.height);
         debug!("Button size updated: {}x{}", self.width, self.height);
     }
 
@@ -95,6 +113,43 @@ impl Button {
         self.update_size(renderer);
     }
 
+    /// 버튼 텍스트의 글꼴을 바꾸고 버튼 크기를 새 글꼴에 맞춥니다.
+    pub fn set_font(&mut self, query: &FontQuery, renderer: &Renderer) {
+        self.font_query = query.clone();
+        self.font = renderer.font(query);
+        self.update_size(renderer);
+    }
+
+    /// 굵기를 바꿉니다. 패밀리에 해당 굵기의 글꼴 파일이 없으면 가변 글꼴의 `wght` 축을 사용합니다.
+    pub fn set_font_weight(&mut self, weight: FontWeight, renderer: &Renderer) {
+        self.font_query.weight = weight;
+        self.font = renderer.font(&self.font_query);
+        self.update_size(renderer);
+    }
+
+    /// 가변 글꼴의 축 값을 직접 지정합니다 (예: `FontVariation::width(75.0)`).
+    pub fn set_font_variations(&mut self, variations: &[FontVariation], renderer: &Renderer) {
+        self.font = renderer.font(&self.font_query).with_variations(variations);
+        self.update_size(renderer);
+    }
+
+    /// 텍스트에 외곽선, 글로우, 그림자 효과를 적용합니다. `None`이면 효과 없이 그립니다.
+    pub fn set_text_effect(&mut self, effect: Option<TextEffect>) {
+        self.text_effect = effect;
+    }
+
+    pub fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    // 클릭 이벤트 발생
+    fn activate(&mut self) {
+        debug!("Button '{}' activated", self.text);
+        if let Some(callback) = &mut self.on_click {
+            callback();
+        }
+    }
+
     pub fn set_background_color(&mut self, color: Color) {
         self.background_color = color;
     }
@@ -111,16 +166,27 @@ impl Button {
         self.border_width = width;
     }
 
+    /// 기본 상태의 배경 채우기 (예: `Paint::vertical_gradient`). 호버/누름 상태는 각 상태의 배경색을
+    /// 사용하며, `None`이면 기본 상태도 `set_background_color`의 색으로 돌아갑니다.
+    pub fn set_background_paint(&mut self, paint: Option<Paint>) {
+        self.background_paint = paint;
+    }
+
+    /// 배경의 모서리 반지름. 테두리는 바깥쪽으로 `border_width`만큼 더 둥글게 그려집니다.
+    pub fn set_corner_radius(&mut self, radius: impl Into<CornerRadius>) {
+        self.corner_radius = radius.into();
+    }
+
+    /// 테두리를 포함한 버튼 아래에 그림자를 그립니다. `None`이면 그림자를 없앱니다.
+    pub fn set_shadow(&mut self, shadow: Option<BoxShadow>) {
+        self.shadow = shadow;
+    }
+
     pub fn set_padding(&mut self, padding: f32, renderer: &Renderer) {
         self.padding = padding;
         self.update_size(renderer);
     }
 
-    // 클릭 이벤트 핸들러 설정
-    pub fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
-        self.on_click = Some(Arc::new(RefCell::new(callback)));
-    }
-
     // pressed 상태의 배경색 설정
     pub fn set_pressed_background_color(&mut self, color: Color) {
         self.pressed_background_color = color;
@@ -150,28 +216,6 @@ impl Button {
     pub fn set_hover_text_color(&mut self, color: Color) {
         self.hover_text_color = color;
     }
-
-    // hover 이벤트 핸들러 설정
-    pub fn set_on_hover<F>(&mut self, callback: F) where F: FnMut(bool) + 'static {
-        self.on_hover = Some(Arc::new(RefCell::new(callback)));
-    }
-
-    // hover 상태 업데이트
-    pub fn update_hover(&mut self, x: f32, y: f32) {
-        let was_hovered = self.is_hovered;
-        self.is_hovered = self.contains_point(x, y);
-
-        // hover 상태가 변경되었을 때만 콜백 호출
-        if was_hovered != self.is_hovered {
-            if let Some(callback) = &self.on_hover {
-                callback.borrow_mut()(self.is_hovered);
-            }
-        }
-    }
-
-    pub fn has_fade_animation(&self) -> bool {
-        self.fade_animation.is_some()
-    }
 }
 
 impl Widget for Button {
@@ -211,7 +255,20 @@ impl Widget for Button {
         self.is_pressed
     }
 
-    fn draw(&self, renderer: &mut Renderer, screen_width: f32, screen_height: f32) {
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+        renderer.push_transform(&self.transform_matrix());
+
         let (bg, border, text) = if self.is_pressed {
             (self.pressed_background_color, self.pressed_border_color, self.pressed_text_color)
         } else if self.is_hovered {
@@ -220,43 +277,101 @@ impl Widget for Button {
             (self.background_color, self.border_color, self.text_color)
         };
 
-        let current_background = bg * self.opacity;
-        let current_border = border * self.opacity;
-        let current_text = text * self.opacity;
-
-        // 렌더링...
-        if self.border_width > 0.0 {
-            renderer.draw_rect(
-                self.x - self.border_width,
-                self.y - self.border_width,
-                self.width + self.border_width * 2.0,
-                self.height + self.border_width * 2.0,
-                current_border.to_array()
+        // 테두리는 버튼 영역 바깥쪽에 그리므로 테두리를 포함한 바깥 사각형을 기준으로 그립니다.
+        let border_width = self.border_width.max(0.0);
+        let (outer_x, outer_y) = (self.x - border_width, self.y - border_width);
+        let outer_width = self.width + border_width * 2.0;
+        let outer_height = self.height + border_width * 2.0;
+        let outer_radius = self.corner_radius.expanded(border_width);
+
+        // 반투명할 때는 레이어에 불투명하게 그린 뒤 한 번에 옅게 합성합니다. 배경, 테두리, 글자를
+        // 각각 옅게 그리면 겹친 부분(테두리 아래의 배경 등)이 비쳐 보이기 때문입니다.
+        let group_opacity = self.opacity < 1.0;
+        let opacity = if group_opacity {
+            // 그림자와 포커스 링(버튼 밖 5픽셀)까지 레이어에 들어가도록 넓힙니다.
+            let margin = self.shadow
+                .as_ref()
+                .map(|s| s.spread.max(0.0) + s.blur * 1.5 + s.offset_x.abs().max(s.offset_y.abs()))
+                .unwrap_or(0.0)
+                .max(5.0);
+            renderer.push_layer(
+                outer_x - margin,
+                outer_y - margin,
+                outer_width + margin * 2.0,
+                outer_height + margin * 2.0,
+                LayerEffect::opacity(self.opacity)
+            );
+            1.0
+        } else {
+            self.opacity
+        };
+
+        let current_background = bg * opacity;
+        let current_border = border * opacity;
+        let current_text = text * opacity;
+
+        if let Some(shadow) = &self.shadow {
+            renderer.draw_box_shadow(
+                outer_x,
+                outer_y,
+                outer_width,
+                outer_height,
+                outer_radius,
+                &shadow.with_opacity(opacity)
             );
         }
 
-        renderer.draw_rect(self.x, self.y, self.width, self.height, current_background.to_array());
+        let background = match &self.background_paint {
+            Some(paint) if !self.is_pressed && !self.is_hovered => paint.with_opacity(opacity),
+            _ => Paint::from(current_background),
+        };
+        renderer.draw_rounded_rect_with_border(
+            outer_x,
+            outer_y,
+            outer_width,
+            outer_height,
+            outer_radius,
+            background,
+            border_width,
+            current_border.to_array()
+        );
 
         // ... 나머지 텍스트 렌더링 코드는 current_text 사용
-        let (text_width, text_height) = renderer
-            .text_renderer()
-            .font_renderer()
-            .calculate_text_size(&self.text, self.font_size);
+        let (text_width, text_height) = self.font.measure(&self.text, self.font_size).size();
 
         let text_x = self.x + (self.width - text_width) / 2.0;
         let text_y = self.y + (self.height - text_height) / 2.0;
 
-        renderer
-            .text_renderer_mut()
-            .render_text(
-                &self.text,
-                text_x,
-                text_y,
-                self.font_size,
-                screen_width,
-                screen_height,
-                current_text.to_array()
-            );
+        match &self.text_effect {
+            Some(effect) =>
+                renderer.draw_text_with_effect(
+                    &self.font,
+                    &self.text,
+                    text_x,
+                    text_y,
+                    self.font_size,
+                    current_text.to_array(),
+                    effect
+                ),
+            None =>
+                renderer.draw_text_with_font(
+                    &self.font,
+                    &self.text,
+                    text_x,
+                    text_y,
+                    self.font_size,
+                    current_text.to_array()
+                ),
+        }
+
+        if self.is_focused {
+            renderer.draw_focus_ring(self.x, self.y, self.width, self.height);
+        }
+
+        if group_opacity {
+            renderer.pop_layer();
+        }
+        renderer.pop_transform();
     }
 
     fn set_size(&mut self, width: f32, height: f32) {
@@ -264,6 +379,10 @@ impl Widget for Button {
         self.height = height;
     }
 
+    fn intrinsic_size(&self) -> Option<(f32, f32)> {
+        Some(self.intrinsic_size)
+    }
+
     fn position(&self) -> (f32, f32) {
         (self.x, self.y)
     }
@@ -290,22 +409,76 @@ impl Widget for Button {
         if self.is_pressed {
             self.is_pressed = false;
             if self.contains_point(x, y) {
-                // 클릭 이벤트 발생
-                if let Some(callback) = &self.on_click {
-                    callback.borrow_mut()();
-                }
+                self.activate();
                 return true;
             }
         }
         false
     }
 
-    fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
-        self.on_click = Some(Arc::new(RefCell::new(callback)));
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.is_focused = focused;
+        if !focused {
+            self.is_pressed = false;
+        }
+    }
+
+    // Enter는 누를 때, Space는 눌렀다 뗄 때 클릭으로 처리
+    fn on_key_down(&mut self, key: Key, _modifiers: Modifiers) -> bool {
+        match key {
+            Key::Enter => {
+                self.activate();
+                true
+            }
+            Key::Space => {
+                self.is_pressed = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn on_key_up(&mut self, key: Key, _modifiers: Modifiers) -> bool {
+        if key == Key::Space && self.is_pressed {
+            self.is_pressed = false;
+            self.activate();
+            return true;
+        }
+        false
+    }
+
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
+    }
+
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        // hover 상태가 변경되었을 때만 콜백 호출
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
     }
 
-    fn set_on_hover<F>(&mut self, callback: F) where F: FnMut(bool) + 'static {
-        self.on_hover = Some(Arc::new(RefCell::new(callback)));
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
     }
 
     fn set_position_animation(&mut self, animation: Vec2Animation) {
@@ -0,0 +1,552 @@
+use crate::animation::animation::{ Animation, Vec2Animation, FadeAnimation };
+use crate::style::color::Color;
+use crate::event::{ EventPhase, MouseButton, UiEvent };
+use crate::widget::Widget;
+use crate::graphics::{ LayerEffect, Renderer, Transform };
+use crate::layout::{ GridPlacement, Layout, LayoutItem, LayoutParams };
+use log::debug;
+
+// 자식 하나에 대한 레이아웃 정보
+struct ChildSlot {
+    params: LayoutParams,
+    // 고유 크기가 없는 위젯(도형, 이미지 등)의 기본 크기. 추가될 때의 크기를 기억해 두어
+    // 레이아웃이 늘리거나 줄인 크기가 다음 계산의 기준이 되지 않도록 합니다.
+    base_size: (f32, f32),
+}
+
+impl ChildSlot {
+    fn new(child: &dyn Widget, params: LayoutParams) -> Self {
+        Self { params, base_size: child.size() }
+    }
+}
+
+/// 자식 위젯을 소유하고 자신의 위치를 기준으로 배치하는 컨테이너 위젯.
+///
+/// 자식의 위치는 컨테이너 좌상단에 대한 상대 좌표이므로, 컨테이너를 옮기면 자식 전체가 함께
+/// 움직입니다. 그리기, 애니메이션, 마우스 이벤트는 자식에게 재귀적으로 전달되며, 나중에 추가된
+/// 자식일수록 위에 그려지고 마우스 이벤트를 먼저 받습니다.
+///
+/// 자식은 컨테이너 영역 안으로 잘려서 그려집니다 (`set_clip_children(false)`로 끌 수 있음).
+///
+/// `set_layout`으로 레이아웃을 지정하면 `perform_layout`에서 자식의 위치와 크기를 계산하며,
+/// 창 크기가 바뀌어도 자식을 비율로 늘리지 않고 다시 배치합니다.
+pub struct Container {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    children: Vec<Box<dyn Widget>>,
+    slots: Vec<ChildSlot>, // children과 같은 순서
+    layout: Option<Layout>,
+    background_color: Color,
+    hover_background_color: Color,
+    border_color: Color,
+    border_width: f32,
+    clip_children: bool,
+    layer_effect: Option<LayerEffect>, // 지정하면 배경과 자식을 레이어에 그려 효과와 함께 합성
+    opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
+    is_hovered: bool,
+    is_pressed: bool,
+    position_animation: Option<Vec2Animation>,
+    fade_animation: Option<FadeAnimation>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
+}
+
+/// 배경과 테두리를 가진 그룹 위젯을 부를 때 쓰는 별칭
+pub type Panel = Container;
+
+/// `GridLayout`으로 자식을 배치하는 컨테이너를 부를 때 쓰는 별칭 (`Container::with_layout`으로 생성)
+pub type Grid = Container;
+
+impl Container {
+    pub fn new() -> Self {
+        debug!("Creating new Container");
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            children: Vec::new(),
+            slots: Vec::new(),
+            layout: None,
+            background_color: Color::new(0.0, 0.0, 0.0, 0.0),
+            hover_background_color: Color::new(0.0, 0.0, 0.0, 0.0),
+            border_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            border_width: 0.0,
+            clip_children: true,
+            layer_effect: None,
+            opacity: 1.0,
+            transform: Transform::IDENTITY,
+            is_hovered: false,
+            is_pressed: false,
+            position_animation: None,
+            fade_animation: None,
+            on_hover: None,
+            on_click: None,
+        }
+    }
+
+    /// 레이아웃을 지정한 컨테이너를 생성합니다.
+    pub fn with_layout(layout: impl Into<Layout>) -> Self {
+        let mut container = Self::new();
+        container.set_layout(layout);
+        container
+    }
+
+    pub fn set_background_color(&mut self, color: Color) {
+        self.background_color = color;
+    }
+
+    pub fn set_hover_background_color(&mut self, color: Color) {
+        self.hover_background_color = color;
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_border_width(&mut self, width: f32) {
+        self.border_width = width;
+    }
+
+    /// 컨테이너 영역 밖으로 나간 자식을 잘라낼지 설정합니다 (기본값 true).
+    pub fn set_clip_children(&mut self, clip: bool) {
+        self.clip_children = clip;
+    }
+
+    /// 배경, 테두리, 자식 전체를 오프스크린 레이어에 그린 뒤 `effect`(불투명도, 흐림, 채도)를 적용해
+    /// 합성합니다. 자식까지 한 장의 그림처럼 옅어지거나 흐려지며, 레이어는 테두리를 포함한 컨테이너
+    /// 영역이므로 잘라내지 않은 자식도 그 밖으로는 그려지지 않습니다. `None`이면 바로 그립니다.
+    pub fn set_layer_effect(&mut self, effect: Option<LayerEffect>) {
+        self.layer_effect = effect;
+    }
+
+    /// 자식 배치 방식을 지정합니다. 적용하려면 `perform_layout`을 호출합니다.
+    pub fn set_layout(&mut self, layout: impl Into<Layout>) {
+        self.layout = Some(layout.into());
+    }
+
+    /// 레이아웃을 해제합니다. 이후 자식은 직접 지정한 위치를 사용합니다.
+    pub fn clear_layout(&mut self) {
+        self.layout = None;
+    }
+
+    pub fn layout(&self) -> Option<&Layout> {
+        self.layout.as_ref()
+    }
+
+    /// 자식을 맨 위(마지막)에 추가하고 인덱스를 반환합니다.
+    pub fn add_child(&mut self, child: Box<dyn Widget>) -> usize {
+        self.add_child_with_params(child, LayoutParams::default())
+    }
+
+    /// 레이아웃 속성과 함께 자식을 추가하고 인덱스를 반환합니다.
+    pub fn add_child_with_params(&mut self, child: Box<dyn Widget>, params: LayoutParams) -> usize {
+        self.slots.push(ChildSlot::new(child.as_ref(), params));
+        self.children.push(child);
+        debug!("Container child added (count: {})", self.children.len());
+        self.children.len() - 1
+    }
+
+    /// 그리드의 지정한 셀에 자식을 추가하고 인덱스를 반환합니다.
+    pub fn add_child_at(&mut self, child: Box<dyn Widget>, cell: GridPlacement) -> usize {
+        self.add_child_with_params(child, LayoutParams::in_cell(cell))
+    }
+
+    /// 자식을 지정한 인덱스에 끼워 넣습니다. 인덱스가 범위를 넘으면 맨 뒤에 추가합니다.
+    pub fn insert_child(&mut self, index: usize, child: Box<dyn Widget>) {
+        let index = index.min(self.children.len());
+        self.slots.insert(index, ChildSlot::new(child.as_ref(), LayoutParams::default()));
+        self.children.insert(index, child);
+    }
+
+    /// 자식을 제거하고 소유권을 돌려줍니다.
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn Widget>> {
+        if index < self.children.len() {
+            self.slots.remove(index);
+            Some(self.children.remove(index))
+        } else {
+            None
+        }
+    }
+
+    pub fn child_params(&self, index: usize) -> Option<&LayoutParams> {
+        self.slots.get(index).map(|slot| &slot.params)
+    }
+
+    pub fn set_child_params(&mut self, index: usize, params: LayoutParams) {
+        if let Some(slot) = self.slots.get_mut(index) {
+            slot.params = params;
+        }
+    }
+
+    /// 자식의 그리기 순서를 바꿉니다. `to`가 클수록 위에 그려집니다.
+    pub fn move_child(&mut self, from: usize, to: usize) {
+        if from >= self.children.len() {
+            return;
+        }
+        let child = self.children.remove(from);
+        let slot = self.slots.remove(from);
+        let to = to.min(self.children.len());
+        self.children.insert(to, child);
+        self.slots.insert(to, slot);
+    }
+
+    /// 자식을 가장 위로 올립니다.
+    pub fn bring_to_front(&mut self, index: usize) {
+        let last = self.children.len().saturating_sub(1);
+        self.move_child(index, last);
+    }
+
+    /// 자식을 가장 아래로 내립니다.
+    pub fn send_to_back(&mut self, index: usize) {
+        self.move_child(index, 0);
+    }
+
+    pub fn child(&self, index: usize) -> Option<&dyn Widget> {
+        self.children.get(index).map(|child| child.as_ref())
+    }
+
+    pub fn child_mut(&mut self, index: usize) -> Option<&mut Box<dyn Widget>> {
+        self.children.get_mut(index)
+    }
+
+    pub fn child_count(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn clear_children(&mut self) {
+        self.children.clear();
+        self.slots.clear();
+    }
+
+    // 부모 좌표계의 점을 자식 좌표계로 바꿉니다 (컨테이너 변환을 되돌린 뒤 좌상단 기준).
+    fn child_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let (x, y) = self.to_local(x, y);
+        (x - self.x, y - self.y)
+    }
+
+    fn layout_items(&self) -> Vec<LayoutItem> {
+        self.children
+            .iter()
+            .zip(&self.slots)
+            .map(|(child, slot)| LayoutItem {
+                size: child.intrinsic_size().unwrap_or(slot.base_size),
+                params: slot.params,
+            })
+            .collect()
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for Container {
+    fn get_position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn get_background_color(&self) -> Color {
+        self.background_color
+    }
+
+    fn get_text_color(&self) -> Color {
+        Color::new(0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn get_hover_background_color(&self) -> Color {
+        self.hover_background_color
+    }
+
+    fn get_hover_text_color(&self) -> Color {
+        Color::new(0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn get_opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn get_is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    fn get_is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn draw(&self, renderer: &mut Renderer, screen_width: f32, screen_height: f32) {
+        renderer.push_transform(&self.transform_matrix());
+
+        let background =
+            (if self.is_hovered { self.hover_background_color } else { self.background_color }) *
+            self.opacity;
+        let border = self.border_color * self.opacity;
+
+        if let Some(effect) = self.layer_effect {
+            let border_width = self.border_width.max(0.0);
+            renderer.push_layer(
+                self.x - border_width,
+                self.y - border_width,
+                self.width + border_width * 2.0,
+                self.height + border_width * 2.0,
+                effect
+            );
+        }
+
+        if self.border_width > 0.0 {
+            renderer.draw_rect(
+                self.x - self.border_width,
+                self.y - self.border_width,
+                self.width + self.border_width * 2.0,
+                self.height + self.border_width * 2.0,
+                border.to_array()
+            );
+        }
+
+        if background.a > 0.0 {
+            renderer.draw_rect(self.x, self.y, self.width, self.height, background.to_array());
+        }
+
+        if self.clip_children {
+            renderer.push_clip(self.x, self.y, self.width, self.height);
+        }
+
+        // 자식은 컨테이너 좌상단 기준 상대 좌표로 그림
+        renderer.push_translation(self.x, self.y);
+        for child in self.children.iter() {
+            child.draw(renderer, screen_width, screen_height);
+        }
+        renderer.pop_translation();
+
+        if self.clip_children {
+            renderer.pop_clip();
+        }
+
+        if self.layer_effect.is_some() {
+            renderer.pop_layer();
+        }
+        renderer.pop_transform();
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut self.children
+    }
+
+    fn intrinsic_size(&self) -> Option<(f32, f32)> {
+        self.layout.as_ref().map(|layout| layout.measure(&self.layout_items()))
+    }
+
+    fn perform_layout(&mut self) {
+        if let Some(layout) = &self.layout {
+            let rects = layout.compute(self.width, self.height, &self.layout_items());
+            for (child, rect) in self.children.iter_mut().zip(rects) {
+                child.set_position(rect.x, rect.y);
+                child.set_size(rect.width, rect.height);
+            }
+        }
+
+        // 중첩된 컨테이너는 방금 정해진 크기로 다시 배치
+        for child in self.children.iter_mut() {
+            child.perform_layout();
+        }
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        let hovered = self.contains_point(x, y);
+        self.set_hovered(hovered);
+
+        let (local_x, local_y) = self.child_point(x, y);
+        for child in self.children.iter_mut() {
+            child.update_hover(local_x, local_y);
+        }
+    }
+
+    // 디스패처가 자식에게 직접 이벤트를 전달하므로, 여기서는 컨테이너 자신의 상태만 처리합니다.
+    // (`on_mouse_press`/`on_mouse_release`는 디스패처 없이 사용할 때를 위해 자식에게 재귀적으로 전달)
+    fn handle_event(&mut self, event: &UiEvent, phase: EventPhase) -> bool {
+        if phase != EventPhase::Target {
+            return false;
+        }
+
+        match *event {
+            UiEvent::PointerEnter { .. } => {
+                self.set_hovered(true);
+                false
+            }
+            UiEvent::PointerLeave { .. } => {
+                self.set_hovered(false);
+                false
+            }
+            UiEvent::PointerDown { x, y, button: MouseButton::Left } => {
+                self.is_pressed = self.contains_point(x, y);
+                self.is_pressed
+            }
+            UiEvent::PointerUp { x, y, button: MouseButton::Left } => {
+                if !self.is_pressed {
+                    return false;
+                }
+                self.is_pressed = false;
+                if self.contains_point(x, y) {
+                    if let Some(callback) = &mut self.on_click {
+                        callback();
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn on_mouse_press(&mut self, x: f32, y: f32) -> bool {
+        if !self.contains_point(x, y) {
+            return false;
+        }
+
+        // 가장 위에 있는 자식부터 전달하고, 처리한 자식이 있으면 중단
+        let (local_x, local_y) = self.child_point(x, y);
+        for child in self.children.iter_mut().rev() {
+            if child.on_mouse_press(local_x, local_y) {
+                return true;
+            }
+        }
+
+        self.is_pressed = true;
+        true
+    }
+
+    fn on_mouse_release(&mut self, x: f32, y: f32) -> bool {
+        // 눌림 상태를 정리해야 하므로 release는 모든 자식에게 전달
+        let (local_x, local_y) = self.child_point(x, y);
+        let mut handled = false;
+        for child in self.children.iter_mut().rev() {
+            handled |= child.on_mouse_release(local_x, local_y);
+        }
+
+        if self.is_pressed {
+            self.is_pressed = false;
+            if !handled && self.contains_point(x, y) {
+                if let Some(callback) = &mut self.on_click {
+                    callback();
+                }
+                return true;
+            }
+        }
+        handled
+    }
+
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
+    }
+
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
+    }
+
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
+    }
+
+    fn set_position_animation(&mut self, animation: Vec2Animation) {
+        self.position_animation = Some(animation);
+    }
+
+    fn set_fade_animation(&mut self, animation: FadeAnimation) {
+        self.fade_animation = Some(animation);
+    }
+
+    fn update_animations(&mut self, delta_time: f32) {
+        if let Some(ref mut anim) = self.position_animation {
+            anim.update(delta_time);
+            let pos = anim.value();
+            self.x = pos.x;
+            self.y = pos.y;
+
+            if anim.is_finished() {
+                self.position_animation = None;
+            }
+        }
+
+        if let Some(ref mut anim) = self.fade_animation {
+            anim.update(delta_time);
+            self.opacity = anim.value();
+
+            if anim.is_finished() {
+                self.fade_animation = None;
+            }
+        }
+
+        for child in self.children.iter_mut() {
+            child.update_animations(delta_time);
+        }
+    }
+
+    fn resize_by_window_size(&mut self, scale_x: f32, scale_y: f32) {
+        self.x *= scale_x;
+        self.y *= scale_y;
+        self.width *= scale_x;
+        self.height *= scale_y;
+
+        // 레이아웃이 있으면 자식을 늘리지 않고 바뀐 크기에 맞춰 다시 배치
+        if self.layout.is_some() {
+            self.perform_layout();
+            return;
+        }
+
+        // 자식은 상대 좌표이므로 같은 비율로 조정하면 배치가 유지됨
+        for child in self.children.iter_mut() {
+            child.resize_by_window_size(scale_x, scale_y);
+        }
+    }
+}
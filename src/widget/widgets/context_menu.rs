@@ -1,12 +1,14 @@
+use crate::animation::animation::{ Vec2Animation, FadeAnimation };
+use crate::event::{ EventPhase, MouseButton, UiEvent };
 use crate::graphics::Renderer;
 use crate::style::color::Color;
-use std::sync::Arc;
-use std::cell::RefCell;
+use crate::text::{ FontQuery, FontRenderer };
+use crate::widget::Widget;
 use log::debug;
 
 pub struct MenuItem {
     text: String,
-    on_click: Option<Arc<RefCell<dyn FnMut() + 'static>>>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
     background_color: Color,
     text_color: Color,
     hover_background_color: Color,
@@ -30,40 +32,77 @@ impl MenuItem {
 
     pub fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
         debug!("Setting click handler for menu item: {}", self.text);
-        self.on_click = Some(Arc::new(RefCell::new(callback)));
+        self.on_click = Some(Box::new(callback));
     }
 }
 
+/// 오른쪽 버튼을 누른 위치에 열리는 메뉴.
+///
+/// `Widget`으로 최상위 위젯 컬렉션의 마지막에 두고 `EventDispatcher`로 이벤트를 전달하면,
+/// 다른 곳에서 오른쪽 버튼을 누를 때 열리고 메뉴 밖을 누르면 닫힙니다.
+/// 메뉴 항목은 메뉴 안에서 누른 왼쪽 버튼을 같은 항목 위에서 뗄 때 실행됩니다.
 pub struct ContextMenu {
     x: f32,
     y: f32,
     width: f32,
     items: Vec<MenuItem>,
     visible: bool,
-    item_height: f32,
-    padding: f32,
+    is_pressed: bool,
+    item_height: f32, // 줄 높이에 위아래 여백을 더한 항목 높이
+    font: FontRenderer,
+    font_size: f32,
+    padding: f32, // 항목 텍스트와 항목 경계 사이의 여백
     border_color: Color,
     border_width: f32,
 }
 
 impl ContextMenu {
-    pub fn new() -> Self {
-        Self {
+    pub fn new(renderer: &Renderer) -> Self {
+        let mut menu = Self {
             x: 0.0,
             y: 0.0,
-            width: 200.0,
+            width: 0.0,
             items: Vec::new(),
             visible: false,
-            item_height: 30.0,
+            is_pressed: false,
+            item_height: 0.0,
+            font: renderer.text_renderer().font_renderer().clone(),
+            font_size: 20.0,
             padding: 5.0,
             border_color: Color::new(0.8, 0.8, 0.8, 1.0),
             border_width: 1.0,
-        }
+        };
+        menu.update_size();
+        menu
+    }
+
+    // 가장 긴 항목 텍스트와 글꼴의 줄 높이에 맞춰 메뉴 너비와 항목 높이를 다시 계산
+    fn update_size(&mut self) {
+        let text_width = self.items
+            .iter()
+            .map(|item| self.font.measure(&item.text, self.font_size).advance_width)
+            .fold(0.0, f32::max);
+
+        self.width = text_width + self.padding * 2.0;
+        self.item_height = self.font.line_height(self.font_size) + self.padding * 2.0;
+        debug!("Context menu size updated: {}x{}", self.width, self.total_height());
+    }
+
+    pub fn set_font_size(&mut self, size: f32) {
+        self.font_size = size;
+        self.update_size();
+    }
+
+    /// 메뉴 항목 글꼴을 패밀리/굵기/스타일로 지정합니다.
+    pub fn set_font(&mut self, query: &FontQuery, renderer: &Renderer) {
+        self.font = renderer.font(query);
+        self.update_size();
     }
 
     pub fn add_item(&mut self, item: MenuItem) {
         debug!("Adding menu item: {}", item.text);
         self.items.push(item);
+        self.update_size();
     }
 
     pub fn show(&mut self, x: f32, y: f32) {
@@ -76,14 +115,201 @@ impl ContextMenu {
     pub fn hide(&mut self) {
         debug!("Hiding context menu");
         self.visible = false;
+        self.is_pressed = false;
+        for item in self.items.iter_mut() {
+            item.is_hovered = false;
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn total_height(&self) -> f32 {
+        (self.items.len() as f32) * self.item_height
+    }
+
+    // (x, y) 아래에 있는 메뉴 항목의 인덱스
+    fn item_at(&self, x: f32, y: f32) -> Option<usize> {
+        if !self.visible || x < self.x || x > self.x + self.width || y < self.y {
+            return None;
+        }
+        let index = ((y - self.y) / self.item_height) as usize;
+        (index < self.items.len()).then_some(index)
+    }
+
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        if !self.visible {
+            return;
+        }
+
+        let hovered = self.item_at(x, y);
+        for (i, item) in self.items.iter_mut().enumerate() {
+            item.is_hovered = hovered == Some(i);
+        }
+    }
+}
+
+impl Widget for ContextMenu {
+    fn get_position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.size()
+    }
+
+    fn get_background_color(&self) -> Color {
+        self.items
+            .first()
+            .map(|item| item.background_color)
+            .unwrap_or(self.border_color)
+    }
+
+    fn get_text_color(&self) -> Color {
+        self.items
+            .first()
+            .map(|item| item.text_color)
+            .unwrap_or(self.border_color)
+    }
+
+    fn get_hover_background_color(&self) -> Color {
+        self.items
+            .first()
+            .map(|item| item.hover_background_color)
+            .unwrap_or(self.border_color)
+    }
+
+    fn get_hover_text_color(&self) -> Color {
+        self.items
+            .first()
+            .map(|item| item.hover_text_color)
+            .unwrap_or(self.border_color)
+    }
+
+    fn get_opacity(&self) -> f32 {
+        if self.visible { 1.0 } else { 0.0 }
+    }
+
+    fn get_is_hovered(&self) -> bool {
+        self.items.iter().any(|item| item.is_hovered)
+    }
+
+    fn get_is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.width = width;
+        if !self.items.is_empty() {
+            self.item_height = height / (self.items.len() as f32);
+        }
+    }
+
+    fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn size(&self) -> (f32, f32) {
+        (self.width, self.total_height())
+    }
+
+    // 닫혀 있는 메뉴는 포인터 이벤트를 가로채지 않음
+    fn hit_test(&self, x: f32, y: f32) -> bool {
+        self.visible && self.contains_point(x, y)
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.handle_mouse_move(x, y);
     }
 
-    pub fn draw(&self, renderer: &mut Renderer, screen_width: f32, screen_height: f32) {
+    fn handle_event(&mut self, event: &UiEvent, phase: EventPhase) -> bool {
+        if phase != EventPhase::Target {
+            return false;
+        }
+
+        match *event {
+            UiEvent::PointerMove { x, y } => {
+                self.handle_mouse_move(x, y);
+                self.visible
+            }
+            UiEvent::PointerLeave { .. } => {
+                for item in self.items.iter_mut() {
+                    item.is_hovered = false;
+                }
+                false
+            }
+            UiEvent::PointerDown { x, y, button: MouseButton::Left } => self.on_mouse_press(x, y),
+            // 열린 메뉴 위에서 누른 다른 버튼은 아래 위젯에 전달하지 않음
+            UiEvent::PointerDown { .. } => self.visible,
+            UiEvent::PointerUp { x, y, button: MouseButton::Left } => self.on_mouse_release(x, y),
+            UiEvent::PointerDownOutside { x, y, button } => {
+                if button == MouseButton::Right {
+                    self.show(x, y);
+                } else if self.visible {
+                    debug!("Click outside menu bounds, hiding menu");
+                    self.hide();
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn on_mouse_press(&mut self, x: f32, y: f32) -> bool {
+        if !self.visible {
+            return false;
+        }
+
+        if !self.contains_point(x, y) {
+            debug!("Click outside menu bounds, hiding menu");
+            self.hide();
+            return false;
+        }
+
+        self.is_pressed = true;
+        true
+    }
+
+    fn on_mouse_release(&mut self, x: f32, y: f32) -> bool {
+        if !self.is_pressed {
+            return false;
+        }
+        self.is_pressed = false;
+
+        if let Some(index) = self.item_at(x, y) {
+            let item = &mut self.items[index];
+            debug!("Menu item clicked: {}", item.text);
+            if let Some(callback) = &mut item.on_click {
+                callback();
+            }
+            self.hide();
+        }
+        true
+    }
+
+    // 메뉴는 애니메이션을 지원하지 않음
+    fn set_position_animation(&mut self, _animation: Vec2Animation) {}
+
+    fn set_fade_animation(&mut self, _animation: FadeAnimation) {}
+
+    fn update_animations(&mut self, _delta_time: f32) {}
+
+    // 메뉴는 열릴 때 커서 위치에 배치되므로 창 크기에 따라 조정하지 않음
+    fn resize_by_window_size(&mut self, _scale_x: f32, _scale_y: f32) {}
+
+    fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
         if !self.visible {
             return;
         }
 
-        let total_height = (self.items.len() as f32) * self.item_height;
+        let total_height = self.total_height();
+        let text_height = self.font.line_height(self.font_size);
 
         // Draw border
         renderer.draw_rect(
@@ -107,59 +333,15 @@ impl ContextMenu {
             // Draw item background
             renderer.draw_rect(self.x, item_y, self.width, self.item_height, bg_color.to_array());
 
-            // Draw item text
-            renderer
-                .text_renderer_mut()
-                .render_text(
-                    &item.text,
-                    self.x + self.padding,
-                    item_y + (self.item_height - 20.0) / 2.0,
-                    20.0,
-                    screen_width,
-                    screen_height,
-                    text_color.to_array()
-                );
-        }
-    }
-
-    pub fn handle_mouse_move(&mut self, x: f32, y: f32) {
-        if !self.visible {
-            return;
-        }
-
-        for (i, item) in self.items.iter_mut().enumerate() {
-            let item_y = self.y + (i as f32) * self.item_height;
-            item.is_hovered =
-                x >= self.x &&
-                x <= self.x + self.width &&
-                y >= item_y &&
-                y <= item_y + self.item_height;
-        }
-    }
-
-    pub fn handle_click(&mut self, x: f32, y: f32) -> bool {
-        if !self.visible {
-            return false;
-        }
-
-        let total_height = (self.items.len() as f32) * self.item_height;
-        if x < self.x || x > self.x + self.width || y < self.y || y > self.y + total_height {
-            debug!("Click outside menu bounds, hiding menu");
-            self.hide();
-            return false;
-        }
-
-        for (i, item) in self.items.iter_mut().enumerate() {
-            let item_y = self.y + (i as f32) * self.item_height;
-            if y >= item_y && y <= item_y + self.item_height {
-                debug!("Menu item clicked: {}", item.text);
-                if let Some(callback) = &item.on_click {
-                    callback.borrow_mut()();
-                }
-                self.hide();
-                return true;
-            }
+            // Draw item text (줄 높이 기준으로 세로 가운데 정렬)
+            renderer.draw_text_with_font(
+                &self.font,
+                &item.text,
+                self.x + self.padding,
+                item_y + (self.item_height - text_height) / 2.0,
+                self.font_size,
+                text_color.to_array()
+            );
         }
-        false
     }
 }
@@ -1,11 +1,9 @@
 use crate::style::color::Color;
 use crate::widget::Widget;
-use crate::graphics::Renderer;
+use crate::graphics::{ Renderer, Transform };
 use crate::animation::animation::{ Animation, Vec2Animation, FadeAnimation };
 use image::{ DynamicImage, GenericImageView };
-use std::cell::RefCell;
 use std::path::Path;
-use std::sync::Arc;
 use log::debug;
 pub struct ImageView {
     x: f32,
@@ -15,14 +13,16 @@ pub struct ImageView {
     texture_id: Option<u32>,
     position_animation: Option<Vec2Animation>,
     fade_animation: Option<FadeAnimation>,
-    on_hover: Option<Arc<RefCell<dyn FnMut(bool) + 'static>>>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
     is_pressed: bool,
     is_hovered: bool,
-    on_click: Option<Arc<RefCell<dyn FnMut() + 'static>>>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
     opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
 }
 
 impl ImageView {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             x: 0.0,
@@ -33,6 +33,7 @@ impl ImageView {
             position_animation: None,
             fade_animation: None,
             opacity: 1.0,
+            transform: Transform::IDENTITY,
             is_pressed: false,
             is_hovered: false,
             on_click: None,
@@ -92,25 +93,6 @@ impl ImageView {
         self.texture_id = Some(texture_id);
         Ok(())
     }
-
-    pub fn update_hover(&mut self, x: f32, y: f32) {
-        let was_hovered = self.is_hovered;
-        self.is_hovered = self.contains_point(x, y);
-
-        if was_hovered != self.is_hovered {
-            if let Some(callback) = &self.on_hover {
-                callback.borrow_mut()(self.is_hovered);
-            }
-        }
-    }
-
-    pub fn has_fade_animation(&self) -> bool {
-        self.fade_animation.is_some()
-    }
-
-    pub fn has_position_animation(&self) -> bool {
-        self.position_animation.is_some()
-    }
 }
 
 impl Widget for ImageView {
@@ -150,7 +132,17 @@ impl Widget for ImageView {
         self.is_pressed
     }
 
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
     fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
+        renderer.push_transform(&self.transform_matrix());
+
         if let Some(texture_id) = self.texture_id {
             renderer.draw_textured_rect(
                 self.x,
@@ -161,6 +153,8 @@ impl Widget for ImageView {
                 self.opacity
             );
         }
+
+        renderer.pop_transform();
     }
 
     fn set_size(&mut self, width: f32, height: f32) {
@@ -194,8 +188,8 @@ impl Widget for ImageView {
         if self.is_pressed {
             self.is_pressed = false;
             if self.contains_point(x, y) {
-                if let Some(callback) = &self.on_click {
-                    callback.borrow_mut()();
+                if let Some(callback) = &mut self.on_click {
+                    callback();
                 }
                 return true;
             }
@@ -203,12 +197,30 @@ impl Widget for ImageView {
         false
     }
 
-    fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
-        self.on_click = Some(Arc::new(RefCell::new(callback)));
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
     }
 
-    fn set_on_hover<F>(&mut self, callback: F) where F: FnMut(bool) + 'static {
-        self.on_hover = Some(Arc::new(RefCell::new(callback)));
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        // hover 상태가 변경되었을 때만 콜백 호출
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
+    }
+
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
     }
 
     fn set_position_animation(&mut self, animation: Vec2Animation) {
@@ -3,8 +3,14 @@ pub mod button;
 pub mod image_view;
 pub mod context_menu;
 pub mod shape;
+pub mod container;
+pub mod text_input;
+pub mod text_area;
 
 pub use button::Button;
 pub use text_view::TextView;
 pub use image_view::ImageView;
 pub use shape::Shape;
+pub use container::{ Container, Grid, Panel };
+pub use text_input::TextInput;
+pub use text_area::TextArea;
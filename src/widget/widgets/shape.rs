@@ -1,12 +1,10 @@
 use log::debug;
 
 use crate::animation::animation::{ FadeAnimation, Vec2Animation };
-use crate::graphics::Renderer;
+use crate::graphics::{ BoxShadow, CornerRadius, LineJoin, Paint, Path, Renderer, StrokeStyle, Transform };
 use crate::style::color::Color;
 use crate::widget::Widget;
 use crate::Animation;
-use std::cell::RefCell;
-use std::sync::Arc;
 
 #[derive(Debug)]
 pub enum ShapeType {
@@ -22,15 +20,19 @@ pub struct Shape {
     height: f32,
     shape_type: ShapeType,
     fill_color: Color,
+    fill_paint: Option<Paint>, // 지정하면 fill_color 대신 사용 (그라디언트)
     border_color: Color,
     border_width: f32,
+    corner_radius: CornerRadius, // 사각형의 모서리 반지름 (테두리 안쪽 기준)
+    shadow: Option<BoxShadow>,
     opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
     is_hovered: bool,
     is_pressed: bool,
     position_animation: Option<Vec2Animation>,
     fade_animation: Option<FadeAnimation>,
-    on_hover: Option<Arc<RefCell<dyn FnMut(bool) + 'static>>>,
-    on_click: Option<Arc<RefCell<dyn FnMut() + 'static>>>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
 }
 
 impl Shape {
@@ -43,9 +45,13 @@ impl Shape {
             height: 100.0,
             shape_type,
             fill_color: Color::new(1.0, 1.0, 1.0, 1.0),
+            fill_paint: None,
             border_color: Color::new(0.0, 0.0, 0.0, 1.0),
             border_width: 0.0,
+            corner_radius: CornerRadius::default(),
+            shadow: None,
             opacity: 1.0,
+            transform: Transform::IDENTITY,
             is_hovered: false,
             is_pressed: false,
             position_animation: None,
@@ -60,6 +66,12 @@ impl Shape {
         self.fill_color = color;
     }
 
+    /// 도형을 그라디언트 등으로 채웁니다. `None`이면 `set_fill_color`의 색으로 채웁니다.
+    pub fn set_fill_paint(&mut self, paint: Option<Paint>) {
+        debug!("Setting fill paint: {:?}", paint);
+        self.fill_paint = paint;
+    }
+
     pub fn set_border_color(&mut self, color: Color) {
         debug!("Setting border color: {:?}", color);
         self.border_color = color;
@@ -70,60 +82,64 @@ impl Shape {
         self.border_width = width;
     }
 
-    // hover 상태 업데이트
-    pub fn update_hover(&mut self, x: f32, y: f32) {
-        // 이전 상태를 저장
-        let was_hovered = self.is_hovered;
-
-        // 현재 위치가 이전과 같은 상태면 계산 스킵
-        let is_now_hovered = self.contains_point(x, y);
-        if was_hovered == is_now_hovered {
-            return;
-        }
+    /// 사각형의 모서리 반지름. 원과 삼각형에는 적용되지 않습니다.
+    pub fn set_corner_radius(&mut self, radius: impl Into<CornerRadius>) {
+        self.corner_radius = radius.into();
+        debug!("Setting corner radius: {:?}", self.corner_radius);
+    }
 
-        self.is_hovered = is_now_hovered;
-        if let Some(callback) = &self.on_hover {
-            callback.borrow_mut()(self.is_hovered);
-        }
+    /// 도형 아래에 그림자를 그립니다 (사각형과 원). `None`이면 그림자를 없앱니다.
+    pub fn set_shadow(&mut self, shadow: Option<BoxShadow>) {
+        debug!("Setting shadow: {:?}", shadow);
+        self.shadow = shadow;
     }
+}
 
-    pub fn has_fade_animation(&self) -> bool {
-        self.fade_animation.is_some()
+impl Widget for Shape {
+    fn transform(&self) -> Transform {
+        self.transform
     }
 
-    pub fn has_position_animation(&self) -> bool {
-        self.position_animation.is_some()
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
     }
-}
 
-impl Widget for Shape {
     fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
+        renderer.push_transform(&self.transform_matrix());
+
         // opacity를 적용한 색상 계산
-        let fill_color = self.fill_color.with_opacity(self.opacity);
+        let fill = match &self.fill_paint {
+            Some(paint) => paint.with_opacity(self.opacity),
+            None => Paint::from(self.fill_color.with_opacity(self.opacity)),
+        };
         let border_color = self.border_color.with_opacity(self.opacity);
 
         match self.shape_type {
             ShapeType::Rectangle => {
-                // 테두리 그리기
-                if self.border_width > 0.0 {
-                    renderer.draw_rect(
-                        self.x - self.border_width,
-                        self.y - self.border_width,
-                        self.width + self.border_width * 2.0,
-                        self.height + self.border_width * 2.0,
-                        border_color.to_array()
-                    );
-                }
-                // 사각형 내부 그리기
-                renderer.draw_rect(self.x, self.y, self.width, self.height, fill_color.to_array());
+                // 테두리는 도형 바깥쪽에 그리므로 테두리를 포함한 사각형을 기준으로 그립니다.
+                let border_width = self.border_width.max(0.0);
+                self.draw_rounded_with_opacity(
+                    renderer,
+                    [
+                        self.x - border_width,
+                        self.y - border_width,
+                        self.width + border_width * 2.0,
+                        self.height + border_width * 2.0,
+                    ],
+                    self.corner_radius.expanded(border_width),
+                    fill,
+                    border_color
+                );
             }
             ShapeType::Circle => {
-                self.draw_circle_with_opacity(renderer, fill_color, border_color)
+                self.draw_circle_with_opacity(renderer, fill, border_color)
             }
             ShapeType::Triangle => {
-                self.draw_triangle_with_opacity(renderer, fill_color, border_color)
+                self.draw_triangle_with_opacity(renderer, fill, border_color)
             }
         }
+
+        renderer.pop_transform();
     }
 
     // Widget trait의 나머지 필수 메서드들 구현
@@ -177,12 +193,30 @@ impl Widget for Shape {
         self.fade_animation = Some(animation);
     }
 
-    fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
-        self.on_click = Some(Arc::new(RefCell::new(callback)));
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
     }
 
-    fn set_on_hover<F>(&mut self, callback: F) where F: FnMut(bool) + 'static {
-        self.on_hover = Some(Arc::new(RefCell::new(callback)));
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        // hover 상태가 변경되었을 때만 콜백 호출
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
+    }
+
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
     }
 
     fn on_mouse_press(&mut self, x: f32, y: f32) -> bool {
@@ -196,8 +230,8 @@ impl Widget for Shape {
 
     fn on_mouse_release(&mut self, x: f32, y: f32) -> bool {
         if self.is_pressed && self.contains_point(x, y) {
-            if let Some(callback) = &self.on_click {
-                callback.borrow_mut()();
+            if let Some(callback) = &mut self.on_click {
+                callback();
             }
             true
         } else {
@@ -234,31 +268,51 @@ impl Shape {
     fn draw_circle_with_opacity(
         &self,
         renderer: &mut Renderer,
-        fill_color: Color,
+        fill: Paint,
         border_color: Color
     ) {
         let center_x = self.x + self.width / 2.0;
         let center_y = self.y + self.height / 2.0;
-        let radius = self.width.min(self.height) / 2.0;
-
-        // 테두리가 있을 때만 테두리 그리기
-        if self.border_width > 0.0 {
-            renderer.draw_circle(
-                center_x,
-                center_y,
-                radius + self.border_width,
-                border_color.to_array()
-            );
-        }
+        // 원은 반지름이 변 길이의 절반인 둥근 사각형으로 그립니다 (가장자리 안티에일리어싱).
+        let radius = self.width.min(self.height) / 2.0 + self.border_width.max(0.0);
+        self.draw_rounded_with_opacity(
+            renderer,
+            [center_x - radius, center_y - radius, radius * 2.0, radius * 2.0],
+            CornerRadius::uniform(radius),
+            fill,
+            border_color
+        );
+    }
 
-        // 내부 채우기
-        renderer.draw_circle(center_x, center_y, radius, fill_color.to_array());
+    // 테두리를 포함한 바깥 사각형 (x, y, width, height)에 그림자, 채우기, 테두리를 그립니다.
+    fn draw_rounded_with_opacity(
+        &self,
+        renderer: &mut Renderer,
+        rect: [f32; 4],
+        radius: CornerRadius,
+        fill: Paint,
+        border_color: Color
+    ) {
+        let [x, y, width, height] = rect;
+        if let Some(shadow) = &self.shadow {
+            renderer.draw_box_shadow(x, y, width, height, radius, &shadow.with_opacity(self.opacity));
+        }
+        renderer.draw_rounded_rect_with_border(
+            x,
+            y,
+            width,
+            height,
+            radius,
+            fill,
+            self.border_width.max(0.0),
+            border_color.to_array()
+        );
     }
 
     fn draw_triangle_with_opacity(
         &self,
         renderer: &mut Renderer,
-        fill_color: Color,
+        fill: Paint,
         border_color: Color
     ) {
         let vertices = [
@@ -266,17 +320,36 @@ impl Shape {
             (self.x + self.width, self.y + self.height),
             (self.x + self.width / 2.0, self.y),
         ];
+        renderer.draw_triangle(vertices, fill);
+
+        // 테두리는 사각형, 원과 같이 도형 바깥쪽에 그립니다. 각 변을 테두리 두께의 절반만큼 바깥으로
+        // 옮긴 삼각형을 따라 선을 그리면 선의 안쪽 가장자리가 채우기의 변과 겹치지 않고 맞닿습니다.
+        let border_width = self.border_width.max(0.0);
+        if border_width <= 0.0 || !(self.width > 0.0 && self.height > 0.0) {
+            return;
+        }
+        let normals: Vec<(f32, f32)> = (0..3)
+            .map(|i| {
+                let (x0, y0) = vertices[i];
+                let (x1, y1) = vertices[(i + 1) % 3];
+                let length = (x1 - x0).hypot(y1 - y0);
+                ((y0 - y1) / length, (x1 - x0) / length)
+            })
+            .collect();
 
-        // 테두리가 있을 때만 테두리 그리기
-        if self.border_width > 0.0 {
-            let border_vertices = [
-                (vertices[0].0 - self.border_width, vertices[0].1 + self.border_width),
-                (vertices[1].0 + self.border_width, vertices[1].1 + self.border_width),
-                (vertices[2].0, vertices[2].1 - self.border_width),
-            ];
-            renderer.draw_triangle(border_vertices, border_color.to_array());
+        let half = border_width / 2.0;
+        let mut path = Path::new();
+        for i in 0..3 {
+            // 꼭짓점에서 만나는 두 변의 법선으로 마이터 방향을 구함
+            let (ax, ay) = normals[(i + 2) % 3];
+            let (bx, by) = normals[i];
+            let scale = half / (1.0 + ax * bx + ay * by);
+            let (x, y) = vertices[i];
+            path.line_to(x + (ax + bx) * scale, y + (ay + by) * scale);
         }
+        path.close();
 
-        renderer.draw_triangle(vertices, fill_color.to_array());
+        let style = StrokeStyle::new(border_width).with_join(LineJoin::Miter);
+        renderer.stroke_path(&path, &style, border_color);
     }
 }
@@ -0,0 +1,942 @@
+use crate::animation::animation::{ Animation, Vec2Animation, FadeAnimation };
+use crate::event::{ EventPhase, Key, Modifiers, MouseButton, UiEvent };
+use crate::graphics::{ Renderer, Transform };
+use crate::style::color::Color;
+use crate::text::font::{ nearest_index, selection_spans };
+use crate::text::layout::line_index_of;
+use crate::text::{ wrap_text, FontQuery, FontRenderer, TextEditor, TextLine };
+use crate::widget::{ TextHandler, Widget };
+use log::{ debug, info };
+use std::ops::Range;
+
+// 캐럿 깜빡임 주기의 절반 (초)
+const CARET_BLINK_INTERVAL: f32 = 0.5;
+// 이 시간 안에 다시 누르면 더블 클릭으로 보고 단어를 선택 (초)
+const DOUBLE_CLICK_TIME: f32 = 0.4;
+const CARET_WIDTH: f32 = 2.0;
+// 줄 끝의 줄바꿈 문자가 선택되었을 때 표시하는 너비
+const NEWLINE_SELECTION_WIDTH: f32 = 6.0;
+
+/// 여러 줄 텍스트 편집 위젯.
+///
+/// 텍스트는 위젯 너비에 맞춰 자동으로 줄바꿈되고, 줄이 영역보다 많으면 세로로 스크롤됩니다.
+/// 편집 기록은 `TextEditor`에 남아 Ctrl+Z / Ctrl+Y(또는 Ctrl+Shift+Z)로 되돌릴 수 있습니다.
+/// Tab은 들여쓰기로 처리되며, `set_accepts_tab(false)`로 포커스 이동에 양보할 수 있습니다.
+pub struct TextArea {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    font: FontRenderer, // 이벤트 처리 중 줄바꿈과 캐럿 위치 계산용
+    editor: TextEditor,
+    lines: Vec<TextLine>, // 현재 너비로 줄바꿈한 결과
+    placeholder: String,
+    font_size: f32,
+    line_height: f32,
+    padding: f32,
+    tab_size: usize,
+    accepts_tab: bool,
+    read_only: bool,
+    scroll_offset: f32,
+    preferred_x: Option<f32>, // 위/아래로 이동할 때 유지할 캐럿의 x 위치
+    background_color: Color,
+    text_color: Color,
+    placeholder_color: Color,
+    border_color: Color,
+    focused_border_color: Color,
+    border_width: f32,
+    selection_color: Color,
+    caret_color: Color,
+    is_hovered: bool,
+    hover_background_color: Color,
+    hover_text_color: Color,
+    is_pressed: bool, // 마우스로 선택 영역을 드래그하는 중
+    is_focused: bool,
+    elapsed: f32,
+    caret_timer: f32,
+    last_click: Option<(f32, usize)>, // 마지막으로 누른 시각과 캐럿 위치
+    on_change: Option<TextHandler>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
+    position_animation: Option<Vec2Animation>,
+    fade_animation: Option<FadeAnimation>,
+    opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
+}
+
+impl TextArea {
+    pub fn new(placeholder: &str, renderer: &Renderer) -> Self {
+        debug!("Creating new TextArea with placeholder: '{}'", placeholder);
+        let font = renderer.text_renderer().font_renderer().clone();
+        let font_size = 24.0;
+
+        let mut area = Self {
+            x: 0.0,
+            y: 0.0,
+            width: 400.0,
+            height: 200.0,
+            line_height: font.line_height(font_size),
+            font,
+            editor: TextEditor::new(),
+            lines: Vec::new(),
+            placeholder: placeholder.to_string(),
+            font_size,
+            padding: 8.0,
+            tab_size: 4,
+            accepts_tab: true,
+            read_only: false,
+            scroll_offset: 0.0,
+            preferred_x: None,
+            background_color: Color::new(1.0, 1.0, 1.0, 1.0),
+            text_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            placeholder_color: Color::new(0.55, 0.55, 0.55, 1.0),
+            border_color: Color::new(0.5, 0.5, 0.5, 1.0),
+            focused_border_color: Color::new(0.25, 0.55, 1.0, 1.0),
+            border_width: 1.0,
+            selection_color: Color::new(0.6, 0.75, 1.0, 1.0),
+            caret_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            is_hovered: false,
+            hover_background_color: Color::new(0.97, 0.97, 0.97, 1.0),
+            hover_text_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            is_pressed: false,
+            is_focused: false,
+            elapsed: 0.0,
+            caret_timer: 0.0,
+            last_click: None,
+            on_change: None,
+            on_click: None,
+            on_hover: None,
+            position_animation: None,
+            fade_animation: None,
+            opacity: 1.0,
+            transform: Transform::IDENTITY,
+        };
+        area.relayout();
+        info!("TextArea created with size: {}x{}", area.width, area.height);
+        area
+    }
+
+    /// 편집 중인 텍스트 전체
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    /// 텍스트를 바꾸고 편집 기록을 비웁니다. `on_change`는 호출되지 않습니다.
+    pub fn set_text(&mut self, text: &str) {
+        self.editor.set_text(&self.expand_tabs(text));
+        self.preferred_x = None;
+        self.relayout();
+    }
+
+    /// 캐럿 위치에 텍스트를 넣습니다 (선택 영역은 대체). 실행 취소할 수 있으며 `on_change`가 호출됩니다.
+    pub fn insert_text(&mut self, text: &str) {
+        let text = self.expand_tabs(text);
+        if self.editor.insert(&text) {
+            self.text_changed();
+        }
+    }
+
+    /// 텍스트 끝에 덧붙입니다 (로그 등). 캐럿이 끝에 있었으면 캐럿과 스크롤도 끝을 따라갑니다.
+    pub fn append_text(&mut self, text: &str) {
+        let end = self.editor.char_count();
+        let follow = self.editor.caret() == end && !self.editor.has_selection();
+        let (caret, anchor) = (self.editor.caret(), self.editor.anchor());
+
+        let text = self.expand_tabs(text);
+        if !self.editor.replace(end..end, &text) {
+            return;
+        }
+        if !follow {
+            self.editor.select(anchor, caret);
+        }
+        self.text_changed();
+    }
+
+    pub fn editor(&self) -> &TextEditor {
+        &self.editor
+    }
+
+    /// 캐럿의 문자 인덱스
+    pub fn caret(&self) -> usize {
+        self.editor.caret()
+    }
+
+    /// 캐럿의 (줄, 열) 위치 ('\n' 기준, 0부터 시작)
+    pub fn caret_line_column(&self) -> (usize, usize) {
+        self.editor.line_column(self.editor.caret())
+    }
+
+    pub fn set_caret(&mut self, index: usize) {
+        self.editor.move_to(index, false);
+        self.caret_moved(true);
+    }
+
+    /// 문자 범위 `start..end`를 선택합니다. 캐럿은 `end`에 놓입니다.
+    pub fn select(&mut self, start: usize, end: usize) {
+        self.editor.select(start, end);
+        self.caret_moved(true);
+    }
+
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.editor.selection()
+    }
+
+    pub fn selected_text(&self) -> &str {
+        self.editor.selected_text()
+    }
+
+    /// '\n'으로 나뉜 줄 수
+    pub fn line_count(&self) -> usize {
+        self.editor.line_count()
+    }
+
+    /// 자동 줄바꿈을 포함해 화면에 표시되는 줄 수
+    pub fn visual_line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let changed = self.editor.undo();
+        if changed {
+            self.text_changed();
+        }
+        changed
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let changed = self.editor.redo();
+        if changed {
+            self.text_changed();
+        }
+        changed
+    }
+
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    /// 세로 스크롤 위치 (내용 높이를 넘지 않도록 제한)
+    pub fn set_scroll_offset(&mut self, offset: f32) {
+        self.scroll_offset = offset.clamp(0.0, self.max_scroll());
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
+    }
+
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.editor.set_max_length(max_length);
+        self.relayout();
+    }
+
+    /// 본문 글꼴을 바꿉니다. 줄 높이가 달라지므로 줄바꿈과 스크롤 위치를 다시 계산합니다.
+    pub fn set_font(&mut self, query: &FontQuery, renderer: &Renderer) {
+        self.font = renderer.font(query);
+        self.line_height = self.font.line_height(self.font_size);
+        self.relayout();
+    }
+
+    pub fn set_font_size(&mut self, size: f32) {
+        debug!("TextArea font size changing from {} to {}", self.font_size, size);
+        self.font_size = size;
+        self.line_height = self.font.line_height(size);
+        self.relayout();
+    }
+
+    pub fn set_padding(&mut self, padding: f32) {
+        self.padding = padding;
+        self.relayout();
+    }
+
+    /// Tab 키가 넣는 공백 수
+    pub fn set_tab_size(&mut self, tab_size: usize) {
+        self.tab_size = tab_size.max(1);
+    }
+
+    /// false면 Tab을 처리하지 않아 포커스가 다음 위젯으로 이동합니다.
+    pub fn set_accepts_tab(&mut self, accepts_tab: bool) {
+        self.accepts_tab = accepts_tab;
+    }
+
+    /// true면 선택과 스크롤만 가능하고 편집할 수 없습니다.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    pub fn set_background_color(&mut self, color: Color) {
+        self.background_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    pub fn set_placeholder_color(&mut self, color: Color) {
+        self.placeholder_color = color;
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    // 포커스를 가졌을 때의 테두리 색상 설정
+    pub fn set_focused_border_color(&mut self, color: Color) {
+        self.focused_border_color = color;
+    }
+
+    pub fn set_border_width(&mut self, width: f32) {
+        self.border_width = width;
+    }
+
+    pub fn set_selection_color(&mut self, color: Color) {
+        self.selection_color = color;
+    }
+
+    pub fn set_caret_color(&mut self, color: Color) {
+        self.caret_color = color;
+    }
+
+    // hover 상태의 배경색 설정
+    pub fn set_hover_background_color(&mut self, color: Color) {
+        self.hover_background_color = color;
+    }
+
+    // 텍스트가 편집될 때마다 호출되는 콜백 설정
+    pub fn set_on_change<F>(&mut self, callback: F) where F: FnMut(&str) + 'static {
+        self.on_change = Some(Box::new(callback));
+    }
+
+    // 탭 문자는 공백으로 바꿔서 저장 (글꼴에 탭 글리프가 없음)
+    fn expand_tabs(&self, text: &str) -> String {
+        text.replace('\t', &" ".repeat(self.tab_size)).replace("\r\n", "\n")
+    }
+
+    fn inner_width(&self) -> f32 {
+        (self.width - self.padding * 2.0).max(0.0)
+    }
+
+    fn inner_height(&self) -> f32 {
+        (self.height - self.padding * 2.0).max(0.0)
+    }
+
+    fn content_height(&self) -> f32 {
+        self.lines.len() as f32 * self.line_height
+    }
+
+    fn max_scroll(&self) -> f32 {
+        (self.content_height() - self.inner_height()).max(0.0)
+    }
+
+    // 화면에 완전히 보이는 줄 수 (Page Up/Down 이동량)
+    fn page_lines(&self) -> usize {
+        ((self.inner_height() / self.line_height) as usize).max(1)
+    }
+
+    // 현재 너비로 다시 줄바꿈하고 스크롤 조정
+    fn relayout(&mut self) {
+        let max_width = (self.inner_width() - CARET_WIDTH).max(1.0);
+        self.lines = wrap_text(&self.font, self.editor.text(), self.font_size, Some(max_width));
+        self.scroll_to_caret();
+    }
+
+    fn caret_line(&self) -> usize {
+        line_index_of(&self.lines, self.editor.caret())
+    }
+
+    // 줄 안에서 문자 인덱스의 x 위치
+    fn x_in_line(&self, line: &TextLine, index: usize) -> f32 {
+        line.caret_positions[index.clamp(line.start, line.end) - line.start]
+    }
+
+    // 줄 안에서 x에 가장 가까운 문자 인덱스
+    fn index_in_line(&self, line: &TextLine, x: f32) -> usize {
+        let index = line.start + nearest_index(&line.caret_positions, x);
+        index.min(line.last_caret())
+    }
+
+    // 부모 좌표 (x, y)에 가장 가까운 문자 인덱스 (위젯 변환을 되돌려서 계산)
+    fn index_at(&self, x: f32, y: f32) -> usize {
+        let (x, y) = self.to_local(x, y);
+        let local_y = y - self.y - self.padding + self.scroll_offset;
+        let row = (local_y / self.line_height).max(0.0) as usize;
+        let line = &self.lines[row.min(self.lines.len() - 1)];
+        self.index_in_line(line, x - self.x - self.padding)
+    }
+
+    // 캐럿이 보이도록 세로 스크롤 조정
+    fn scroll_to_caret(&mut self) {
+        let top = self.caret_line() as f32 * self.line_height;
+        let bottom = top + self.line_height;
+
+        if bottom - self.scroll_offset > self.inner_height() {
+            self.scroll_offset = bottom - self.inner_height();
+        }
+        if top < self.scroll_offset {
+            self.scroll_offset = top;
+        }
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll());
+    }
+
+    // 캐럿 이동 후 깜빡임을 다시 시작하고 스크롤 조정.
+    // 가로 이동이면 위/아래 이동에 사용할 x 위치를 초기화합니다.
+    fn caret_moved(&mut self, horizontal: bool) {
+        if horizontal {
+            self.preferred_x = None;
+        }
+        self.caret_timer = 0.0;
+        self.scroll_to_caret();
+    }
+
+    fn text_changed(&mut self) {
+        self.preferred_x = None;
+        self.caret_timer = 0.0;
+        self.relayout();
+        if let Some(callback) = &mut self.on_change {
+            callback(self.editor.text());
+        }
+    }
+
+    // 캐럿을 `delta` 줄만큼 위/아래로 옮깁니다 (x 위치 유지)
+    fn move_vertical(&mut self, delta: isize, extend: bool) {
+        let current = self.caret_line();
+        let x = match self.preferred_x {
+            Some(x) => x,
+            None => self.x_in_line(&self.lines[current], self.editor.caret()),
+        };
+
+        let target = current as isize + delta;
+        let index = if target < 0 {
+            0
+        } else if target as usize >= self.lines.len() {
+            self.editor.char_count()
+        } else {
+            self.index_in_line(&self.lines[target as usize], x)
+        };
+
+        self.editor.move_to(index, extend);
+        self.caret_moved(false);
+        self.preferred_x = Some(x);
+    }
+
+    // 화면 줄의 처음/끝으로 이동
+    fn move_line_home(&mut self, extend: bool) {
+        let line = &self.lines[self.caret_line()];
+        let index = line.start;
+        self.editor.move_to(index, extend);
+        self.caret_moved(true);
+    }
+
+    fn move_line_end(&mut self, extend: bool) {
+        let line = &self.lines[self.caret_line()];
+        let index = line.last_caret();
+        self.editor.move_to(index, extend);
+        self.caret_moved(true);
+    }
+
+    // 선택 영역이 걸친 '\n' 줄들의 문자 범위
+    fn selected_line_range(&self) -> Range<usize> {
+        let (start, end) = match self.editor.selection() {
+            Some(range) => (range.start, range.end),
+            None => (self.editor.caret(), self.editor.caret()),
+        };
+        self.editor.line_start(start)..self.editor.line_end(end)
+    }
+
+    // Tab: 선택 영역이 여러 줄이면 들여쓰기, 아니면 다음 탭 위치까지 공백 입력
+    fn indent(&mut self) {
+        let multi_line = self.editor.selected_text().contains('\n');
+        if !multi_line {
+            let (_, column) = self.editor.line_column(self.editor.caret());
+            let spaces = self.tab_size - (column % self.tab_size);
+            if self.editor.insert(&" ".repeat(spaces)) {
+                self.text_changed();
+            }
+            return;
+        }
+
+        let range = self.selected_line_range();
+        let unit = " ".repeat(self.tab_size);
+        let block: String = self.editor.text()
+            [self.editor.byte_index(range.start)..self.editor.byte_index(range.end)]
+            .split('\n')
+            .map(|line| format!("{}{}", unit, line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.replace_lines(range, &block);
+    }
+
+    // Shift+Tab: 선택한 줄(또는 캐럿이 있는 줄)의 앞 공백을 한 단계 제거
+    fn outdent(&mut self) {
+        let range = self.selected_line_range();
+        let tab_size = self.tab_size;
+        let block: String = self.editor.text()
+            [self.editor.byte_index(range.start)..self.editor.byte_index(range.end)]
+            .split('\n')
+            .map(|line| {
+                let spaces = line
+                    .chars()
+                    .take(tab_size)
+                    .take_while(|&c| c == ' ')
+                    .count();
+                &line[spaces..]
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.replace_lines(range, &block);
+    }
+
+    // 줄 범위를 바꾸고 바뀐 줄 전체를 선택. 최대 길이를 넘으면 텍스트와 선택을 그대로 둡니다.
+    fn replace_lines(&mut self, range: Range<usize>, block: &str) {
+        if self.editor.replace(range.clone(), block) {
+            self.editor.select(range.start, range.start + block.chars().count());
+            self.text_changed();
+        }
+    }
+
+    fn begin_drag(&mut self, x: f32, y: f32) {
+        let index = self.index_at(x, y);
+        let is_double_click = matches!(
+            self.last_click,
+            Some((time, last_index)) if self.elapsed - time < DOUBLE_CLICK_TIME && last_index == index
+        );
+
+        if is_double_click {
+            let word = self.editor.word_at(index);
+            self.editor.select(word.start, word.end);
+            self.last_click = None;
+        } else {
+            self.editor.move_to(index, false);
+            self.last_click = Some((self.elapsed, index));
+            self.is_pressed = true;
+        }
+        self.caret_moved(true);
+    }
+
+    // 편집 키 처리 (읽기 전용이면 무시)
+    fn on_edit_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let by_word = modifiers.ctrl || modifiers.alt;
+
+        let changed = match key {
+            Key::Backspace if by_word => self.editor.backspace_word(),
+            Key::Backspace => self.editor.backspace(),
+            Key::Delete if by_word => self.editor.delete_word(),
+            Key::Delete => self.editor.delete(),
+            Key::Enter => self.editor.insert("\n"),
+            Key::Tab if !self.accepts_tab || modifiers.ctrl => {
+                return false;
+            }
+            Key::Tab => {
+                if modifiers.shift { self.outdent() } else { self.indent() }
+                return true;
+            }
+            Key::Character('z') if modifiers.command() && !modifiers.shift => {
+                self.undo();
+                return true;
+            }
+            Key::Character('y') | Key::Character('z') if modifiers.command() => {
+                self.redo();
+                return true;
+            }
+            _ => {
+                return false;
+            }
+        };
+
+        if changed {
+            self.text_changed();
+        }
+        true
+    }
+}
+
+impl Widget for TextArea {
+    fn get_position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn get_background_color(&self) -> Color {
+        self.background_color
+    }
+
+    fn get_text_color(&self) -> Color {
+        self.text_color
+    }
+
+    fn get_hover_background_color(&self) -> Color {
+        self.hover_background_color
+    }
+
+    fn get_hover_text_color(&self) -> Color {
+        self.hover_text_color
+    }
+
+    fn get_opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn get_is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    fn get_is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
+        renderer.push_transform(&self.transform_matrix());
+
+        let background = if self.is_hovered && !self.is_focused {
+            self.hover_background_color
+        } else {
+            self.background_color
+        };
+        let border = if self.is_focused { self.focused_border_color } else { self.border_color };
+
+        if self.border_width > 0.0 {
+            renderer.draw_rect(
+                self.x - self.border_width,
+                self.y - self.border_width,
+                self.width + self.border_width * 2.0,
+                self.height + self.border_width * 2.0,
+                (border * self.opacity).to_array()
+            );
+        }
+        renderer.draw_rect(self.x, self.y, self.width, self.height, (background * self.opacity).to_array());
+
+        let inner_x = self.x + self.padding;
+        let inner_y = self.y + self.padding;
+
+        // 스크롤된 내용은 안쪽 여백의 위아래에서 잘라냄 (가로는 캐럿이 보이도록 위젯 너비 전체)
+        renderer.push_clip(self.x, inner_y, self.width, self.inner_height());
+
+        if self.editor.text().is_empty() {
+            let placeholder_lines = wrap_text(
+                &self.font,
+                &self.placeholder,
+                self.font_size,
+                Some(self.inner_width())
+            );
+            let chars: Vec<char> = self.placeholder.chars().collect();
+            for (row, line) in placeholder_lines.iter().enumerate() {
+                let line_y = inner_y + row as f32 * self.line_height;
+                if line_y + self.line_height > inner_y + self.inner_height() + 0.5 {
+                    break;
+                }
+                let text: String = chars[line.start..line.end].iter().collect();
+                renderer.draw_text_with_font(
+                    &self.font,
+                    &text,
+                    inner_x,
+                    line_y,
+                    self.font_size,
+                    (self.placeholder_color * self.opacity).to_array()
+                );
+            }
+        }
+
+        // 영역에 걸치는 줄만 그리기 (걸친 부분은 클립으로 잘림)
+        let first = (self.scroll_offset / self.line_height).floor().max(0.0) as usize;
+        let bottom = inner_y + self.inner_height();
+        let chars: Vec<char> = self.editor.text().chars().collect();
+        let selection = self.editor.selection();
+        let caret = self.editor.caret();
+        let caret_line = self.caret_line();
+        let caret_visible = ((self.caret_timer / CARET_BLINK_INTERVAL) as u32) % 2 == 0;
+
+        for (row, line) in self.lines.iter().enumerate().skip(first) {
+            let line_y = inner_y + row as f32 * self.line_height - self.scroll_offset;
+            if line_y >= bottom {
+                break;
+            }
+
+            if let Some(range) = &selection {
+                let start = range.start.max(line.start);
+                let end = range.end.min(line.end);
+                let mut spans = if start < end {
+                    selection_spans(&line.caret_positions, start - line.start, end - line.start)
+                } else {
+                    Vec::new()
+                };
+                // 선택이 줄 끝의 '\n'을 넘어가면 줄 오른쪽 끝에 줄바꿈 표시를 덧붙임
+                let newline_selected = !line.is_wrapped && range.end > line.end && line.end < chars.len();
+                if newline_selected && range.start <= line.end {
+                    let width = line.width();
+                    spans.push((width, width + NEWLINE_SELECTION_WIDTH));
+                }
+                for (start_x, end_x) in spans {
+                    renderer.draw_rect(
+                        inner_x + start_x,
+                        line_y,
+                        end_x - start_x,
+                        self.line_height,
+                        (self.selection_color * self.opacity).to_array()
+                    );
+                }
+            }
+
+            let text: String = chars[line.start..line.end].iter().collect();
+            renderer.draw_text_with_font(
+                &self.font,
+                &text,
+                inner_x,
+                line_y,
+                self.font_size,
+                (self.text_color * self.opacity).to_array()
+            );
+
+            // 포커스가 있으면 캐럿을 깜빡이며 표시
+            if self.is_focused && caret_visible && row == caret_line {
+                renderer.draw_rect(
+                    inner_x + self.x_in_line(line, caret),
+                    line_y,
+                    CARET_WIDTH,
+                    self.line_height,
+                    (self.caret_color * self.opacity).to_array()
+                );
+            }
+        }
+
+        renderer.pop_clip();
+
+        // 내용이 넘치면 오른쪽에 스크롤 위치 표시
+        if self.content_height() > self.inner_height() {
+            let track_height = self.height - 4.0;
+            let thumb_height = (track_height * self.inner_height() / self.content_height()).max(12.0);
+            let thumb_y = self.y + 2.0 + (track_height - thumb_height) * (self.scroll_offset / self.max_scroll());
+            renderer.draw_rect(
+                self.x + self.width - 6.0,
+                thumb_y,
+                4.0,
+                thumb_height,
+                (Color::new(0.0, 0.0, 0.0, 0.3) * self.opacity).to_array()
+            );
+        }
+
+        if self.is_focused {
+            renderer.draw_focus_ring(self.x, self.y, self.width, self.height);
+        }
+
+        renderer.pop_transform();
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let width_changed = width != self.width;
+        self.width = width;
+        self.height = height;
+        if width_changed {
+            self.relayout();
+        } else {
+            self.scroll_to_caret();
+        }
+    }
+
+    fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    // 드래그 선택과 휠 스크롤을 위해 포인터 이동과 휠 이벤트도 처리
+    fn handle_event(&mut self, event: &UiEvent, phase: EventPhase) -> bool {
+        if phase != EventPhase::Target {
+            return false;
+        }
+
+        match *event {
+            UiEvent::PointerEnter { .. } => {
+                self.set_hovered(true);
+                false
+            }
+            UiEvent::PointerLeave { .. } => {
+                self.set_hovered(false);
+                false
+            }
+            UiEvent::PointerMove { x, y } if self.is_pressed => {
+                let index = self.index_at(x, y);
+                self.editor.move_to(index, true);
+                self.caret_moved(true);
+                true
+            }
+            UiEvent::Wheel { delta_y, .. } => {
+                let before = self.scroll_offset;
+                self.set_scroll_offset(before + delta_y);
+                self.scroll_offset != before
+            }
+            UiEvent::PointerDown { x, y, button: MouseButton::Left } => self.on_mouse_press(x, y),
+            UiEvent::PointerUp { x, y, button: MouseButton::Left } => self.on_mouse_release(x, y),
+            UiEvent::KeyDown { key, modifiers } => self.on_key_down(key, modifiers),
+            UiEvent::Char { character } => self.on_char(character),
+            _ => false,
+        }
+    }
+
+    fn on_mouse_press(&mut self, x: f32, y: f32) -> bool {
+        if self.contains_point(x, y) {
+            self.begin_drag(x, y);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn on_mouse_release(&mut self, x: f32, y: f32) -> bool {
+        if self.is_pressed {
+            self.is_pressed = false;
+            if self.contains_point(x, y) {
+                if let Some(callback) = &mut self.on_click {
+                    callback();
+                }
+            }
+            return true;
+        }
+        false
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.is_focused = focused;
+        self.caret_timer = 0.0;
+        if !focused {
+            self.is_pressed = false;
+        }
+    }
+
+    fn on_key_down(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let extend = modifiers.shift;
+        let by_word = modifiers.ctrl || modifiers.alt;
+
+        match key {
+            Key::Left => {
+                if by_word { self.editor.move_word_left(extend) } else { self.editor.move_left(extend) }
+                self.caret_moved(true);
+            }
+            Key::Right => {
+                if by_word { self.editor.move_word_right(extend) } else { self.editor.move_right(extend) }
+                self.caret_moved(true);
+            }
+            Key::Up => self.move_vertical(-1, extend),
+            Key::Down => self.move_vertical(1, extend),
+            Key::PageUp => self.move_vertical(-(self.page_lines() as isize), extend),
+            Key::PageDown => self.move_vertical(self.page_lines() as isize, extend),
+            Key::Home if modifiers.ctrl => {
+                self.editor.move_home(extend);
+                self.caret_moved(true);
+            }
+            Key::End if modifiers.ctrl => {
+                self.editor.move_end(extend);
+                self.caret_moved(true);
+            }
+            Key::Home => self.move_line_home(extend),
+            Key::End => self.move_line_end(extend),
+            Key::Character('a') if modifiers.command() => {
+                self.editor.select_all();
+                self.caret_moved(true);
+            }
+            _ => {
+                return !self.read_only && self.on_edit_key(key, modifiers);
+            }
+        }
+        true
+    }
+
+    fn on_char(&mut self, character: char) -> bool {
+        if character.is_control() || self.read_only {
+            return false;
+        }
+
+        let mut buffer = [0; 4];
+        if self.editor.insert(character.encode_utf8(&mut buffer)) {
+            self.text_changed();
+        }
+        true
+    }
+
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
+    }
+
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        // hover 상태가 변경되었을 때만 콜백 호출
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
+    }
+
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
+    }
+
+    fn set_position_animation(&mut self, animation: Vec2Animation) {
+        self.position_animation = Some(animation);
+    }
+
+    fn set_fade_animation(&mut self, animation: FadeAnimation) {
+        self.fade_animation = Some(animation);
+    }
+
+    fn update_animations(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+        if self.is_focused {
+            self.caret_timer += delta_time;
+        }
+
+        // Update position animation
+        if let Some(ref mut anim) = self.position_animation {
+            anim.update(delta_time);
+            let pos = anim.value();
+            self.x = pos.x;
+            self.y = pos.y;
+
+            if anim.is_finished() {
+                self.position_animation = None;
+            }
+        }
+
+        // Update fade animation
+        if let Some(ref mut anim) = self.fade_animation {
+            anim.update(delta_time);
+            self.opacity = anim.value();
+
+            if anim.is_finished() {
+                self.fade_animation = None;
+            }
+        }
+    }
+}
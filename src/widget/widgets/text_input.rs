@@ -0,0 +1,689 @@
+use crate::animation::animation::{ Animation, Vec2Animation, FadeAnimation };
+use crate::event::{ EventPhase, Key, Modifiers, MouseButton, UiEvent };
+use crate::graphics::{ Renderer, Transform };
+use crate::style::color::Color;
+use crate::text::layout::fitting_prefix;
+use crate::text::{ selection_spans, FontQuery, FontRenderer, TextEditor, TextMetrics };
+use crate::widget::{ TextHandler, Widget };
+use log::{ debug, info };
+
+// 캐럿 깜빡임 주기의 절반 (초)
+const CARET_BLINK_INTERVAL: f32 = 0.5;
+// 이 시간 안에 다시 누르면 더블 클릭으로 보고 단어를 선택 (초)
+const DOUBLE_CLICK_TIME: f32 = 0.4;
+const CARET_WIDTH: f32 = 2.0;
+
+/// 한 줄 텍스트 입력 위젯.
+///
+/// 캐럿 위치와 선택 영역은 `FontRenderer`의 글리프 위치로 계산합니다. 텍스트가 입력 영역보다
+/// 길면 캐럿이 보이도록 가로로 스크롤되며, 영역 밖의 글자는 그리지 않습니다.
+pub struct TextInput {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    intrinsic_size: (f32, f32), // 글꼴 높이로 계산한 기본 크기 (레이아웃에서 사용)
+    font: FontRenderer, // 이벤트 처리 중 캐럿 위치 계산용
+    editor: TextEditor,
+    placeholder: String,
+    password: bool,
+    mask_char: char,
+    font_size: f32,
+    padding: f32,
+    metrics: TextMetrics, // 화면에 표시되는 텍스트의 측정 결과 (캐럿 위치, 히트 테스트)
+    scroll_offset: f32,
+    background_color: Color,
+    text_color: Color,
+    placeholder_color: Color,
+    border_color: Color,
+    focused_border_color: Color,
+    border_width: f32,
+    selection_color: Color,
+    caret_color: Color,
+    is_hovered: bool,
+    hover_background_color: Color,
+    hover_text_color: Color,
+    is_pressed: bool, // 마우스로 선택 영역을 드래그하는 중
+    is_focused: bool,
+    elapsed: f32,
+    caret_timer: f32,
+    last_click: Option<(f32, usize)>, // 마지막으로 누른 시각과 캐럿 위치
+    on_change: Option<TextHandler>,
+    on_submit: Option<TextHandler>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
+    position_animation: Option<Vec2Animation>,
+    fade_animation: Option<FadeAnimation>,
+    opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
+}
+
+impl TextInput {
+    pub fn new(placeholder: &str, renderer: &Renderer) -> Self {
+        debug!("Creating new TextInput with placeholder: '{}'", placeholder);
+
+        let mut input = Self {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            intrinsic_size: (0.0, 0.0),
+            font: renderer.text_renderer().font_renderer().clone(),
+            editor: TextEditor::new(),
+            placeholder: placeholder.to_string(),
+            password: false,
+            mask_char: '•',
+            font_size: 32.0,
+            padding: 8.0,
+            metrics: TextMetrics::default(),
+            scroll_offset: 0.0,
+            background_color: Color::new(1.0, 1.0, 1.0, 1.0),
+            text_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            placeholder_color: Color::new(0.55, 0.55, 0.55, 1.0),
+            border_color: Color::new(0.5, 0.5, 0.5, 1.0),
+            focused_border_color: Color::new(0.25, 0.55, 1.0, 1.0),
+            border_width: 1.0,
+            selection_color: Color::new(0.6, 0.75, 1.0, 1.0),
+            caret_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            is_hovered: false,
+            hover_background_color: Color::new(0.97, 0.97, 0.97, 1.0),
+            hover_text_color: Color::new(0.0, 0.0, 0.0, 1.0),
+            is_pressed: false,
+            is_focused: false,
+            elapsed: 0.0,
+            caret_timer: 0.0,
+            last_click: None,
+            on_change: None,
+            on_submit: None,
+            on_click: None,
+            on_hover: None,
+            position_animation: None,
+            fade_animation: None,
+            opacity: 1.0,
+            transform: Transform::IDENTITY,
+        };
+        input.update_size();
+        info!("TextInput created with size: {}x{}", input.width, input.height);
+        input
+    }
+
+    fn update_size(&mut self) {
+        let line_height = self.font.line_height(self.font_size);
+        let width = if self.width > 0.0 { self.width } else { self.font_size * 8.0 };
+
+        self.width = width;
+        self.height = line_height + self.padding * 2.0;
+        self.intrinsic_size = (self.width, self.height);
+        self.update_caret_positions();
+    }
+
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    /// 텍스트를 바꿉니다. `on_change`는 호출되지 않습니다.
+    pub fn set_text(&mut self, text: &str) {
+        self.editor.set_text(text);
+        self.update_caret_positions();
+    }
+
+    pub fn editor(&self) -> &TextEditor {
+        &self.editor
+    }
+
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
+    }
+
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.editor.set_max_length(max_length);
+        self.update_caret_positions();
+    }
+
+    /// 비밀번호 입력처럼 글자를 `mask_char`로 가려서 표시합니다.
+    pub fn set_password(&mut self, password: bool) {
+        self.password = password;
+        self.update_caret_positions();
+    }
+
+    pub fn set_mask_char(&mut self, mask_char: char) {
+        self.mask_char = mask_char;
+        self.update_caret_positions();
+    }
+
+    /// 입력 텍스트와 placeholder의 글꼴을 바꿉니다. 너비는 유지하고 높이만 줄 높이에 맞춥니다.
+    pub fn set_font(&mut self, query: &FontQuery, renderer: &Renderer) {
+        self.font = renderer.font(query);
+        self.update_size();
+    }
+
+    pub fn set_font_size(&mut self, size: f32) {
+        debug!("TextInput font size changing from {} to {}", self.font_size, size);
+        self.font_size = size;
+        self.update_size();
+    }
+
+    pub fn set_padding(&mut self, padding: f32) {
+        self.padding = padding;
+        self.update_size();
+    }
+
+    pub fn set_background_color(&mut self, color: Color) {
+        self.background_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    pub fn set_placeholder_color(&mut self, color: Color) {
+        self.placeholder_color = color;
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    // 포커스를 가졌을 때의 테두리 색상 설정
+    pub fn set_focused_border_color(&mut self, color: Color) {
+        self.focused_border_color = color;
+    }
+
+    pub fn set_border_width(&mut self, width: f32) {
+        self.border_width = width;
+    }
+
+    pub fn set_selection_color(&mut self, color: Color) {
+        self.selection_color = color;
+    }
+
+    pub fn set_caret_color(&mut self, color: Color) {
+        self.caret_color = color;
+    }
+
+    // hover 상태의 배경색 설정
+    pub fn set_hover_background_color(&mut self, color: Color) {
+        self.hover_background_color = color;
+    }
+
+    // 텍스트가 편집될 때마다 호출되는 콜백 설정
+    pub fn set_on_change<F>(&mut self, callback: F) where F: FnMut(&str) + 'static {
+        self.on_change = Some(Box::new(callback));
+    }
+
+    // Enter를 눌렀을 때 호출되는 콜백 설정
+    pub fn set_on_submit<F>(&mut self, callback: F) where F: FnMut(&str) + 'static {
+        self.on_submit = Some(Box::new(callback));
+    }
+
+    // 화면에 표시되는 텍스트 (비밀번호 모드면 가림 문자)
+    fn display_text(&self) -> String {
+        if self.password {
+            self.mask_char.to_string().repeat(self.editor.char_count())
+        } else {
+            self.editor.text().to_string()
+        }
+    }
+
+    fn inner_width(&self) -> f32 {
+        (self.width - self.padding * 2.0).max(0.0)
+    }
+
+    fn update_caret_positions(&mut self) {
+        self.metrics = self.font.measure(&self.display_text(), self.font_size);
+        self.scroll_to_caret();
+    }
+
+    // 캐럿이 입력 영역 안에 보이도록 가로 스크롤 조정
+    fn scroll_to_caret(&mut self) {
+        let caret_x = self.metrics.caret_x(self.editor.caret());
+        let text_width = self.metrics.advance_width;
+        let inner_width = self.inner_width();
+
+        if caret_x - self.scroll_offset > inner_width - CARET_WIDTH {
+            self.scroll_offset = caret_x - inner_width + CARET_WIDTH;
+        }
+        if caret_x < self.scroll_offset {
+            self.scroll_offset = caret_x;
+        }
+        let max_scroll = (text_width + CARET_WIDTH - inner_width).max(0.0);
+        self.scroll_offset = self.scroll_offset.clamp(0.0, max_scroll);
+    }
+
+    // 부모 좌표 (x, y)에 가장 가까운 문자 경계 (위젯 변환을 되돌려서 계산)
+    fn index_at(&self, x: f32, y: f32) -> usize {
+        let (x, _) = self.to_local(x, y);
+        let local_x = x - self.x - self.padding + self.scroll_offset;
+        self.metrics.hit_test(local_x)
+    }
+
+    // 캐럿 이동 후 깜빡임을 다시 시작하고 스크롤 조정
+    fn caret_moved(&mut self) {
+        self.caret_timer = 0.0;
+        self.scroll_to_caret();
+    }
+
+    fn text_changed(&mut self) {
+        self.caret_timer = 0.0;
+        self.update_caret_positions();
+        if let Some(callback) = &mut self.on_change {
+            callback(self.editor.text());
+        }
+    }
+
+    fn submit(&mut self) {
+        debug!("TextInput submitted: '{}'", self.editor.text());
+        if let Some(callback) = &mut self.on_submit {
+            callback(self.editor.text());
+        }
+    }
+
+    fn begin_drag(&mut self, x: f32, y: f32) {
+        let index = self.index_at(x, y);
+        let is_double_click = matches!(
+            self.last_click,
+            Some((time, last_index)) if self.elapsed - time < DOUBLE_CLICK_TIME && last_index == index
+        );
+
+        if is_double_click {
+            // 더블 클릭은 단어 선택 (비밀번호 모드에서는 전체 선택)
+            if self.password {
+                self.editor.select_all();
+            } else {
+                let word = self.editor.word_at(index);
+                self.editor.select(word.start, word.end);
+            }
+            self.last_click = None;
+        } else {
+            self.editor.move_to(index, false);
+            self.last_click = Some((self.elapsed, index));
+            self.is_pressed = true;
+        }
+        self.caret_moved();
+    }
+}
+
+impl Widget for TextInput {
+    fn get_position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn get_background_color(&self) -> Color {
+        self.background_color
+    }
+
+    fn get_text_color(&self) -> Color {
+        self.text_color
+    }
+
+    fn get_hover_background_color(&self) -> Color {
+        self.hover_background_color
+    }
+
+    fn get_hover_text_color(&self) -> Color {
+        self.hover_text_color
+    }
+
+    fn get_opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn get_is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    fn get_is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
+        renderer.push_transform(&self.transform_matrix());
+
+        let background = if self.is_hovered && !self.is_focused {
+            self.hover_background_color
+        } else {
+            self.background_color
+        };
+        let border = if self.is_focused { self.focused_border_color } else { self.border_color };
+
+        if self.border_width > 0.0 {
+            renderer.draw_rect(
+                self.x - self.border_width,
+                self.y - self.border_width,
+                self.width + self.border_width * 2.0,
+                self.height + self.border_width * 2.0,
+                (border * self.opacity).to_array()
+            );
+        }
+        renderer.draw_rect(self.x, self.y, self.width, self.height, (background * self.opacity).to_array());
+
+        let inner_x = self.x + self.padding;
+        let inner_width = self.inner_width();
+        let line_height = self.font.line_height(self.font_size);
+        let text_y = self.y + (self.height - line_height) / 2.0;
+
+        // 스크롤된 글자는 안쪽 여백 경계에서 잘라냄 (캐럿이 오른쪽 끝에 있어도 보이도록 캐럿 두께만큼 여유)
+        renderer.push_clip(inner_x, self.y, inner_width + CARET_WIDTH, self.height);
+
+        if self.editor.text().is_empty() {
+            // 비어 있으면 플레이스홀더를 보이는 만큼만 표시
+            let placeholder_metrics = self.font.measure(&self.placeholder, self.font_size);
+            let visible = fitting_prefix(&placeholder_metrics.caret_positions, inner_width);
+            let placeholder: String = self.placeholder.chars().take(visible).collect();
+            renderer.draw_text_with_font(
+                &self.font,
+                &placeholder,
+                inner_x,
+                text_y,
+                self.font_size,
+                (self.placeholder_color * self.opacity).to_array()
+            );
+        } else {
+            // 스크롤된 영역에 걸치는 글자만 그리기 (걸친 부분은 클립으로 잘림)
+            let visible_start = self.scroll_offset;
+            let visible_end = self.scroll_offset + inner_width;
+            // 오른쪽에서 왼쪽으로 쓰는 문자가 있어 캐럿 위치가 증가하지 않으면 잘라 그릴 수 없으므로 전체를 그림
+            let positions = &self.metrics.caret_positions;
+            let last_index = positions.len().saturating_sub(1);
+            let (first, last) = if positions.windows(2).all(|pair| pair[0] <= pair[1]) {
+                let first = positions
+                    .iter()
+                    .rposition(|&x| x <= visible_start)
+                    .unwrap_or(0);
+                let last = positions
+                    .iter()
+                    .position(|&x| x >= visible_end)
+                    .unwrap_or(last_index)
+                    .max(first);
+                (first, last)
+            } else {
+                (0, last_index)
+            };
+
+            if let Some(range) = self.editor.selection() {
+                for (start, end) in selection_spans(positions, range.start, range.end) {
+                    renderer.draw_rect(
+                        inner_x + start - self.scroll_offset,
+                        text_y,
+                        end - start,
+                        line_height,
+                        (self.selection_color * self.opacity).to_array()
+                    );
+                }
+            }
+
+            let visible_text: String = self
+                .display_text()
+                .chars()
+                .skip(first)
+                .take(last - first)
+                .collect();
+            renderer.draw_text_with_font(
+                &self.font,
+                &visible_text,
+                inner_x + self.metrics.caret_positions[first] - self.scroll_offset,
+                text_y,
+                self.font_size,
+                (self.text_color * self.opacity).to_array()
+            );
+        }
+
+        // 포커스가 있으면 캐럿을 깜빡이며 표시
+        let caret_visible = ((self.caret_timer / CARET_BLINK_INTERVAL) as u32) % 2 == 0;
+        if self.is_focused && caret_visible {
+            let caret_x = inner_x + self.metrics.caret_positions[self.editor.caret()] - self.scroll_offset;
+            renderer.draw_rect(
+                caret_x,
+                text_y,
+                CARET_WIDTH,
+                line_height,
+                (self.caret_color * self.opacity).to_array()
+            );
+        }
+
+        renderer.pop_clip();
+
+        if self.is_focused {
+            renderer.draw_focus_ring(self.x, self.y, self.width, self.height);
+        }
+
+        renderer.pop_transform();
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+        self.scroll_to_caret();
+    }
+
+    fn intrinsic_size(&self) -> Option<(f32, f32)> {
+        Some(self.intrinsic_size)
+    }
+
+    fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    // 마우스 드래그로 선택 영역을 넓히기 위해 포인터 이동도 처리
+    fn handle_event(&mut self, event: &UiEvent, phase: EventPhase) -> bool {
+        if phase != EventPhase::Target {
+            return false;
+        }
+
+        match *event {
+            UiEvent::PointerEnter { .. } => {
+                self.set_hovered(true);
+                false
+            }
+            UiEvent::PointerLeave { .. } => {
+                self.set_hovered(false);
+                false
+            }
+            UiEvent::PointerMove { x, y } if self.is_pressed => {
+                let index = self.index_at(x, y);
+                self.editor.move_to(index, true);
+                self.caret_moved();
+                true
+            }
+            UiEvent::PointerDown { x, y, button: MouseButton::Left } => self.on_mouse_press(x, y),
+            UiEvent::PointerUp { x, y, button: MouseButton::Left } => self.on_mouse_release(x, y),
+            UiEvent::KeyDown { key, modifiers } => self.on_key_down(key, modifiers),
+            UiEvent::Char { character } => self.on_char(character),
+            _ => false,
+        }
+    }
+
+    fn on_mouse_press(&mut self, x: f32, y: f32) -> bool {
+        if self.contains_point(x, y) {
+            self.begin_drag(x, y);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn on_mouse_release(&mut self, x: f32, y: f32) -> bool {
+        if self.is_pressed {
+            self.is_pressed = false;
+            if self.contains_point(x, y) {
+                if let Some(callback) = &mut self.on_click {
+                    callback();
+                }
+            }
+            return true;
+        }
+        false
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.is_focused = focused;
+        self.caret_timer = 0.0;
+        if !focused {
+            self.is_pressed = false;
+        }
+    }
+
+    fn on_key_down(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let extend = modifiers.shift;
+        // 비밀번호 모드에서는 단어 단위 이동으로 글자 구성이 드러나지 않도록 처리
+        let by_word = (modifiers.ctrl || modifiers.alt) && !self.password;
+
+        match key {
+            Key::Left => {
+                if by_word { self.editor.move_word_left(extend) } else { self.editor.move_left(extend) }
+                self.caret_moved();
+            }
+            Key::Right => {
+                if by_word { self.editor.move_word_right(extend) } else { self.editor.move_right(extend) }
+                self.caret_moved();
+            }
+            Key::Home | Key::Up => {
+                self.editor.move_home(extend);
+                self.caret_moved();
+            }
+            Key::End | Key::Down => {
+                self.editor.move_end(extend);
+                self.caret_moved();
+            }
+            Key::Backspace => {
+                let changed = if by_word { self.editor.backspace_word() } else { self.editor.backspace() };
+                if changed {
+                    self.text_changed();
+                }
+            }
+            Key::Delete => {
+                let changed = if by_word { self.editor.delete_word() } else { self.editor.delete() };
+                if changed {
+                    self.text_changed();
+                }
+            }
+            Key::Enter => self.submit(),
+            Key::Character('a') if modifiers.command() => {
+                self.editor.select_all();
+                self.caret_moved();
+            }
+            Key::Character('z') if modifiers.command() && !modifiers.shift => {
+                if self.editor.undo() {
+                    self.text_changed();
+                }
+            }
+            Key::Character('y') | Key::Character('z') if modifiers.command() => {
+                if self.editor.redo() {
+                    self.text_changed();
+                }
+            }
+            _ => {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn on_char(&mut self, character: char) -> bool {
+        if character.is_control() {
+            return false;
+        }
+
+        let mut buffer = [0; 4];
+        if self.editor.insert(character.encode_utf8(&mut buffer)) {
+            self.text_changed();
+        }
+        true
+    }
+
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
+    }
+
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        // hover 상태가 변경되었을 때만 콜백 호출
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
+    }
+
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
+    }
+
+    fn set_position_animation(&mut self, animation: Vec2Animation) {
+        self.position_animation = Some(animation);
+    }
+
+    fn set_fade_animation(&mut self, animation: FadeAnimation) {
+        self.fade_animation = Some(animation);
+    }
+
+    fn update_animations(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+        if self.is_focused {
+            self.caret_timer += delta_time;
+        }
+
+        // Update position animation
+        if let Some(ref mut anim) = self.position_animation {
+            anim.update(delta_time);
+            let pos = anim.value();
+            self.x = pos.x;
+            self.y = pos.y;
+
+            if anim.is_finished() {
+                self.position_animation = None;
+            }
+        }
+
+        // Update fade animation
+        if let Some(ref mut anim) = self.fade_animation {
+            anim.update(delta_time);
+            self.opacity = anim.value();
+
+            if anim.is_finished() {
+                self.fade_animation = None;
+            }
+        }
+    }
+}
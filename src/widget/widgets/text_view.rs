@@ -1,29 +1,75 @@
 use crate::animation::animation::{ Animation, Vec2Animation, FadeAnimation };
 use crate::style::color::Color;
 use crate::widget::Widget;
-use crate::graphics::Renderer;
+use crate::graphics::{ Renderer, Transform };
+use crate::text::{
+    FontQuery,
+    FontRenderer,
+    FontStyle,
+    FontVariation,
+    FontWeight,
+    RichText,
+    RichTextLayout,
+    TextAlign,
+    TextEffect,
+    TextLayout,
+    TextLayoutOptions,
+    TextOverflow,
+    VerticalAlign,
+    FONT_SCALE_FACTOR,
+};
 use log::{ debug, info };
-use std::sync::Arc;
-use std::cell::RefCell;
 
+// 텍스트와 위젯 경계 사이의 여백
+const PADDING_X: f32 = 10.0;
+const PADDING_Y: f32 = 5.0;
+// 이탤릭 글꼴이 없을 때 글리프를 기울이는 정도 (약 11도)
+const SYNTHETIC_ITALIC_SKEW: f32 = 0.2;
+
+/// 읽기 전용 텍스트 위젯.
+///
+/// '\n'으로 줄을 나누고, `set_word_wrap(true)`이면 위젯 너비에 맞춰 자동으로 줄을 바꿉니다.
+/// 텍스트나 글꼴 크기처럼 내용이 바뀌는 설정은 위젯 크기를 내용에 맞추며(자동 줄바꿈이면 높이만),
+/// 그 뒤에 `set_size`로 영역을 고정하면 정렬과 넘침(`TextOverflow`) 처리가 그 영역을 기준으로 동작합니다.
+///
+/// `set_rich_text`로 스팬마다 색, 크기, 굵기, 이탤릭, 밑줄, 취소선, 배경 강조를 다르게 지정할 수 있으며,
+/// 정렬과 줄바꿈 설정은 일반 텍스트와 같이 적용됩니다.
+///
+/// `set_text_effect`로 외곽선, 글로우, 그림자를 지정하면 텍스트를 거리 필드(SDF) 글리프로 그립니다.
 pub struct TextView {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
+    intrinsic_size: (f32, f32), // 텍스트 크기로 계산한 기본 크기 (레이아웃에서 사용)
     text: String,
     font_size: f32,
+    font: FontRenderer, // 크기가 바뀔 때 다시 배치하기 위한 글꼴
+    font_query: FontQuery, // 굵기만 바꿀 때 다시 찾을 글꼴 패밀리와 스타일
+    layout: TextLayout, // 현재 영역에 맞춰 배치한 줄
+    rich_text: Option<RichText>,
+    span_fonts: Vec<FontRenderer>, // 스팬별 굵기/이탤릭을 적용한 글꼴
+    synthetic_italic: Vec<bool>, // 이탤릭 글꼴이 없어 기울여 그릴 스팬
+    rich_layout: RichTextLayout,
+    word_wrap: bool,
+    align: TextAlign,
+    vertical_align: VerticalAlign,
+    line_spacing: f32,
+    overflow: TextOverflow,
+    clip_to_bounds: bool, // 위젯 영역 밖으로 나간 글자를 잘라낼지 여부
+    text_effect: Option<TextEffect>,
     background_color: Color,
     text_color: Color,
     is_hovered: bool,
     hover_background_color: Color,
     hover_text_color: Color,
-    on_hover: Option<Arc<RefCell<dyn FnMut(bool) + 'static>>>,
+    on_hover: Option<Box<dyn FnMut(bool) + 'static>>,
     is_pressed: bool,
-    on_click: Option<Arc<RefCell<dyn FnMut() + 'static>>>,
+    on_click: Option<Box<dyn FnMut() + 'static>>,
     position_animation: Option<Vec2Animation>,
     fade_animation: Option<FadeAnimation>,
     opacity: f32,
+    transform: Transform, // 위젯 중심 기준 변환
 }
 
 impl TextView {
@@ -37,8 +83,23 @@ impl TextView {
             y: 0.0,
             width: 0.0,
             height: 0.0,
+            intrinsic_size: (0.0, 0.0),
             text: text.to_string(),
             font_size: 32.0,
+            font: renderer.text_renderer().font_renderer().clone(),
+            font_query: FontQuery::default().with_weight(renderer.default_font_weight()),
+            layout: TextLayout::default(),
+            rich_text: None,
+            span_fonts: Vec::new(),
+            synthetic_italic: Vec::new(),
+            rich_layout: RichTextLayout::default(),
+            word_wrap: false,
+            align: TextAlign::Left,
+            vertical_align: VerticalAlign::Top,
+            line_spacing: 1.0,
+            overflow: TextOverflow::Visible,
+            clip_to_bounds: true,
+            text_effect: None,
             background_color,
             text_color,
             is_hovered: false,
@@ -50,8 +111,9 @@ impl TextView {
             position_animation: None,
             fade_animation: None,
             opacity: 1.0,
+            transform: Transform::IDENTITY,
         };
-        tv.update_size(renderer);
+        tv.fit_to_content();
         info!("TextView created with size: {}x{}", tv.width, tv.height);
         tv
     }
@@ -70,44 +132,284 @@ impl TextView {
             y: 0.0,
             width: 0.0,
             height: 0.0,
+            intrinsic_size: (0.0, 0.0),
             text,
             font_size,
+            font: renderer.text_renderer().font_renderer().clone(),
+            font_query: FontQuery::default().with_weight(renderer.default_font_weight()),
+            layout: TextLayout::default(),
+            rich_text: None,
+            span_fonts: Vec::new(),
+            synthetic_italic: Vec::new(),
+            rich_layout: RichTextLayout::default(),
+            word_wrap: false,
+            align: TextAlign::Left,
+            vertical_align: VerticalAlign::Top,
+            line_spacing: 1.0,
+            overflow: TextOverflow::Visible,
+            clip_to_bounds: true,
+            text_effect: None,
             background_color,
             text_color,
             hover_background_color,
             hover_text_color,
             is_hovered: false,
             opacity: 1.0,
+            transform: Transform::IDENTITY,
             on_hover: None,
             is_pressed: false,
             on_click: None,
             position_animation: None,
             fade_animation: None,
         };
-        tv.update_size(renderer);
+        tv.fit_to_content();
         tv
     }
 
-    fn update_size(&mut self, renderer: &Renderer) {
-        let (width, height) = renderer
-            .text_renderer()
-            .font_renderer()
-            .calculate_text_size(&self.text, self.font_size);
-        self.width = width + 20.0; // 여백 추가
-        self.height = height + 10.0;
+    // 내용에 맞춰 위젯 크기를 다시 계산 (자동 줄바꿈이면 현재 너비를 유지하고 높이만 맞춤)
+    fn fit_to_content(&mut self) {
+        let wrap_width = (self.word_wrap && self.width > 0.0).then_some(self.width - PADDING_X * 2.0);
+        let options = self.layout_options().with_size(wrap_width, None);
+        let (content_width, content_height) = match &self.rich_text {
+            Some(rich) => {
+                let content = RichTextLayout::new(rich, &self.span_fonts, self.font_size, &options);
+                (content.width, content.height)
+            }
+            None => {
+                let content = TextLayout::new(&self.font, &self.text, self.font_size, &options);
+                (content.width, content.height)
+            }
+        };
+
+        if wrap_width.is_none() {
+            self.width = content_width + PADDING_X * 2.0;
+        }
+        self.height = content_height + PADDING_Y * 2.0;
+        self.intrinsic_size = (self.width, self.height);
+        self.relayout();
         debug!("TextView size updated: {}x{}", self.width, self.height);
     }
 
+    // 현재 영역에 맞춰 줄을 다시 배치
+    fn relayout(&mut self) {
+        let options = self
+            .layout_options()
+            .with_size(
+                Some((self.width - PADDING_X * 2.0).max(0.0)),
+                Some((self.height - PADDING_Y * 2.0).max(0.0))
+            );
+        match &self.rich_text {
+            Some(rich) => {
+                self.rich_layout = RichTextLayout::new(rich, &self.span_fonts, self.font_size, &options);
+            }
+            None => {
+                self.layout = TextLayout::new(&self.font, &self.text, self.font_size, &options);
+            }
+        }
+    }
+
+    // 스팬의 굵기와 이탤릭에 맞는 글꼴을 찾습니다. 지정하지 않은 스팬은 위젯 글꼴을 그대로 사용합니다.
+    fn resolve_span_fonts(&mut self, renderer: &Renderer) {
+        self.span_fonts.clear();
+        self.synthetic_italic.clear();
+        let Some(rich) = &self.rich_text else {
+            return;
+        };
+
+        for span in rich.spans() {
+            let style = span.style;
+            if style.weight.is_none() && !style.italic {
+                self.span_fonts.push(self.font.clone());
+                self.synthetic_italic.push(false);
+                continue;
+            }
+
+            let query = self.font_query.clone().with_weight(style.weight.unwrap_or(self.font_query.weight));
+            let upright = renderer.font(&query);
+            if style.italic {
+                let italic = renderer.font(&query.with_style(FontStyle::Italic));
+                self.synthetic_italic.push(italic.id() == upright.id());
+                self.span_fonts.push(italic);
+            } else {
+                self.span_fonts.push(upright);
+                self.synthetic_italic.push(false);
+            }
+        }
+    }
+
+    fn layout_options(&self) -> TextLayoutOptions {
+        TextLayoutOptions::new()
+            .with_wrap(self.word_wrap)
+            .with_align(self.align)
+            .with_vertical_align(self.vertical_align)
+            .with_line_spacing(self.line_spacing)
+            .with_overflow(self.overflow)
+    }
+
+    /// 일반 텍스트로 바꿉니다 (스타일이 있는 텍스트는 지워집니다).
     pub fn set_text(&mut self, text: &str, renderer: &Renderer) {
         debug!("TextView text changing from '{}' to '{}'", self.text, text);
         self.text = text.to_string();
-        self.update_size(renderer);
+        self.rich_text = None;
+        self.resolve_span_fonts(renderer);
+        self.fit_to_content();
+    }
+
+    /// 스팬별 스타일이 있는 텍스트로 바꿉니다. 스팬에 지정하지 않은 값은 위젯의 글꼴, 크기, 글자 색을 따릅니다.
+    pub fn set_rich_text(&mut self, rich_text: RichText, renderer: &Renderer) {
+        debug!("TextView rich text with {} spans", rich_text.spans().len());
+        self.text = rich_text.text();
+        self.rich_text = Some(rich_text);
+        self.resolve_span_fonts(renderer);
+        self.fit_to_content();
+    }
+
+    pub fn rich_text(&self) -> Option<&RichText> {
+        self.rich_text.as_ref()
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 텍스트 글꼴을 바꿉니다. 리치 텍스트 구간의 굵게/기울임 글꼴도 새 패밀리에서 다시 찾습니다.
+    pub fn set_font(&mut self, query: &FontQuery, renderer: &Renderer) {
+        self.font_query = query.clone();
+        self.font = renderer.font(query);
+        self.resolve_span_fonts(renderer);
+        self.fit_to_content();
+    }
+
+    /// 굵기를 바꿉니다. 패밀리에 해당 굵기의 글꼴 파일이 없으면 가변 글꼴의 `wght` 축을 사용합니다.
+    pub fn set_font_weight(&mut self, weight: FontWeight, renderer: &Renderer) {
+        self.font_query.weight = weight;
+        self.font = renderer.font(&self.font_query);
+        self.resolve_span_fonts(renderer);
+        self.fit_to_content();
+    }
+
+    /// 가변 글꼴의 축 값을 직접 지정합니다 (예: `FontVariation::width(75.0)`).
+    pub fn set_font_variations(&mut self, variations: &[FontVariation], renderer: &Renderer) {
+        self.font = renderer.font(&self.font_query).with_variations(variations);
+        self.resolve_span_fonts(renderer);
+        self.fit_to_content();
     }
 
-    pub fn set_font_size(&mut self, size: f32, renderer: &Renderer) {
+    pub fn set_font_size(&mut self, size: f32, _renderer: &Renderer) {
         debug!("TextView font size changing from {} to {}", self.font_size, size);
         self.font_size = size;
-        self.update_size(renderer);
+        self.fit_to_content();
+    }
+
+    /// 위젯 너비에 맞춰 자동 줄바꿈할지 여부. 켜면 현재 너비를 유지하고 높이를 내용에 맞춥니다.
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.word_wrap = word_wrap;
+        self.fit_to_content();
+    }
+
+    pub fn set_align(&mut self, align: TextAlign) {
+        self.align = align;
+        self.relayout();
+    }
+
+    pub fn set_vertical_align(&mut self, vertical_align: VerticalAlign) {
+        self.vertical_align = vertical_align;
+        self.relayout();
+    }
+
+    /// 줄 간격 배율 (1.0이면 글꼴의 줄 높이). 높이를 내용에 맞춥니다.
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.line_spacing = line_spacing;
+        self.fit_to_content();
+    }
+
+    pub fn set_overflow(&mut self, overflow: TextOverflow) {
+        self.overflow = overflow;
+        self.relayout();
+    }
+
+    /// 위젯 영역 밖으로 나간 텍스트를 잘라낼지 설정합니다 (기본값 true).
+    /// `TextOverflow::Visible`로 배치한 긴 줄이나 그림자, 글로우 효과를 영역 밖까지 그리려면 끕니다.
+    pub fn set_clip_to_bounds(&mut self, clip: bool) {
+        self.clip_to_bounds = clip;
+    }
+
+    /// 현재 영역에 배치된 줄 (넘쳐서 잘렸는지는 `TextLayout::truncated`)
+    pub fn text_layout(&self) -> &TextLayout {
+        &self.layout
+    }
+
+    /// 스타일이 있는 텍스트의 배치 결과 (`set_rich_text`를 사용한 경우)
+    pub fn rich_text_layout(&self) -> &RichTextLayout {
+        &self.rich_layout
+    }
+
+    // 배경 강조를 먼저 칠한 뒤 글자와 밑줄/취소선을 그립니다.
+    fn draw_rich_text(&self, renderer: &mut Renderer, rich: &RichText, text_color: Color) {
+        let origin_x = self.x + PADDING_X;
+        let origin_y = self.y + PADDING_Y;
+
+        for line in &self.rich_layout.lines {
+            for run in &line.runs {
+                if let Some(background) = rich.spans()[run.span].style.background {
+                    renderer.draw_rect(
+                        origin_x + run.x,
+                        origin_y + line.y,
+                        run.width,
+                        line.height,
+                        (background * self.opacity).to_array()
+                    );
+                }
+            }
+
+            for run in &line.runs {
+                let style = rich.spans()[run.span].style;
+                let color = style.color
+                    .map(|color| color * self.opacity)
+                    .unwrap_or(text_color)
+                    .to_array();
+                let font = &self.span_fonts[run.span];
+                let (x, y) = (origin_x + run.x, origin_y + run.y);
+                if self.synthetic_italic[run.span] {
+                    renderer.draw_text_skewed(font, &run.text, x, y, run.size, SYNTHETIC_ITALIC_SKEW, color);
+                } else {
+                    self.draw_run(renderer, font, &run.text, x, y, run.size, color);
+                }
+
+                let thickness = ((run.size * FONT_SCALE_FACTOR) / 16.0).max(1.0);
+                let baseline = origin_y + line.baseline;
+                if style.underline {
+                    renderer.draw_rect(x, baseline + thickness * 1.5, run.width, thickness, color);
+                }
+                if style.strikethrough {
+                    let strike_y = baseline - run.ascent * 0.3 - thickness / 2.0;
+                    renderer.draw_rect(x, strike_y, run.width, thickness, color);
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_run(
+        &self,
+        renderer: &mut Renderer,
+        font: &FontRenderer,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: [f32; 4]
+    ) {
+        match &self.text_effect {
+            Some(effect) => renderer.draw_text_with_effect(font, text, x, y, size, color, effect),
+            None => renderer.draw_text_with_font(font, text, x, y, size, color),
+        }
+    }
+
+    /// 텍스트에 외곽선, 글로우, 그림자 효과를 적용합니다. `None`이면 효과 없이 그립니다.
+    /// 합성 이탤릭으로 그리는 스팬에는 효과가 적용되지 않습니다.
+    pub fn set_text_effect(&mut self, effect: Option<TextEffect>) {
+        self.text_effect = effect;
     }
 
     pub fn set_background_color(&mut self, color: Color) {
@@ -130,25 +432,6 @@ impl TextView {
     pub fn set_hover_text_color(&mut self, color: Color) {
         self.hover_text_color = color;
     }
-
-    pub fn update_hover(&mut self, x: f32, y: f32) {
-        let was_hovered = self.is_hovered;
-        self.is_hovered = self.contains_point(x, y);
-
-        if was_hovered != self.is_hovered {
-            if let Some(callback) = &self.on_hover {
-                callback.borrow_mut()(self.is_hovered);
-            }
-        }
-    }
-
-    pub fn has_fade_animation(&self) -> bool {
-        self.fade_animation.is_some()
-    }
-
-    pub fn has_position_animation(&self) -> bool {
-        self.position_animation.is_some()
-    }
 }
 
 impl Widget for TextView {
@@ -188,7 +471,17 @@ impl Widget for TextView {
         self.is_pressed
     }
 
-    fn draw(&self, renderer: &mut Renderer, screen_width: f32, screen_height: f32) {
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn draw(&self, renderer: &mut Renderer, _screen_width: f32, _screen_height: f32) {
+        renderer.push_transform(&self.transform_matrix());
+
         let current_background =
             (if self.is_hovered { self.hover_background_color } else { self.background_color }) *
             self.opacity;
@@ -198,22 +491,43 @@ impl Widget for TextView {
 
         renderer.draw_rect(self.x, self.y, self.width, self.height, current_background.to_array());
 
-        renderer
-            .text_renderer_mut()
-            .render_text(
-                &self.text,
-                self.x + 10.0,
-                self.y + 5.0,
-                self.font_size,
-                screen_width,
-                screen_height,
-                current_text.to_array()
-            );
+        if self.clip_to_bounds {
+            renderer.push_clip(self.x, self.y, self.width, self.height);
+        }
+
+        if let Some(rich) = &self.rich_text {
+            self.draw_rich_text(renderer, rich, current_text);
+        } else {
+            for line in &self.layout.lines {
+                for run in &line.runs {
+                    self.draw_run(
+                        renderer,
+                        &self.font,
+                        &run.text,
+                        self.x + PADDING_X + run.x,
+                        self.y + PADDING_Y + line.y,
+                        self.font_size,
+                        current_text.to_array()
+                    );
+                }
+            }
+        }
+
+        if self.clip_to_bounds {
+            renderer.pop_clip();
+        }
+
+        renderer.pop_transform();
     }
 
     fn set_size(&mut self, width: f32, height: f32) {
         self.width = width;
         self.height = height;
+        self.relayout();
+    }
+
+    fn intrinsic_size(&self) -> Option<(f32, f32)> {
+        Some(self.intrinsic_size)
     }
 
     fn position(&self) -> (f32, f32) {
@@ -242,8 +556,8 @@ impl Widget for TextView {
         if self.is_pressed {
             self.is_pressed = false;
             if self.contains_point(x, y) {
-                if let Some(callback) = &self.on_click {
-                    callback.borrow_mut()();
+                if let Some(callback) = &mut self.on_click {
+                    callback();
                 }
                 return true;
             }
@@ -251,12 +565,30 @@ impl Widget for TextView {
         false
     }
 
-    fn set_on_click<F>(&mut self, callback: F) where F: FnMut() + 'static {
-        self.on_click = Some(Arc::new(RefCell::new(callback)));
+    fn set_on_click_boxed(&mut self, callback: Box<dyn FnMut() + 'static>) {
+        self.on_click = Some(callback);
     }
 
-    fn set_on_hover<F>(&mut self, callback: F) where F: FnMut(bool) + 'static {
-        self.on_hover = Some(Arc::new(RefCell::new(callback)));
+    fn set_on_hover_boxed(&mut self, callback: Box<dyn FnMut(bool) + 'static>) {
+        self.on_hover = Some(callback);
+    }
+
+    fn set_hovered(&mut self, hovered: bool) {
+        // hover 상태가 변경되었을 때만 콜백 호출
+        if self.is_hovered != hovered {
+            self.is_hovered = hovered;
+            if let Some(callback) = &mut self.on_hover {
+                callback(hovered);
+            }
+        }
+    }
+
+    fn has_fade_animation(&self) -> bool {
+        self.fade_animation.is_some()
+    }
+
+    fn has_position_animation(&self) -> bool {
+        self.position_animation.is_some()
     }
 
     fn set_position_animation(&mut self, animation: Vec2Animation) {
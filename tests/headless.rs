@@ -0,0 +1,102 @@
+//! `headless` 기능으로 창 없이 그린 결과를 픽셀 단위로 확인하는 스모크 테스트.
+//! EGL(Mesa llvmpipe 등)이 필요합니다: `cargo test --features headless`
+#![cfg(feature = "headless")]
+
+use base_ui::core::HeadlessContext;
+use base_ui::graphics::{ LineCap, LineJoin, StrokeStyle };
+use base_ui::style::color::Color;
+use base_ui::widget::widgets::shape::{ Shape, ShapeType };
+use base_ui::{ Renderer, Widget };
+use image::Rgba;
+
+const FONT: &[u8] = include_bytes!("../src/assets/FiraCode-VariableFont_wght.ttf");
+
+fn setup(width: u32, height: u32) -> (HeadlessContext, Renderer) {
+    let context = HeadlessContext::new(width, height).expect("headless EGL context");
+    let _gl_context = base_ui::initialize_headless(&context);
+    let mut renderer = Renderer::new(FONT.to_vec());
+    renderer.set_background_color(0.0, 0.0, 1.0, 1.0);
+    (context, renderer)
+}
+
+// 채널마다 반올림 오차를 허용해서 비교
+fn assert_pixel(actual: &Rgba<u8>, expected: [u8; 4]) {
+    let close = actual.0
+        .iter()
+        .zip(expected)
+        .all(|(&a, e)| a.abs_diff(e) <= 2);
+    assert!(close, "expected {:?}, got {:?}", expected, actual.0);
+}
+
+#[test]
+fn capture_frame_reads_drawn_pixels() {
+    let (context, mut renderer) = setup(64, 48);
+    let (width, height) = context.size();
+
+    renderer.render(width, height);
+    renderer.draw_rect(8.0, 8.0, 16.0, 16.0, [1.0, 0.0, 0.0, 1.0]);
+    renderer.draw_rect(40.0, 8.0, 16.0, 16.0, [1.0, 1.0, 1.0, 0.5]);
+    renderer.end_frame();
+
+    let frame = renderer.capture_frame();
+    assert_eq!(frame.dimensions(), (64, 48));
+    // 위쪽 행부터 저장되므로 화면 좌표 그대로 읽습니다.
+    assert_pixel(frame.get_pixel(16, 16), [255, 0, 0, 255]);
+    assert_pixel(frame.get_pixel(48, 16), [128, 128, 255, 255]);
+    assert_pixel(frame.get_pixel(16, 40), [0, 0, 255, 255]);
+    assert_pixel(frame.get_pixel(0, 0), [0, 0, 255, 255]);
+}
+
+#[test]
+fn capture_widget_uses_transparent_background() {
+    let (context, mut renderer) = setup(64, 64);
+    let (width, height) = context.size();
+
+    let mut shape = Shape::new(ShapeType::Rectangle);
+    shape.set_position(10.0, 10.0);
+    shape.set_size(20.0, 10.0);
+    shape.set_fill_color(Color::new(0.0, 1.0, 0.0, 1.0));
+
+    renderer.render(width, height);
+    shape.draw(&mut renderer, width, height);
+    renderer.end_frame();
+
+    let image = renderer.capture_widget(&shape, 2.0);
+    assert_eq!(image.dimensions(), (24, 14));
+    assert_pixel(image.get_pixel(12, 7), [0, 255, 0, 255]);
+    assert_eq!(image.get_pixel(0, 0)[3], 0);
+
+    // 위젯 캡처는 화면에 그린 내용을 바꾸지 않습니다.
+    let frame = renderer.capture_frame();
+    assert_pixel(frame.get_pixel(20, 15), [0, 255, 0, 255]);
+    assert_pixel(frame.get_pixel(40, 40), [0, 0, 255, 255]);
+}
+
+#[test]
+fn round_caps_and_joins_are_filled() {
+    let (context, mut renderer) = setup(120, 120);
+    let (width, height) = context.size();
+
+    let style = StrokeStyle::new(30.0).with_cap(LineCap::Round).with_join(LineJoin::Round);
+    let points = [[20.0, 40.0], [80.0, 40.0], [80.0, 100.0]];
+    renderer.render(width, height);
+    renderer.draw_polyline(&points, &style, [1.0, 0.0, 0.0, 1.0]);
+    renderer.end_frame();
+
+    let frame = renderer.capture_frame();
+    // 양 끝과 꺾인 곳을 중심으로 반지름(15)보다 조금 안쪽의 픽셀은 모두 선 색이어야 합니다.
+    for [cx, cy] in points {
+        for y in 0..120 {
+            for x in 0..120 {
+                let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+                if dx.hypot(dy) < 13.5 {
+                    assert_pixel(frame.get_pixel(x, y), [255, 0, 0, 255]);
+                }
+            }
+        }
+    }
+    // 둥근 모서리 바깥은 배경 그대로
+    assert_pixel(frame.get_pixel(93, 27), [0, 0, 255, 255]);
+    assert_pixel(frame.get_pixel(7, 27), [0, 0, 255, 255]);
+    assert_pixel(frame.get_pixel(93, 113), [0, 0, 255, 255]);
+}